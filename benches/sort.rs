@@ -0,0 +1,255 @@
+//! Sorts rows with a growing share of `NULL` values and totally ordered rows with [`OrdBy`]. Run with `cargo bench`.
+//!
+//! The `per_comparison` baseline checks for `NULL` inside the comparator as earlier versions did. Compare it against `use_sorter` to see the gain from partitioning `NULL`s up front.
+//!
+//! The `projection` group sorts numbers held as text, parsing them in every comparison or once per row with [`PartialOrdBy::projection`].
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use dioxus::prelude::*;
+use dioxus_sortable::*;
+use std::{cmp::Ordering, rc::Rc};
+
+const ROWS: usize = 10_000;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Row(f64);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum RowField {
+    #[default]
+    Value,
+}
+
+impl PartialOrdBy<Row> for RowField {
+    fn partial_cmp_by(&self, a: &Row, b: &Row) -> Option<Ordering> {
+        match self {
+            Self::Value => a.0.partial_cmp(&b.0),
+        }
+    }
+}
+
+impl Sortable for RowField {
+    fn sort_by(&self) -> Option<SortBy> {
+        SortBy::increasing_or_decreasing()
+    }
+}
+
+/// Pseudo-random rows where roughly `null_percent` of values are `NULL` (NaN).
+fn rows(null_percent: u64) -> Vec<Row> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..ROWS)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state % 100 < null_percent {
+                Row(f64::NAN)
+            } else {
+                Row((state % 1_000_000) as f64)
+            }
+        })
+        .collect()
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SortRowsProps {
+    rows: Rc<Vec<Row>>,
+}
+
+#[allow(non_snake_case)]
+fn SortRows(props: SortRowsProps) -> Element {
+    let sorter = use_sorter::<RowField>();
+    let mut rows = props.rows.as_ref().clone();
+    sorter.sort(&mut rows);
+    black_box(rows);
+    rsx! {}
+}
+
+/// Baseline: NULL checks made inside the comparator for every comparison.
+fn per_comparison(rows: &mut [Row]) {
+    let field = RowField::Value;
+    rows.sort_by(|a, b| {
+        field.partial_cmp_by(a, b).unwrap_or_else(|| {
+            let a_is_null = field.partial_cmp_by(a, a).is_none();
+            let b_is_null = field.partial_cmp_by(b, b).is_none();
+            a_is_null.cmp(&b_is_null)
+        })
+    });
+}
+
+fn bench_nulls(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nulls");
+    for null_percent in [0, 10, 50, 90] {
+        let data = Rc::new(rows(null_percent));
+        group.bench_with_input(
+            BenchmarkId::new("per_comparison", null_percent),
+            &data,
+            |b, data| {
+                b.iter_batched(
+                    || data.as_ref().clone(),
+                    |mut rows| per_comparison(&mut rows),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("use_sorter", null_percent),
+            &data,
+            |b, data| {
+                b.iter_batched(
+                    || VirtualDom::new_with_props(SortRows, SortRowsProps { rows: data.clone() }),
+                    |mut dom| {
+                        dom.rebuild_in_place();
+                        // Drop outside of the measurement
+                        dom
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum CountField {
+    #[default]
+    Count,
+}
+
+impl OrdBy<u64> for CountField {
+    fn cmp_by(&self, a: &u64, b: &u64) -> Ordering {
+        match self {
+            Self::Count => a.cmp(b),
+        }
+    }
+}
+
+impl Sortable for CountField {
+    fn sort_by(&self) -> Option<SortBy> {
+        SortBy::increasing_or_decreasing()
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SortCountsProps {
+    counts: Rc<Vec<u64>>,
+    ord: bool,
+}
+
+#[allow(non_snake_case)]
+fn SortCounts(props: SortCountsProps) -> Element {
+    let sorter = use_sorter::<CountField>();
+    let mut counts = props.counts.as_ref().clone();
+    if props.ord {
+        sorter.sort_ord(&mut counts);
+    } else {
+        sorter.sort(&mut counts);
+    }
+    black_box(counts);
+    rsx! {}
+}
+
+/// Totally ordered data sorted with and without `NULL` handling.
+fn bench_ord(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ord");
+    let counts = Rc::new(rows(0).into_iter().map(|row| row.0 as u64).collect());
+    for (name, ord) in [("sort", false), ("sort_ord", true)] {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    let props = SortCountsProps {
+                        counts: Rc::clone(&counts),
+                        ord,
+                    };
+                    VirtualDom::new_with_props(SortCounts, props)
+                },
+                |mut dom| {
+                    dom.rebuild_in_place();
+                    dom
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// Parses amounts such as "1,234" in every comparison, or once per row if `projected`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct AmountField {
+    projected: bool,
+}
+
+fn parse_amount(amount: &str) -> Option<f64> {
+    amount.replace(',', "").parse().ok()
+}
+
+impl PartialOrdBy<String> for AmountField {
+    fn partial_cmp_by(&self, a: &String, b: &String) -> Option<Ordering> {
+        parse_amount(a)?.partial_cmp(&parse_amount(b)?)
+    }
+
+    fn projection(&self) -> Option<Projection<String>> {
+        let amount = Projection::new(|amount: &String| parse_amount(amount));
+        self.projected.then_some(amount)
+    }
+}
+
+impl Sortable for AmountField {
+    fn sort_by(&self) -> Option<SortBy> {
+        SortBy::increasing_or_decreasing()
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SortAmountsProps {
+    amounts: Rc<Vec<String>>,
+    projected: bool,
+}
+
+#[allow(non_snake_case)]
+fn SortAmounts(props: SortAmountsProps) -> Element {
+    let field = AmountField {
+        projected: props.projected,
+    };
+    let sorter = UseSorterBuilder::default().with_field(field).use_sorter();
+    let mut amounts = props.amounts.as_ref().clone();
+    sorter.sort(&mut amounts);
+    black_box(amounts);
+    rsx! {}
+}
+
+/// Numbers held as text, parsed per comparison or once per row.
+fn bench_projection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("projection");
+    let amounts = rows(10)
+        .into_iter()
+        .map(|row| match row.0.is_nan() {
+            true => "n/a".to_string(),
+            false => format!("{},{:03}", row.0 as u64 / 1000, row.0 as u64 % 1000),
+        })
+        .collect::<Vec<_>>();
+    let amounts = Rc::new(amounts);
+    for (name, projected) in [("per_comparison", false), ("projected", true)] {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || {
+                    let props = SortAmountsProps {
+                        amounts: Rc::clone(&amounts),
+                        projected,
+                    };
+                    VirtualDom::new_with_props(SortAmounts, props)
+                },
+                |mut dom| {
+                    dom.rebuild_in_place();
+                    dom
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_nulls, bench_ord, bench_projection);
+criterion_main!(benches);
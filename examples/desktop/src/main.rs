@@ -0,0 +1,127 @@
+use dioxus::prelude::*;
+use dioxus_sortable::{
+    use_filter, use_paginator, use_sorter, FilterBy, FilteredSorted, NullHandling, NullableCell,
+    Paginator, PartialOrdBy, SortBy, Sortable, Th,
+};
+use std::cmp::Ordering;
+
+fn main() {
+    dioxus::launch(app);
+}
+
+/// A sortable, searchable, paged table of planets in a desktop window. The same components work in the browser: nothing here is specific to the renderer.
+fn app() -> Element {
+    let sorter = use_sorter::<PlanetField>();
+    let filter = use_filter(&[PlanetField::Name]);
+    let paginator = use_paginator(4);
+    let planets = planets();
+    let view = FilteredSorted::new(&planets, &filter, &sorter);
+    let range = paginator.range(view.len());
+
+    rsx! {
+        h1 { "Planets and dwarf planets" }
+        input {
+            placeholder: "Search by name",
+            oninput: move |evt| {
+                filter.set_search(evt.value());
+                paginator.first();
+            },
+        }
+        table {
+            thead {
+                tr {
+                    Th { sorter: sorter, field: PlanetField::Name, "Name" }
+                    Th {
+                        sorter: sorter,
+                        field: PlanetField::Radius,
+                        sublabel: "km",
+                        "Radius"
+                    }
+                    Th { sorter: sorter, field: PlanetField::Moons, "Moons" }
+                }
+            }
+            tbody {
+                for (name, planet) in view.keyed(|planet| planet.name).skip(range.start).take(range.len()) {
+                    tr { key: "{name}",
+                        td { "{planet.name}" }
+                        td { "{planet.radius}" }
+                        NullableCell { value: planet.moons }
+                    }
+                }
+            }
+        }
+        Paginator { paginator: paginator, total: view.len(), page_sizes: vec![4, 8] }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Planet {
+    name: &'static str,
+    /// Mean radius in kilometres
+    radius: u32,
+    /// Known moons. None if still being counted
+    moons: Option<u32>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum PlanetField {
+    #[default]
+    Name,
+    Radius,
+    Moons,
+}
+
+impl PartialOrdBy<Planet> for PlanetField {
+    fn partial_cmp_by(&self, a: &Planet, b: &Planet) -> Option<Ordering> {
+        match self {
+            PlanetField::Name => a.name.partial_cmp(b.name),
+            PlanetField::Radius => a.radius.partial_cmp(&b.radius),
+            PlanetField::Moons => a.moons?.partial_cmp(&b.moons?),
+        }
+    }
+}
+
+impl Sortable for PlanetField {
+    fn sort_by(&self) -> Option<SortBy> {
+        match self {
+            PlanetField::Name => SortBy::increasing_or_decreasing(),
+            PlanetField::Radius | PlanetField::Moons => SortBy::decreasing_or_increasing(),
+        }
+    }
+
+    fn null_handling(&self) -> NullHandling {
+        NullHandling::Last
+    }
+}
+
+impl FilterBy<Planet> for PlanetField {
+    fn filter_by(&self, item: &Planet, value: &str) -> bool {
+        match self {
+            PlanetField::Name => item.name.to_lowercase().contains(&value.to_lowercase()),
+            _ => true,
+        }
+    }
+}
+
+fn planets() -> Vec<Planet> {
+    let planet = |name, radius, moons| Planet {
+        name,
+        radius,
+        moons,
+    };
+    vec![
+        planet("Mercury", 2_440, Some(0)),
+        planet("Venus", 6_052, Some(0)),
+        planet("Earth", 6_371, Some(1)),
+        planet("Mars", 3_390, Some(2)),
+        planet("Jupiter", 69_911, Some(95)),
+        planet("Saturn", 58_232, Some(146)),
+        planet("Uranus", 25_362, Some(28)),
+        planet("Neptune", 24_622, Some(16)),
+        planet("Ceres", 470, Some(0)),
+        planet("Pluto", 1_188, Some(5)),
+        planet("Haumea", 780, Some(2)),
+        planet("Makemake", 715, None),
+        planet("Eris", 1_163, Some(1)),
+    ]
+}
@@ -1,53 +1,51 @@
 use dioxus::prelude::*;
-use dioxus_sortable::{use_sorter, NullHandling, PartialOrdBy, SortBy, Sortable, Th, ThStatus};
+use dioxus_sortable::{
+    use_filter, use_sorter, FilterBy, FilteredSorted, NullHandling, NullableCell, PartialOrdBy,
+    SortBy, Sortable, Th, ThStatus,
+};
 
 fn main() {
     wasm_logger::init(wasm_logger::Config::new(log::Level::Info));
-    dioxus_web::launch(app);
+    dioxus_web::launch::launch_cfg(app, dioxus_web::Config::new());
 }
 
-fn app(cx: Scope) -> Element {
+fn app() -> Element {
     // Trigger pulling our data "externally"
-    let future = use_future(cx, (), |_| load_prime_ministers());
+    let future = use_resource(load_prime_ministers);
 
-    cx.render(rsx! {
+    rsx! {
         h1 { "Birthplaces of British prime ministers" }
-        future.value().map_or_else(
+        match &*future.read() {
             // Show a loading message while the data is being fetched
-            || rsx!{
+            None => rsx! {
                 p { "Loading..." }
             },
             // Pass the data onto our table component
-            |data| rsx!{
-                PrimeMinisters{ data: data.to_vec(), }
-            })
-    })
+            Some(data) => rsx! {
+                PrimeMinisters { data: data.clone() }
+            },
+        }
+    }
 }
 
 /// Creates a sortable table of prime ministers and their birthplaces. Can be filtered by name.
 ///
 /// Each column header can be clicked to sort by that column. The current sort state is displayed in the header.
-#[allow(non_snake_case)]
-#[inline_props]
-fn PrimeMinisters(cx: Scope, data: Vec<Person>) -> Element {
-    // Sorter hook must be called unconditionally
-    let sorter = use_sorter::<PersonField>(cx);
-    let name = use_state(cx, || "".to_string());
+#[component]
+fn PrimeMinisters(data: Vec<Person>) -> Element {
+    // Sorter and filter hooks must be called unconditionally
+    let sorter = use_sorter::<PersonField>();
+    // Our search box only looks at names
+    let filter = use_filter(&[PersonField::Name]);
 
-    // Filter the data
-    let mut data = data
-        .to_owned()
-        .into_iter()
-        .filter(|row| row.name.to_lowercase().contains(&name.get().to_lowercase()))
-        .collect::<Vec<_>>();
-    // Sort the data. Unlike use_sorter, may be skipped
-    sorter.sort(data.as_mut_slice());
+    // Filter and sort the data in one pass. Unlike the hooks, may be skipped. Our data isn't cloned, we get a view over it instead
+    let data = FilteredSorted::new(&data, &filter, &sorter);
 
-    cx.render(rsx! {
+    rsx! {
         // Our simple search box
         input {
             placeholder: "Search by name",
-            oninput: move |evt| name.set(evt.value.clone()),
+            oninput: move |evt| filter.set_search(evt.value()),
         }
 
         // Render a table like we would any other except for the `Th` component
@@ -74,30 +72,24 @@ fn PrimeMinisters(cx: Scope, data: Vec<Person>) -> Element {
                 }
             }
             tbody {
-                // Iterate over our Person data like we would any other.
-                data.iter().map(|row| {
-                    rsx! {
-                        tr {
-                            td { "{row.name}" }
-                            td {
-                                match row.left_office {
-                                    None => rsx!(em { "Present" }),
-                                    Some(ref x) => rsx!("{x}"),
-                                }
-                            }
-                            td {
-                                match row.birthplace {
-                                    Birthplace::Unknown => rsx!(em { "Unknown" }),
-                                    Birthplace::City(ref city) => rsx!("{city}")
-                                }
-                            }
-                            td { "{row.country}" }
+                // Iterate over our Person data like we would any other. Keying rows lets Dioxus move them when re-sorted rather than re-render them. Names alone aren't unique
+                for (key, row) in data.keyed(|row| format!("{}-{:?}", row.name, row.left_office)) {
+                    tr {
+                        key: "{key}",
+                        td { "{row.name}" }
+                        // NULL cells render as "Unknown" unless given a placeholder
+                        NullableCell { value: row.left_office, placeholder: rsx!(em { "Present" }) }
+                        // Or ask the sorter which cells it sorts as NULL so the two always agree
+                        NullableCell {
+                            value: Some(row.birthplace.clone()),
+                            null: sorter.is_null(&PersonField::Birthplace, row),
                         }
+                        td { "{row.country}" }
                     }
-                })
+                }
             }
         }
-    })
+    }
 }
 
 /// Our per-row data type that we want to sort
@@ -119,6 +111,15 @@ enum Birthplace {
     City(String),
 }
 
+impl std::fmt::Display for Birthplace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Birthplace::Unknown => Ok(()),
+            Birthplace::City(city) => write!(f, "{city}"),
+        }
+    }
+}
+
 /// This is the field we want to sort by. Each variant corresponds to a column in our table or field in our Person struct. Keep it simple, use `{struct}Field`.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 enum PersonField {
@@ -154,6 +155,24 @@ impl PartialOrdBy<Person> for PersonField {
     }
 }
 
+/// This trait decides how rows are filtered by each field. The value is what the user typed
+impl FilterBy<Person> for PersonField {
+    fn filter_by(&self, row: &Person, value: &str) -> bool {
+        let value = value.to_lowercase();
+        match self {
+            PersonField::Name => row.name.to_lowercase().contains(&value),
+            PersonField::LeftOffice => row
+                .left_office
+                .is_some_and(|year| year.to_string().contains(&value)),
+            PersonField::Birthplace => match row.birthplace {
+                Birthplace::Unknown => false,
+                Birthplace::City(ref city) => city.to_lowercase().contains(&value),
+            },
+            PersonField::Country => row.country.to_lowercase().contains(&value),
+        }
+    }
+}
+
 /// This trait decides how fields (columns) may be sorted
 impl Sortable for PersonField {
     fn sort_by(&self) -> Option<SortBy> {
@@ -0,0 +1,202 @@
+use dioxus::prelude::*;
+use dioxus_sortable::{
+    use_paginator, Direction, FieldName, NullHandling, NullableCell, Paginator, SortBy, SortMode,
+    SortRequest, Sortable, Th, UseSorterBuilder,
+};
+
+fn main() {
+    wasm_logger::init(wasm_logger::Config::new(log::Level::Info));
+    dioxus_web::launch::launch_cfg(app, dioxus_web::Config::new());
+}
+
+/// A table of cities sorted and paged by a (mock) server. The browser never sees more than one page.
+///
+/// Clicking a header changes the sorter's state which we turn into a query. The server replies with the page of rows already sorted.
+fn app() -> Element {
+    // Remote mode: the sorter only tracks header state and never sorts rows itself
+    let sorter = UseSorterBuilder::<CityField>::default()
+        .with_mode(SortMode::Remote)
+        .use_sorter();
+    let paginator = use_paginator(5);
+    // Re-sorting shows a different set of rows so return to the first page
+    let sorter = sorter.on_change(move |_, _| paginator.first());
+    // Total rows on the server from the last reply. Peeked so that updating it doesn't refetch
+    let mut total = use_signal(|| 0);
+
+    // Refetches whenever the sort or page changes as both are read here
+    let query = use_memo(move || {
+        let sort = sorter
+            .sort_request()
+            .map(|request| request.to_query())
+            .unwrap_or_default();
+        let page = paginator.page(*total.peek());
+        format!("{sort}&page={page}&per_page={}", paginator.page_size())
+    });
+    let reply = use_resource(move || async move {
+        let reply = fetch_cities(&query()).await;
+        total.set(reply.total);
+        reply
+    });
+
+    rsx! {
+        h1 { "Largest cities, sorted on the server" }
+        p {
+            "Query sent: "
+            code { "?{query}" }
+        }
+        table {
+            thead {
+                tr {
+                    Th { sorter: sorter, field: CityField::Name, "City" }
+                    Th { sorter: sorter, field: CityField::Country, "Country" }
+                    Th {
+                        sorter: sorter,
+                        field: CityField::Population,
+                        sublabel: "millions",
+                        "Population"
+                    }
+                }
+            }
+            tbody {
+                // Rows arrive in order so render them as they are
+                if let Some(reply) = &*reply.read() {
+                    for city in reply.rows.iter() {
+                        tr { key: "{city.name}",
+                            td { "{city.name}" }
+                            td { "{city.country}" }
+                            NullableCell { value: city.population.map(|population| format!("{population:.1}")) }
+                        }
+                    }
+                }
+            }
+        }
+        Paginator { paginator: paginator, total: total(), page_sizes: vec![5, 10] }
+    }
+}
+
+/// Row type. Shared by client and server in this example.
+#[derive(Clone, Debug, PartialEq)]
+struct City {
+    name: &'static str,
+    country: &'static str,
+    /// Metro population in millions. None if unknown
+    population: Option<f64>,
+}
+
+/// Our columns. Unlike a local table no `PartialOrdBy` is needed: the server does the comparing.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum CityField {
+    Name,
+    Country,
+    #[default]
+    Population,
+}
+
+impl CityField {
+    const ALL: [CityField; 3] = [CityField::Name, CityField::Country, CityField::Population];
+}
+
+/// Names the server knows our columns by. Only these can appear in a query
+impl FieldName for CityField {
+    fn field_name(&self) -> &'static str {
+        match self {
+            CityField::Name => "name",
+            CityField::Country => "country",
+            CityField::Population => "population",
+        }
+    }
+}
+
+impl Sortable for CityField {
+    fn sort_by(&self) -> Option<SortBy> {
+        match self {
+            CityField::Population => SortBy::decreasing_or_increasing(),
+            _ => SortBy::increasing_or_decreasing(),
+        }
+    }
+
+    // Like a database's default: unknown populations go last when ascending, first when descending
+    fn null_handling(&self) -> NullHandling {
+        NullHandling::SqlDefault
+    }
+}
+
+/// A page of rows and how many there are in total.
+#[derive(Clone, Debug, PartialEq)]
+struct CityPage {
+    rows: Vec<City>,
+    total: usize,
+}
+
+/// Our mock server. In a real app this would be an HTTP request and the server would pass the `SortRequest` on to its database e.g., as `ORDER BY {request}`.
+async fn fetch_cities(query: &str) -> CityPage {
+    let param = |name: &str| {
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+            .and_then(|value| value.parse::<usize>().ok())
+    };
+    let (page, per_page) = (param("page").unwrap_or(0), param("per_page").unwrap_or(5));
+    let mut rows = cities();
+
+    // Untrusted input is checked against our known fields
+    if let Some(request) = SortRequest::from_query(query, &CityField::ALL) {
+        if request.nulls == NullHandling::Remove {
+            rows.retain(|city| city.population.is_some());
+        }
+        rows.sort_by(|a, b| {
+            let ord = match request.field_name {
+                "name" => a.name.cmp(b.name),
+                "country" => a.country.cmp(b.country),
+                _ => match (a.population, b.population) {
+                    (Some(a), Some(b)) => a.total_cmp(&b),
+                    // NULLs keep their place whatever the direction
+                    (a, b) => {
+                        let nulls_first = request.nulls.nulls_first(request.direction);
+                        return (a.is_none() != nulls_first).cmp(&(b.is_none() != nulls_first));
+                    }
+                },
+            };
+            match request.direction {
+                Direction::Ascending => ord,
+                Direction::Descending => ord.reverse(),
+            }
+        });
+    }
+
+    let total = rows.len();
+    let rows = rows
+        .into_iter()
+        .skip(page * per_page)
+        .take(per_page)
+        .collect();
+    CityPage { rows, total }
+}
+
+fn cities() -> Vec<City> {
+    let city = |name, country, population| City {
+        name,
+        country,
+        population,
+    };
+    vec![
+        city("Tokyo", "Japan", Some(37.1)),
+        city("Delhi", "India", Some(33.8)),
+        city("Shanghai", "China", Some(29.9)),
+        city("Dhaka", "Bangladesh", Some(23.9)),
+        city("São Paulo", "Brazil", Some(22.8)),
+        city("Cairo", "Egypt", Some(22.6)),
+        city("Mexico City", "Mexico", Some(22.5)),
+        city("Beijing", "China", Some(22.2)),
+        city("Mumbai", "India", Some(21.7)),
+        city("Osaka", "Japan", Some(19.0)),
+        city("Chongqing", "China", None),
+        city("Karachi", "Pakistan", Some(17.6)),
+        city("Kinshasa", "DR Congo", None),
+        city("Lagos", "Nigeria", Some(16.0)),
+        city("Istanbul", "Türkiye", Some(15.8)),
+        city("Buenos Aires", "Argentina", Some(15.5)),
+        city("Kolkata", "India", Some(15.4)),
+        city("Manila", "Philippines", Some(14.7)),
+    ]
+}
@@ -0,0 +1,113 @@
+use dioxus::prelude::*;
+use dioxus_sortable::{
+    Direction, NullHandling, NullableCell, PartialOrdBy, SortBy, Sortable, SorterState, Th,
+    UseSorterBuilder,
+};
+use std::cmp::Ordering;
+
+/// Renders a table of cities to HTML, sorted server-side by the field and direction given on the command line e.g., from a request's query string. Output is the same on every run so can be cached or diffed.
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let field = match args.next().as_deref() {
+        Some("population") => CityField::Population,
+        Some("founded") => CityField::Founded,
+        _ => CityField::Name,
+    };
+    let direction = match args.next().as_deref() {
+        Some("descending") => Direction::Descending,
+        _ => Direction::Ascending,
+    };
+    let state = SorterState {
+        field,
+        direction,
+        sorted: true,
+    };
+
+    let mut vdom = VirtualDom::new_with_props(Cities, CitiesProps { state });
+    vdom.rebuild_in_place();
+    println!("{}", dioxus_ssr::render(&vdom));
+}
+
+/// The same component could be hydrated or served over liveview: the sorter starts in the given state and carries on from there.
+#[component]
+fn Cities(state: SorterState<CityField>) -> Element {
+    let sorter = UseSorterBuilder::default().with_state(state).use_sorter();
+    let mut cities = cities();
+    sorter.sort(&mut cities);
+
+    rsx! {
+        table {
+            thead {
+                tr {
+                    Th { sorter: sorter, field: CityField::Name, "Name" }
+                    Th { sorter: sorter, field: CityField::Population, "Population" }
+                    Th { sorter: sorter, field: CityField::Founded, "Founded" }
+                }
+            }
+            tbody {
+                for city in cities {
+                    tr { key: "{city.name}",
+                        td { "{city.name}" }
+                        td { "{city.population}" }
+                        NullableCell { value: city.founded }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct City {
+    name: &'static str,
+    population: u32,
+    /// Year founded. None if not known
+    founded: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum CityField {
+    #[default]
+    Name,
+    Population,
+    Founded,
+}
+
+impl PartialOrdBy<City> for CityField {
+    fn partial_cmp_by(&self, a: &City, b: &City) -> Option<Ordering> {
+        match self {
+            CityField::Name => a.name.partial_cmp(b.name),
+            CityField::Population => a.population.partial_cmp(&b.population),
+            CityField::Founded => a.founded?.partial_cmp(&b.founded?),
+        }
+    }
+}
+
+impl Sortable for CityField {
+    fn sort_by(&self) -> Option<SortBy> {
+        match self {
+            CityField::Name | CityField::Founded => SortBy::increasing_or_decreasing(),
+            CityField::Population => SortBy::decreasing_or_increasing(),
+        }
+    }
+
+    fn null_handling(&self) -> NullHandling {
+        NullHandling::Last
+    }
+}
+
+fn cities() -> Vec<City> {
+    let city = |name, population, founded| City {
+        name,
+        population,
+        founded,
+    };
+    vec![
+        city("Edinburgh", 514_990, Some(1124)),
+        city("Glasgow", 635_130, None),
+        city("Aberdeen", 198_590, Some(1179)),
+        city("Dundee", 148_210, None),
+        city("Inverness", 47_790, Some(1158)),
+        city("Perth", 47_430, Some(1210)),
+    ]
+}
@@ -0,0 +1,248 @@
+//! Footer aggregates such as totals and averages computed over the rows in view.
+//!
+//! Aggregates take rows in view order so they see exactly what the table shows. Pass them rows from the same pipeline that sorts and filters e.g., [`crate::TableView::aggregate`] or [`crate::FilteredSorted::aggregate`], and render them in a [`crate::TFoot`] with a [`Footer`] per column. For example:
+//! ```rust
+//! # use dioxus::prelude::*;
+//! # use dioxus_sortable::aggregates::{Count, Footer, Mean, Sum};
+//! # use dioxus_sortable::TFoot;
+//! #[derive(Clone, PartialEq)]
+//! struct Order {
+//!     customer: String,
+//!     total: f64,
+//!     /// Days to ship. None if not shipped yet
+//!     shipped_in: Option<u32>,
+//! }
+//!
+//! #[component]
+//! fn Footers(orders: Vec<Order>) -> Element {
+//!     let footers = vec![
+//!         Footer::text(Count),
+//!         Footer::text(Sum(|order: &Order| Some(order.total))),
+//!         // Unshipped orders are NULL so left out of the mean
+//!         Footer::new(Mean(|order: &Order| order.shipped_in.map(f64::from)), |mean| {
+//!             match mean {
+//!                 Some(mean) => rsx!("{mean:.1} days"),
+//!                 None => rsx!("-"),
+//!             }
+//!         }),
+//!     ];
+//!     rsx! {
+//!         TFoot { footers: footers, rows: orders }
+//!     }
+//! }
+//! ```
+//!
+//! Like sorting, `None` values are treated as `NULL` and left out: they don't count towards a [`Sum`], [`Mean`], [`Min`] or [`Max`]. Implement [`Aggregate`] or use [`Custom`] for anything else.
+
+use dioxus::prelude::*;
+use std::{fmt, rc::Rc};
+
+type RenderFooter<T> = Option<Rc<dyn Fn(&[&T]) -> Element>>;
+
+/// Computes a value from rows of `T` e.g., a column total. Rows are given in view order.
+pub trait Aggregate<T> {
+    /// Type of the computed value.
+    type Output;
+
+    /// Computes the value from the rows.
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> Self::Output;
+}
+
+/// Counts rows.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Count;
+
+impl<T> Aggregate<T> for Count {
+    type Output = usize;
+
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> usize {
+        rows.count()
+    }
+}
+
+/// Adds up the values returned by a fn. `None` values are left out. No rows adds up to zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Sum<G>(pub G);
+
+impl<T, V: std::iter::Sum<V>, G: Fn(&T) -> Option<V>> Aggregate<T> for Sum<G> {
+    type Output = V;
+
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> V {
+        V::sum(rows.filter_map(|row| (self.0)(row)))
+    }
+}
+
+/// Averages the values returned by a fn. `None` values are left out. Returns `None` if there are no values.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Mean<G>(pub G);
+
+impl<T, G: Fn(&T) -> Option<f64>> Aggregate<T> for Mean<G> {
+    type Output = Option<f64>;
+
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> Option<f64> {
+        let (sum, count) = rows
+            .filter_map(|row| (self.0)(row))
+            .fold((0.0, 0_usize), |(sum, count), value| {
+                (sum + value, count + 1)
+            });
+        (count > 0).then(|| sum / count as f64)
+    }
+}
+
+/// Finds the smallest value returned by a fn. `None` values and those that don't compare e.g., `f64::NAN` are left out. Returns the first of equal values or `None` if there are no values.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Min<G>(pub G);
+
+impl<T, V: PartialOrd, G: Fn(&T) -> Option<V>> Aggregate<T> for Min<G> {
+    type Output = Option<V>;
+
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> Option<V> {
+        extreme(rows.filter_map(|row| (self.0)(row)), |value, best| {
+            value < best
+        })
+    }
+}
+
+/// Finds the largest value returned by a fn. Otherwise behaves like [`Min`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Max<G>(pub G);
+
+impl<T, V: PartialOrd, G: Fn(&T) -> Option<V>> Aggregate<T> for Max<G> {
+    type Output = Option<V>;
+
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> Option<V> {
+        extreme(rows.filter_map(|row| (self.0)(row)), |value, best| {
+            value > best
+        })
+    }
+}
+
+/// Returns the first value that no later value beats. Skips values that don't compare to themselves.
+fn extreme<V: PartialOrd>(
+    values: impl Iterator<Item = V>,
+    beats: impl Fn(&V, &V) -> bool,
+) -> Option<V> {
+    values
+        .filter(|value| value.partial_cmp(value).is_some())
+        .fold(None, |best, value| match best {
+            Some(best) if !beats(&value, &best) => Some(best),
+            _ => Some(value),
+        })
+}
+
+/// Computes anything else from a fn given the rows e.g., the number of distinct values.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Custom<G>(pub G);
+
+impl<T, O, G: Fn(&mut dyn Iterator<Item = &T>) -> O> Aggregate<T> for Custom<G> {
+    type Output = O;
+
+    fn aggregate(&self, rows: &mut dyn Iterator<Item = &T>) -> O {
+        (self.0)(rows)
+    }
+}
+
+/// Renders a column's footer cell in a [`crate::TFoot`]: an aggregate and how to show it, some fixed text or nothing.
+pub struct Footer<T> {
+    render: RenderFooter<T>,
+}
+
+impl<T> Clone for Footer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            render: self.render.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Footer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.render, &other.render) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Footer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Footer")
+            .field("empty", &self.render.is_none())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Footer<T> {
+    /// Renders the result of an aggregate. Use for outputs that need formatting or may be `None` e.g., `|mean: Option<f64>| rsx!(...)`.
+    pub fn new<A: Aggregate<T> + 'static>(
+        aggregate: A,
+        render: impl Fn(A::Output) -> Element + 'static,
+    ) -> Self {
+        Self {
+            render: Some(Rc::new(move |rows| {
+                render(aggregate.aggregate(&mut rows.iter().copied()))
+            })),
+        }
+    }
+
+    /// Renders the result of an aggregate as text e.g., a [`Count`] or [`Sum`].
+    pub fn text<A: Aggregate<T> + 'static>(aggregate: A) -> Self
+    where
+        A::Output: fmt::Display,
+    {
+        Self::new(aggregate, |output| rsx!("{output}"))
+    }
+
+    /// Renders fixed text e.g., "Total" in the first column.
+    pub fn label(text: &'static str) -> Self {
+        Self {
+            render: Some(Rc::new(move |_| rsx!("{text}"))),
+        }
+    }
+
+    /// Renders an empty cell.
+    pub fn empty() -> Self {
+        Self { render: None }
+    }
+
+    /// Renders the cell's contents from rows in view order.
+    #[cfg(feature = "components")]
+    pub(crate) fn render(&self, rows: &[&T]) -> Element {
+        match &self.render {
+            Some(render) => render(rows),
+            None => rsx! {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aggregate<A: Aggregate<Option<f64>>>(aggregate: A, rows: &[Option<f64>]) -> A::Output {
+        aggregate.aggregate(&mut rows.iter())
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let rows = [Some(2.0), None, Some(f64::NAN), Some(-1.0), Some(2.0)];
+        let value = |row: &Option<f64>| *row;
+        assert_eq!(aggregate(Count, &rows), 5);
+        assert!(aggregate(Sum(value), &rows).is_nan());
+        let not_nan = |row: &Option<f64>| row.filter(|v| !v.is_nan());
+        assert_eq!(aggregate(Sum(not_nan), &rows), 3.0);
+        assert_eq!(aggregate(Mean(not_nan), &rows), Some(1.0));
+        // NaN doesn't compare so is left out
+        assert_eq!(aggregate(Min(value), &rows), Some(-1.0));
+        assert_eq!(aggregate(Max(value), &rows), Some(2.0));
+        let nulls = Custom(|rows: &mut dyn Iterator<Item = &Option<f64>>| {
+            rows.filter(|row| row.is_none()).count()
+        });
+        assert_eq!(aggregate(nulls, &rows), 1);
+
+        // No values
+        assert_eq!(aggregate(Sum(value), &[None]), 0.0);
+        assert_eq!(aggregate(Mean(value), &[]), None);
+        assert_eq!(aggregate(Min(value), &[None]), None);
+    }
+}
@@ -0,0 +1,481 @@
+//! Ready-made comparison helpers for use inside [`crate::PartialOrdBy::partial_cmp_by`].
+//!
+//! Each helper returns `Option<Ordering>` so it can be returned directly. `None` is treated as `NULL`. For example:
+//! ```rust
+//! # use dioxus_sortable::{comparators, PartialOrdBy};
+//! # use std::cmp::Ordering;
+//! struct File {
+//!     name: String,
+//!     owner: Option<String>,
+//! }
+//!
+//! # #[derive(PartialEq)]
+//! enum FileField {
+//!     Name,
+//!     Owner,
+//! }
+//!
+//! impl PartialOrdBy<File> for FileField {
+//!     fn partial_cmp_by(&self, a: &File, b: &File) -> Option<Ordering> {
+//!         match self {
+//!             // "file2" before "file10"
+//!             FileField::Name => comparators::natural(&a.name, &b.name),
+//!             // Missing owners are NULL, otherwise ignore case
+//!             FileField::Owner => comparators::option_by(&a.owner, &b.owner, |a, b| {
+//!                 comparators::case_insensitive(a, b)
+//!             }),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Or wrap text fields in [`CaseInsensitive`] or [`AccentInsensitive`] so their `PartialOrd` does the right thing wherever they're compared.
+//!
+//! Moving existing sort code over? Comparisons written for [`slice::sort_by`] or `itertools`' `sorted_by` return a plain [`Ordering`]. Adapt them with [`total`] or key fns with [`by_key`] for [`crate::UseSorter::with_comparator`] and [`crate::SortFields::field_by`]. With the `ordered-float` feature, [`ordered_float()`] keeps `NaN` with the `NULL`s.
+//!
+//! Numbers and dates held as text sort as text: "10" before "9" and "02/01/2024" before "31/12/2023". Compare them by value with [`locale_number`] or, with the `chrono` or `time` features, by parsing dates e.g., [`chrono_date`] or [`time_date`]. Text that doesn't parse is `NULL`.
+
+use std::{cmp::Ordering, fmt, iter::Peekable, str::Chars};
+
+/// Compares strings in natural (human) order: runs of digits are compared by their numeric value. For example "file2" sorts before "file10". Otherwise compares by character.
+pub fn natural(a: &str, b: &str) -> Option<Ordering> {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Some(Ordering::Equal),
+            (None, Some(_)) => return Some(Ordering::Less),
+            (Some(_), None) => return Some(Ordering::Greater),
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let ord = cmp_digits(&take_digits(&mut a), &take_digits(&mut b));
+                if ord != Ordering::Equal {
+                    return Some(ord);
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return Some(x.cmp(&y));
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+fn take_digits(chars: &mut Peekable<Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        digits.push(c);
+    }
+    digits
+}
+
+/// Compares two runs of ASCII digits by value without parsing (no overflow).
+fn cmp_digits(a: &str, b: &str) -> Ordering {
+    let (a_trim, b_trim) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+    a_trim
+        .len()
+        .cmp(&b_trim.len())
+        .then_with(|| a_trim.cmp(b_trim))
+        // Same value: fewer leading zeros first
+        .then_with(|| a.len().cmp(&b.len()))
+}
+
+/// Compares strings ignoring case. Uses Unicode lowercase mapping.
+pub fn case_insensitive(a: &str, b: &str) -> Option<Ordering> {
+    let a = a.chars().flat_map(char::to_lowercase);
+    let b = b.chars().flat_map(char::to_lowercase);
+    Some(a.cmp(b))
+}
+
+/// Compares strings ignoring accents and other diacritics e.g., "é" sorts with "e" and "Å" with "A". Case is still compared. Strings are decomposed (NFD) and nonspacing marks dropped. Requires the `icu` feature.
+///
+/// Unlike [`locale`], the order is the same in every language. For example Swedish sorts "ä" after "z" but here it sorts with "a".
+#[cfg(feature = "icu")]
+pub fn accent_insensitive(a: &str, b: &str) -> Option<Ordering> {
+    let nfd = icu_normalizer::DecomposingNormalizer::new_nfd();
+    Some(strip_accents(&nfd, a).cmp(strip_accents(&nfd, b)))
+}
+
+/// Iterates over a string's characters without diacritics.
+#[cfg(feature = "icu")]
+fn strip_accents<'a>(
+    nfd: &'a icu_normalizer::DecomposingNormalizer,
+    s: &'a str,
+) -> impl Iterator<Item = char> + 'a {
+    use icu_properties::{maps, GeneralCategory};
+
+    let categories = maps::general_category();
+    nfd.normalize_iter(s.chars())
+        .filter(move |c| categories.get(*c) != GeneralCategory::NonspacingMark)
+}
+
+/// A string ordered ignoring case by [`case_insensitive`]. Store text fields as `CaseInsensitive<String>` so `partial_cmp` in [`crate::PartialOrdBy`] needs no special treatment. Equality ignores case too.
+///
+/// Displays the original string.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CaseInsensitive<S>(pub S);
+
+impl<S: AsRef<str>> PartialEq for CaseInsensitive<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: AsRef<str>> Eq for CaseInsensitive<S> {}
+
+impl<S: AsRef<str>> PartialOrd for CaseInsensitive<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str>> Ord for CaseInsensitive<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        case_insensitive(self.0.as_ref(), other.0.as_ref()).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for CaseInsensitive<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<S> From<S> for CaseInsensitive<S> {
+    fn from(s: S) -> Self {
+        Self(s)
+    }
+}
+
+/// A string ordered ignoring accents by [`accent_insensitive`]. Like [`CaseInsensitive`] but for diacritics. Requires the `icu` feature.
+///
+/// Each comparison decomposes both strings. For large tables consider a [`crate::ComputedField`] of the stripped strings.
+#[cfg(feature = "icu")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AccentInsensitive<S>(pub S);
+
+#[cfg(feature = "icu")]
+impl<S: AsRef<str>> PartialEq for AccentInsensitive<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "icu")]
+impl<S: AsRef<str>> Eq for AccentInsensitive<S> {}
+
+#[cfg(feature = "icu")]
+impl<S: AsRef<str>> PartialOrd for AccentInsensitive<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "icu")]
+impl<S: AsRef<str>> Ord for AccentInsensitive<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        accent_insensitive(self.0.as_ref(), other.0.as_ref()).unwrap_or(Ordering::Equal)
+    }
+}
+
+#[cfg(feature = "icu")]
+impl<S: fmt::Display> fmt::Display for AccentInsensitive<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "icu")]
+impl<S> From<S> for AccentInsensitive<S> {
+    fn from(s: S) -> Self {
+        Self(s)
+    }
+}
+
+/// Compares optional values treating `None` as `NULL`. Avoids the gotcha of `Option`'s own ordering where `None` is less than `Some`.
+pub fn option<T: PartialOrd>(a: &Option<T>, b: &Option<T>) -> Option<Ordering> {
+    option_by(a, b, |a, b| a.partial_cmp(b))
+}
+
+/// Compares optional values with a custom comparison, treating `None` as `NULL`.
+pub fn option_by<T>(
+    a: &Option<T>,
+    b: &Option<T>,
+    cmp: impl FnOnce(&T, &T) -> Option<Ordering>,
+) -> Option<Ordering> {
+    cmp(a.as_ref()?, b.as_ref()?)
+}
+
+/// Compares floats treating `NaN` as `NULL`. The same as [`f64::partial_cmp`] but explicit about intent.
+pub fn nan_as_null(a: f64, b: f64) -> Option<Ordering> {
+    a.partial_cmp(&b)
+}
+
+/// Adapts a total order e.g., [`Ord::cmp`], [`f64::total_cmp`] or a closure from existing [`slice::sort_by`] code, into a comparison returning `Option<Ordering>`. Nothing is `NULL`.
+///
+/// ```rust
+/// # use dioxus_sortable::comparators::total;
+/// # use std::cmp::Ordering;
+/// let cmp = total(|a: &(u8, &str), b: &(u8, &str)| a.0.cmp(&b.0).then_with(|| b.1.cmp(a.1)));
+/// assert_eq!(cmp(&(1, "a"), &(1, "b")), Some(Ordering::Greater));
+/// ```
+pub fn total<T: ?Sized>(cmp: impl Fn(&T, &T) -> Ordering) -> impl Fn(&T, &T) -> Option<Ordering> {
+    move |a, b| Some(cmp(a, b))
+}
+
+/// Adapts a key fn like those given to [`slice::sort_by_key`] into a comparison of keys. A key that doesn't compare e.g., `f64::NAN`, is `NULL`.
+///
+/// ```rust
+/// # use dioxus_sortable::comparators::by_key;
+/// # use std::cmp::Ordering;
+/// let cmp = by_key(|name: &&str| name.len());
+/// assert_eq!(cmp(&"ab", &"c"), Some(Ordering::Greater));
+/// ```
+pub fn by_key<T: ?Sized, K: PartialOrd>(
+    key: impl Fn(&T) -> K,
+) -> impl Fn(&T, &T) -> Option<Ordering> {
+    move |a, b| key(a).partial_cmp(&key(b))
+}
+
+/// Compares [`ordered_float::OrderedFloat`]s treating `NaN` as `NULL`. `OrderedFloat`'s own order puts `NaN` above every number so it would sort last ascending but first descending, apart from the other `NULL`s. Requires the `ordered-float` feature.
+///
+/// Values already known not to be `NaN` e.g., [`ordered_float::NotNan`], have a total order so compare them with their `Ord`.
+#[cfg(feature = "ordered-float")]
+pub fn ordered_float<T: PartialOrd>(
+    a: &ordered_float::OrderedFloat<T>,
+    b: &ordered_float::OrderedFloat<T>,
+) -> Option<Ordering> {
+    a.0.partial_cmp(&b.0)
+}
+
+/// Compares numbers written for a locale by value e.g., "1.234,5" in German or "1,234.5" in English. `decimal` is the locale's decimal separator. Any other `.`, `,`, `'`, `_` or space is taken as digit grouping and ignored. Text that isn't a number is `NULL`.
+///
+/// ```rust
+/// # use dioxus_sortable::comparators::locale_number;
+/// # use std::cmp::Ordering;
+/// assert_eq!(locale_number("1.234,5", "999", ','), Some(Ordering::Greater));
+/// assert_eq!(locale_number("n/a", "1", '.'), None);
+/// ```
+pub fn locale_number(a: &str, b: &str, decimal: char) -> Option<Ordering> {
+    parse_number(a, decimal)?.partial_cmp(&parse_number(b, decimal)?)
+}
+
+/// Parses a number with the given decimal separator, ignoring digit grouping.
+fn parse_number(s: &str, decimal: char) -> Option<f64> {
+    let number = s
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c == decimal => Some('.'),
+            '.' | ',' | '\'' | '_' => None,
+            c if c.is_whitespace() => None,
+            // Unicode minus sign
+            '\u{2212}' => Some('-'),
+            c => Some(c),
+        })
+        .collect::<String>();
+    number.parse().ok()
+}
+
+/// Compares dates written in a [`chrono` format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) e.g., `"%d/%m/%Y"` by date rather than as text. Text that doesn't parse is `NULL`. Requires the `chrono` feature.
+///
+/// ```rust
+/// # use dioxus_sortable::comparators::chrono_date;
+/// # use std::cmp::Ordering;
+/// assert_eq!(chrono_date("02/01/2024", "31/12/2023", "%d/%m/%Y"), Some(Ordering::Greater));
+/// ```
+#[cfg(feature = "chrono")]
+pub fn chrono_date(a: &str, b: &str, format: &str) -> Option<Ordering> {
+    let parse = |s: &str| chrono::NaiveDate::parse_from_str(s.trim(), format).ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+/// Compares dates and times written in a `chrono` format e.g., `"%d/%m/%Y %H:%M"`. Like [`chrono_date`] but for [`chrono::NaiveDateTime`]. Requires the `chrono` feature.
+#[cfg(feature = "chrono")]
+pub fn chrono_datetime(a: &str, b: &str, format: &str) -> Option<Ordering> {
+    let parse = |s: &str| chrono::NaiveDateTime::parse_from_str(s.trim(), format).ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+/// Compares RFC 3339 timestamps e.g., "2024-05-01T09:00:00+02:00" by the instant they describe. Unlike comparing as text, timestamps with different offsets are ordered correctly. Text that doesn't parse is `NULL`. Requires the `chrono` feature.
+#[cfg(feature = "chrono")]
+pub fn chrono_rfc3339(a: &str, b: &str) -> Option<Ordering> {
+    let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s.trim()).ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+/// Compares dates written in a [`time` format](https://time-rs.github.io/book/api/format-description.html) by date rather than as text. Parse the format once and keep it rather than parsing it per comparison. Text that doesn't parse is `NULL`. Requires the `time` feature.
+///
+/// ```rust
+/// # use dioxus_sortable::comparators::time_date;
+/// # use std::cmp::Ordering;
+/// let format = time::format_description::parse_borrowed::<2>("[day]/[month]/[year]").unwrap();
+/// assert_eq!(time_date("02/01/2024", "31/12/2023", &format), Some(Ordering::Greater));
+/// ```
+#[cfg(feature = "time")]
+pub fn time_date(
+    a: &str,
+    b: &str,
+    format: &(impl time::parsing::Parsable + ?Sized),
+) -> Option<Ordering> {
+    let parse = |s: &str| time::Date::parse(s.trim(), format).ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+/// Compares dates and times written in a `time` format. Like [`time_date`] but for [`time::PrimitiveDateTime`]. Requires the `time` feature.
+#[cfg(feature = "time")]
+pub fn time_datetime(
+    a: &str,
+    b: &str,
+    format: &(impl time::parsing::Parsable + ?Sized),
+) -> Option<Ordering> {
+    let parse = |s: &str| time::PrimitiveDateTime::parse(s.trim(), format).ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+/// Compares RFC 3339 timestamps by the instant they describe. Like [`chrono_rfc3339`] but parsed with `time`. Requires the `time` feature.
+#[cfg(feature = "time")]
+pub fn time_rfc3339(a: &str, b: &str) -> Option<Ordering> {
+    use time::format_description::well_known::Rfc3339;
+    let parse = |s: &str| time::OffsetDateTime::parse(s.trim(), &Rfc3339).ok();
+    parse(a)?.partial_cmp(&parse(b)?)
+}
+
+/// Creates a collator for [`locale`] from a BCP 47 language tag e.g., `"en"` or `"sv-SE"`. Returns `None` if the tag is invalid or unsupported. Requires the `icu` feature.
+///
+/// Creating a collator is relatively expensive so keep it around rather than creating one per comparison.
+#[cfg(feature = "icu")]
+pub fn collator(locale: &str) -> Option<icu_collator::Collator> {
+    let locale: icu_locid::Locale = locale.parse().ok()?;
+    icu_collator::Collator::try_new(&(&locale).into(), Default::default()).ok()
+}
+
+/// Compares strings using locale-aware collation e.g., "ä" sorts with "a" in German but after "z" in Swedish. See [`collator`]. Requires the `icu` feature.
+#[cfg(feature = "icu")]
+pub fn locale(collator: &icu_collator::Collator, a: &str, b: &str) -> Option<Ordering> {
+    Some(collator.compare(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapters() {
+        let mut values = [2.0, f64::NAN, -1.0];
+        values.sort_by(|a, b| total(f64::total_cmp)(a, b).unwrap());
+        assert_eq!(values[..2], [-1.0, 2.0]);
+        assert_eq!(
+            by_key(|v: &f64| v.abs())(&-3.0, &2.0),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(by_key(|v: &f64| *v)(&f64::NAN, &2.0), None);
+    }
+
+    #[test]
+    #[cfg(feature = "ordered-float")]
+    fn test_ordered_float() {
+        use ordered_float::OrderedFloat;
+        let nan = OrderedFloat(f64::NAN);
+        // NULL rather than greatest
+        assert_eq!(ordered_float(&nan, &OrderedFloat(1.0)), None);
+        let (one, two) = (OrderedFloat(1.0), OrderedFloat(2.0));
+        assert_eq!(ordered_float(&one, &two), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_natural() {
+        let mut names = vec![
+            "file10", "file2", "File1", "file02", "file1", "file", "10", "9",
+        ];
+        names.sort_by(|a, b| natural(a, b).unwrap());
+        assert_eq!(
+            names,
+            vec!["9", "10", "File1", "file", "file1", "file2", "file02", "file10"]
+        );
+        // No overflow on long runs of digits
+        assert_eq!(
+            natural("a99999999999999999999999", "a100000000000000000000000"),
+            Some(Ordering::Less)
+        );
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        assert_eq!(case_insensitive("abc", "ABC"), Some(Ordering::Equal));
+        assert_eq!(case_insensitive("Apple", "banana"), Some(Ordering::Less));
+        assert_eq!(case_insensitive("ÉCOLE", "école"), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_case_insensitive_wrapper() {
+        let mut names = ["bob", "Alice", "alice", "Carol"].map(CaseInsensitive);
+        names.sort();
+        assert_eq!(names.map(|name| name.0), ["Alice", "alice", "bob", "Carol"]);
+        assert_eq!(CaseInsensitive("ABC"), CaseInsensitive("abc"));
+        assert_eq!(CaseInsensitive("Zoë".to_string()).to_string(), "Zoë");
+    }
+
+    #[cfg(feature = "icu")]
+    #[test]
+    fn test_accent_insensitive() {
+        assert_eq!(accent_insensitive("école", "ecole"), Some(Ordering::Equal));
+        assert_eq!(
+            accent_insensitive("Ångström", "Angstrom"),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(accent_insensitive("é", "E"), Some(Ordering::Greater));
+        let mut names = ["Zoë", "Émile", "Eve"].map(AccentInsensitive);
+        names.sort();
+        assert_eq!(names.map(|name| name.0), ["Émile", "Eve", "Zoë"]);
+    }
+
+    #[test]
+    fn test_locale_number() {
+        assert_eq!(locale_number("10", "9", '.'), Some(Ordering::Greater));
+        assert_eq!(
+            locale_number("1,234.5", "1 234.6", '.'),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            locale_number("1.234,5", "1234,5", ','),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(locale_number("−3", "-2", '.'), Some(Ordering::Less));
+        assert_eq!(locale_number("", "1", '.'), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono() {
+        let format = "%d/%m/%Y %H:%M";
+        let (a, b) = ("02/01/2024 09:00", "31/12/2023 23:59");
+        assert_eq!(chrono_datetime(a, b, format), Some(Ordering::Greater));
+        assert_eq!(chrono_datetime(a, "soon", format), None);
+        // Earlier as text but later as an instant
+        let (a, b) = ("2024-05-01T09:00:00+02:00", "2024-05-01T08:00:00+00:00");
+        assert_eq!(chrono_rfc3339(a, b), Some(Ordering::Less));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time() {
+        let format =
+            time::format_description::parse_borrowed::<2>("[day]/[month]/[year] [hour]:[minute]")
+                .unwrap();
+        let (a, b) = ("02/01/2024 09:00", "31/12/2023 23:59");
+        assert_eq!(time_datetime(a, b, &format), Some(Ordering::Greater));
+        assert_eq!(time_datetime(a, "soon", &format), None);
+        let (a, b) = ("2024-05-01T09:00:00+02:00", "2024-05-01T08:00:00+00:00");
+        assert_eq!(time_rfc3339(a, b), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_option() {
+        assert_eq!(option(&Some(1), &Some(2)), Some(Ordering::Less));
+        assert_eq!(option(&None, &Some(2)), None);
+        assert_eq!(option::<i32>(&None, &None), None);
+        assert_eq!(nan_as_null(f64::NAN, 1.0), None);
+    }
+}
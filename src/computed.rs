@@ -0,0 +1,111 @@
+use crate::sort::{project_keys, KeyCmp};
+use crate::Projection;
+use std::{fmt, rc::Rc};
+
+type Compute<T, K> = Rc<dyn Fn(&T) -> Option<K>>;
+
+/// A derived value of rows of `T` e.g., an age from a birth year or the ratio of two fields. Defined once and used for both rendering and sorting. Register it on a sorter with [`crate::UseSorter::with_computed`] so sorting computes each row's value once rather than on every comparison.
+///
+/// Returning `None` marks a row as `NULL`. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # use std::cmp::Ordering;
+/// #[derive(Clone, PartialEq)]
+/// struct Country {
+///     name: &'static str,
+///     population: u64,
+///     /// Square kilometres. None if disputed
+///     area: Option<f64>,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum CountryField { #[default] Name, Density }
+/// # impl PartialOrdBy<Country> for CountryField {
+/// #     fn partial_cmp_by(&self, a: &Country, b: &Country) -> Option<Ordering> {
+/// #         match self {
+/// #             CountryField::Name => a.name.partial_cmp(b.name),
+/// #             // Replaced by the computed field
+/// #             CountryField::Density => None,
+/// #         }
+/// #     }
+/// # }
+/// # impl Sortable for CountryField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Countries(countries: Vec<Country>) -> Element {
+///     let density = ComputedField::new(|c: &Country| Some(c.population as f64 / c.area?));
+///     let sorter = use_sorter::<CountryField>().with_computed(CountryField::Density, density.clone());
+///     let mut countries = countries;
+///     sorter.sort(&mut countries);
+///     rsx! {
+///         for country in countries {
+///             tr {
+///                 td { "{country.name}" }
+///                 td {
+///                     match density.compute(&country) {
+///                         Some(density) => rsx!("{density:.1}"),
+///                         None => rsx!("-"),
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub struct ComputedField<T, K> {
+    compute: Compute<T, K>,
+}
+
+impl<T, K> Clone for ComputedField<T, K> {
+    fn clone(&self) -> Self {
+        Self {
+            compute: self.compute.clone(),
+        }
+    }
+}
+
+impl<T, K> fmt::Debug for ComputedField<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComputedField").finish_non_exhaustive()
+    }
+}
+
+impl<T, K> PartialEq for ComputedField<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.compute, &other.compute)
+    }
+}
+
+impl<T, K> ComputedField<T, K> {
+    /// Creates a computed field from a fn deriving a row's value.
+    pub fn new(compute: impl Fn(&T) -> Option<K> + 'static) -> Self {
+        Self {
+            compute: Rc::new(compute),
+        }
+    }
+
+    /// Computes a row's value e.g., to render it. Returns `None` if `NULL`.
+    pub fn compute(&self, row: &T) -> Option<K> {
+        (self.compute)(row)
+    }
+}
+
+/// Computes sort keys for rows once. Lets sorters hold [`ComputedField`]s without knowing their key type.
+pub(crate) trait ComputeKeys<T> {
+    /// Computes keys for rows given with their index. Returns a comparison by index of rows given.
+    fn keys(&self, rows: &mut dyn Iterator<Item = (usize, &T)>) -> KeyCmp;
+}
+
+impl<T, K: PartialOrd + 'static> ComputeKeys<T> for ComputedField<T, K> {
+    fn keys(&self, rows: &mut dyn Iterator<Item = (usize, &T)>) -> KeyCmp {
+        project_keys(rows, |row| self.compute(row))
+    }
+}
+
+impl<T> ComputeKeys<T> for Projection<T> {
+    fn keys(&self, rows: &mut dyn Iterator<Item = (usize, &T)>) -> KeyCmp {
+        Projection::keys(self, rows)
+    }
+}
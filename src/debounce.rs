@@ -0,0 +1,148 @@
+use crate::UseSorter;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+impl<F: Copy + PartialEq + 'static> UseSorter<F> {
+    /// Creates Dioxus hooks for a copy of the sorter whose state follows this one's once it has stopped changing for `delay`. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+    ///
+    /// Sort data with the returned sorter and pass this one to headers: arrows update as soon as a header is clicked but the data is only sorted once clicking stops. Several quick clicks on a large table then cost one sort rather than one each. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # use std::time::Duration;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Person { name: String }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum PersonField { #[default] Name }
+    /// # impl PartialOrdBy<Person> for PersonField {
+    /// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { a.name.partial_cmp(&b.name) }
+    /// # }
+    /// # impl Sortable for PersonField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// #[component]
+    /// fn People(people: Vec<Person>) -> Element {
+    ///     let sorter = use_sorter::<PersonField>();
+    ///     let settled = sorter.use_debounced(Duration::from_millis(250));
+    ///     let mut people = people;
+    ///     settled.sort(&mut people);
+    ///     rsx! {
+    ///         table {
+    ///             thead {
+    ///                 tr { Th { sorter: sorter, field: PersonField::Name, "Name" } }
+    ///             }
+    ///             tbody {
+    ///                 for person in people {
+    ///                     tr { td { "{person.name}" } }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Shares everything but its state with this sorter e.g., comparators and callbacks, so change the sort through this sorter rather than the copy. Waits with the browser's timer with the `web` feature, otherwise on a background thread.
+    pub fn use_debounced(&self, delay: Duration) -> UseSorter<F> {
+        let (field, direction, sorted, then_by) = self.state_signals();
+        let settled = (
+            use_signal(|| *field.peek()),
+            use_signal(|| *direction.peek()),
+            use_signal(|| *sorted.peek()),
+            use_signal(|| then_by.peek().clone()),
+        );
+        let mut pending = use_hook(|| CopyValue::new(None::<Task>));
+        use_effect(move || {
+            // Read to restart the wait whenever the sort changes
+            let to = (
+                *field.read(),
+                *direction.read(),
+                *sorted.read(),
+                then_by.cloned(),
+            );
+            if let Some(task) = pending.write().take() {
+                task.cancel();
+            }
+            let (mut field, mut direction, mut sorted, mut then_by) = settled;
+            let current = (*field.peek(), *direction.peek(), *sorted.peek());
+            if current == (to.0, to.1, to.2) && *then_by.peek() == to.3 {
+                return;
+            }
+            let task = spawn(async move {
+                sleep(delay).await;
+                field.set(to.0);
+                direction.set(to.1);
+                sorted.set(to.2);
+                then_by.set(to.3);
+            });
+            pending.set(Some(task));
+        });
+        self.with_state_signals(settled)
+    }
+}
+
+/// Waits for `delay` with the browser's timer.
+#[cfg(feature = "web")]
+async fn sleep(delay: Duration) {
+    let millis = i32::try_from(delay.as_millis()).unwrap_or(i32::MAX);
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Waits for `delay` on a background thread so no async runtime is needed.
+#[cfg(not(feature = "web"))]
+async fn sleep(delay: Duration) {
+    let (sender, receiver) = futures_channel::oneshot::channel();
+    std::thread::spawn(move || {
+        std::thread::sleep(delay);
+        let _ = sender.send(());
+    });
+    let _ = receiver.await;
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod tests {
+    use super::*;
+    use crate::{test_dom::mount, use_sorter, Direction, SortBy, Sortable};
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum Field {
+        #[default]
+        Name,
+        Age,
+    }
+
+    impl Sortable for Field {
+        fn sort_by(&self) -> Option<SortBy> {
+            SortBy::increasing_or_decreasing()
+        }
+    }
+
+    #[test]
+    fn test_debounced() {
+        let (mut dom, (sorter, settled)) = mount(|| {
+            let sorter = use_sorter::<Field>();
+            (sorter, sorter.use_debounced(Duration::from_millis(5)))
+        });
+        dom.in_runtime(|| {
+            sorter.toggle_field(Field::Age);
+            sorter.toggle_field(Field::Age);
+            // Headers update straight away
+            assert_eq!(sorter.get_state(), (Field::Age, Direction::Descending));
+            assert_eq!(settled.get_state(), (Field::Name, Direction::Ascending));
+        });
+        // Runs effects and polls the wait until it's over
+        for _ in 0..1000 {
+            dom.process_events();
+            if dom.in_runtime(|| settled.get_state().0) == Field::Age {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        let state = dom.in_runtime(|| settled.get_state());
+        assert_eq!(state, (Field::Age, Direction::Descending));
+    }
+}
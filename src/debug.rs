@@ -0,0 +1,159 @@
+//! Sort timings and state transitions recorded with the `debug` feature. See [`crate::UseSorter::stats`] and [`crate::SorterDebugPanel`].
+
+use crate::SorterState;
+use std::cell::Cell;
+
+/// Transitions kept by [`SortStats::transitions`].
+pub(crate) const MAX_TRANSITIONS: usize = 10;
+
+thread_local! {
+    /// Comparisons run on this thread. Timers read the difference.
+    static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts a comparison for the [`SortTimer`] running.
+pub(crate) fn count_comparison() {
+    COMPARISONS.with(|count| count.set(count.get() + 1));
+}
+
+/// Measures one sort. Recorded with the `debug` feature.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SortTiming {
+    /// Number of items sorted.
+    pub items: usize,
+    /// Number of comparisons run. Doesn't include checks for `NULL` values.
+    pub comparisons: usize,
+    /// Time taken in milliseconds.
+    pub millis: f64,
+}
+
+/// Sort timings and recent state transitions of a [`crate::UseSorter`]. Recorded with the `debug` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortStats<F> {
+    /// Number of sorts recorded.
+    pub sorts: usize,
+    /// Total time spent sorting in milliseconds.
+    pub total_millis: f64,
+    /// The most recent sort.
+    pub last: Option<SortTiming>,
+    /// The slowest sort.
+    pub slowest: Option<SortTiming>,
+    /// Recent state changes as `(from, to)`, oldest first.
+    pub transitions: Vec<(SorterState<F>, SorterState<F>)>,
+}
+
+impl<F> Default for SortStats<F> {
+    fn default() -> Self {
+        Self {
+            sorts: 0,
+            total_millis: 0.0,
+            last: None,
+            slowest: None,
+            transitions: Vec::new(),
+        }
+    }
+}
+
+impl<F> SortStats<F> {
+    /// Returns the mean time per sort in milliseconds or `None` if nothing has been sorted.
+    pub fn mean_millis(&self) -> Option<f64> {
+        (self.sorts > 0).then(|| self.total_millis / self.sorts as f64)
+    }
+
+    pub(crate) fn record_sort(&mut self, timing: SortTiming) {
+        self.sorts += 1;
+        self.total_millis += timing.millis;
+        self.last = Some(timing);
+        if self
+            .slowest
+            .is_none_or(|slowest| timing.millis > slowest.millis)
+        {
+            self.slowest = Some(timing);
+        }
+    }
+
+    pub(crate) fn record_transition(&mut self, from: SorterState<F>, to: SorterState<F>) {
+        if self.transitions.len() == MAX_TRANSITIONS {
+            self.transitions.remove(0);
+        }
+        self.transitions.push((from, to));
+    }
+}
+
+/// Times a sort and counts its comparisons.
+pub(crate) struct SortTimer {
+    start: f64,
+    comparisons: usize,
+}
+
+impl SortTimer {
+    pub(crate) fn start() -> Self {
+        Self {
+            start: now_millis(),
+            comparisons: COMPARISONS.with(Cell::get),
+        }
+    }
+
+    pub(crate) fn finish(self, items: usize) -> SortTiming {
+        SortTiming {
+            items,
+            comparisons: COMPARISONS.with(Cell::get) - self.comparisons,
+            millis: now_millis() - self.start,
+        }
+    }
+}
+
+/// Returns a timestamp in milliseconds from the browser's high resolution clock.
+#[cfg(all(feature = "web", target_arch = "wasm32"))]
+fn now_millis() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or(0.0, |performance| performance.now())
+}
+
+/// Returns milliseconds since the first call. Panics on `wasm32-unknown-unknown` so enable the `web` feature in the browser.
+#[cfg(not(all(feature = "web", target_arch = "wasm32")))]
+fn now_millis() -> f64 {
+    use std::{sync::OnceLock, time::Instant};
+
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_stats() {
+        let mut stats = SortStats::<u8>::default();
+        assert_eq!(stats.mean_millis(), None);
+        let timing = |millis| SortTiming {
+            items: 10,
+            comparisons: 20,
+            millis,
+        };
+        stats.record_sort(timing(3.0));
+        stats.record_sort(timing(1.0));
+        assert_eq!(stats.last, Some(timing(1.0)));
+        assert_eq!(stats.slowest, Some(timing(3.0)));
+        assert_eq!(stats.mean_millis(), Some(2.0));
+
+        let state = |field| SorterState {
+            field,
+            direction: crate::Direction::Ascending,
+            sorted: true,
+        };
+        for field in 0..12 {
+            stats.record_transition(state(field), state(field + 1));
+        }
+        assert_eq!(stats.transitions.len(), MAX_TRANSITIONS);
+        assert_eq!(stats.transitions[0].0, state(2));
+
+        // Counted per timer
+        let timer = SortTimer::start();
+        count_comparison();
+        count_comparison();
+        assert_eq!(timer.finish(2).comparisons, 2);
+    }
+}
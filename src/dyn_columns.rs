@@ -0,0 +1,308 @@
+use crate::sort::{is_null, sort_by, sort_indices_by, Compare};
+use crate::{Direction, NullHandling, SortBy, Sortable, UseSorter, UseSorterBuilder};
+use dioxus::prelude::*;
+use std::{cmp::Ordering, fmt, rc::Rc};
+
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> Option<Ordering>>;
+
+/// A column defined at runtime e.g., from a config file, in place of a field enum. Holds its label, how rows of `T` compare and how it may be sorted. Sort by them with [`use_dyn_sorter()`].
+///
+/// Compares like [`crate::PartialOrdBy`]: returning `None` marks a row as `NULL`. For example:
+/// ```rust
+/// # use dioxus_sortable::{DynColumn, NullHandling, SortBy};
+/// struct Row {
+///     values: Vec<Option<f64>>,
+/// }
+///
+/// /// Builds a column per name in the config, comparing the value at its position.
+/// fn columns(names: &[&str]) -> Vec<DynColumn<Row>> {
+///     names
+///         .iter()
+///         .enumerate()
+///         .map(|(i, name)| {
+///             DynColumn::new(*name, move |a: &Row, b: &Row| {
+///                 a.values[i]?.partial_cmp(&b.values[i]?)
+///             })
+///             .with_null_handling(NullHandling::First)
+///         })
+///         .collect()
+/// }
+/// ```
+pub struct DynColumn<T> {
+    label: String,
+    compare: Comparator<T>,
+    sort_by: Option<SortBy>,
+    null_handling: NullHandling,
+}
+
+impl<T> Clone for DynColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label.clone(),
+            compare: self.compare.clone(),
+            sort_by: self.sort_by,
+            null_handling: self.null_handling,
+        }
+    }
+}
+
+impl<T> fmt::Debug for DynColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynColumn")
+            .field("label", &self.label)
+            .field("sort_by", &self.sort_by)
+            .field("null_handling", &self.null_handling)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> PartialEq for DynColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // Comparators compare by identity
+        self.label == other.label
+            && Rc::ptr_eq(&self.compare, &other.compare)
+            && self.sort_by == other.sort_by
+            && self.null_handling == other.null_handling
+    }
+}
+
+impl<T> DynColumn<T> {
+    /// Creates a column sortable in either direction, starting ascending, with `NULL`s last. See [`Self::with_sort_by`] and [`Self::with_null_handling`].
+    pub fn new(
+        label: impl Into<String>,
+        compare: impl Fn(&T, &T) -> Option<Ordering> + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            compare: Rc::new(compare),
+            sort_by: SortBy::increasing_or_decreasing(),
+            null_handling: NullHandling::default(),
+        }
+    }
+
+    /// Optionally sets how the column may be sorted. Pass [`SortBy::unsortable`] for columns that can't be.
+    pub fn with_sort_by(self, sort_by: Option<SortBy>) -> Self {
+        Self { sort_by, ..self }
+    }
+
+    /// Optionally sets how `NULL` values are ordered.
+    pub fn with_null_handling(self, null_handling: NullHandling) -> Self {
+        Self {
+            null_handling,
+            ..self
+        }
+    }
+
+    /// Returns the column's label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns how the column may be sorted.
+    pub fn sort_by(&self) -> Option<SortBy> {
+        self.sort_by
+    }
+
+    /// Returns how `NULL` values are ordered.
+    pub fn null_handling(&self) -> NullHandling {
+        self.null_handling
+    }
+}
+
+impl<T> Compare<T> for DynColumn<T> {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        (self.compare)(a, b)
+    }
+}
+
+/// Field type of a [`UseDynSorter`]: a column's index along with how it may be sorted. Lets dynamic columns reuse components taking a [`UseSorter`] such as [`crate::ThStatus`] and [`crate::SortControl`]. Get one with [`UseDynSorter::field`].
+///
+/// Fields are equal if their indices are.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DynField {
+    index: usize,
+    sort_by: Option<SortBy>,
+    null_handling: NullHandling,
+}
+
+impl DynField {
+    /// Returns the column's index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl PartialEq for DynField {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl Sortable for DynField {
+    fn sort_by(&self) -> Option<SortBy> {
+        self.sort_by
+    }
+
+    fn null_handling(&self) -> NullHandling {
+        self.null_handling
+    }
+}
+
+/// Stores Dioxus hooks and state of items of `T` sorted by [`DynColumn`]s. Columns are identified by their index. See [`use_dyn_sorter()`].
+///
+/// Render headers with [`crate::DynTh`]. State lives in a [`UseSorter`] over [`DynField`]s so callbacks such as [`UseSorter::on_change`] are registered on [`Self::sorter`].
+pub struct UseDynSorter<T: 'static> {
+    sorter: UseSorter<DynField>,
+    columns: CopyValue<Vec<DynColumn<T>>>,
+}
+
+impl<T> Clone for UseDynSorter<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseDynSorter<T> {}
+
+impl<T> fmt::Debug for UseDynSorter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseDynSorter")
+            .field("sorter", &self.sorter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> PartialEq for UseDynSorter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sorter == other.sorter && self.columns == other.columns
+    }
+}
+
+/// Creates Dioxus hooks to sort by columns defined at runtime. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Starts sorted by the first sortable column. Columns are updated every render so may change e.g., once a config file has loaded, but each column should keep its index.
+pub fn use_dyn_sorter<T: 'static>(columns: &[DynColumn<T>]) -> UseDynSorter<T> {
+    let first = columns
+        .iter()
+        .position(|column| column.sort_by.is_some())
+        .map(|index| dyn_field(index, &columns[index]))
+        .unwrap_or_default();
+    let sorter = UseSorterBuilder::default().with_field(first).use_sorter();
+    let mut slot = use_hook(|| CopyValue::new(Vec::new()));
+    slot.set(columns.to_vec());
+    UseDynSorter {
+        sorter,
+        columns: slot,
+    }
+}
+
+fn dyn_field<T>(index: usize, column: &DynColumn<T>) -> DynField {
+    DynField {
+        index,
+        sort_by: column.sort_by,
+        null_handling: column.null_handling,
+    }
+}
+
+impl<T> UseDynSorter<T> {
+    /// Returns the underlying sorter. Use it with components and methods taking a [`UseSorter`].
+    pub fn sorter(&self) -> UseSorter<DynField> {
+        self.sorter
+    }
+
+    /// Returns the number of columns.
+    pub fn len(&self) -> usize {
+        self.columns.read().len()
+    }
+
+    /// Returns true if there are no columns.
+    pub fn is_empty(&self) -> bool {
+        self.columns.read().is_empty()
+    }
+
+    /// Returns the column at an index.
+    pub fn column(&self, index: usize) -> Option<DynColumn<T>> {
+        self.columns.read().get(index).cloned()
+    }
+
+    /// Returns the field for a column's index. Pass it to components taking a [`UseSorter`] along with [`Self::sorter`].
+    pub fn field(&self, index: usize) -> Option<DynField> {
+        let columns = self.columns.read();
+        columns.get(index).map(|column| dyn_field(index, column))
+    }
+
+    /// Returns the index of the column being sorted by and its direction, or `None` if unsorted.
+    pub fn get_state(&self) -> Option<(usize, Direction)> {
+        let (field, direction) = self.sorter.get_state();
+        self.sorter.is_sorted().then_some((field.index, direction))
+    }
+
+    /// Toggles sorting by a column. See [`UseSorter::toggle_field`]. Ignores unknown columns.
+    pub fn toggle_column(&self, index: usize) {
+        if let Some(field) = self.field(index) {
+            self.sorter.toggle_field(field);
+        }
+    }
+
+    /// Sorts by a column in a direction. See [`UseSorter::set_field`]. Ignores unknown columns.
+    pub fn set_column(&self, index: usize, direction: Direction) {
+        if let Some(field) = self.field(index) {
+            self.sorter.set_field(field, direction);
+        }
+    }
+
+    /// Sorts items by the active column. Otherwise behaves like [`UseSorter::sort`].
+    pub fn sort(&self, items: &mut [T]) {
+        if !self.sorter.sorts_locally() {
+            return;
+        }
+        let (field, direction) = self.sorter.get_state();
+        let columns = self.columns.read();
+        let Some(column) = columns.get(field.index) else {
+            return;
+        };
+        let chain = [(column.clone(), direction, column.null_handling)];
+        sort_by(&chain, self.sorter.algorithm(), items);
+    }
+
+    /// Returns the indices of items in sorted order without modifying them. Otherwise behaves like [`UseSorter::sort_indices`].
+    pub fn sort_indices(&self, items: &[T]) -> Vec<usize> {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        let (field, direction) = self.sorter.get_state();
+        let columns = self.columns.read();
+        if let (true, Some(column)) = (self.sorter.sorts_locally(), columns.get(field.index)) {
+            let chain = [(column.clone(), direction, column.null_handling)];
+            if column.null_handling == NullHandling::Remove {
+                indices.retain(|&index| !is_null(column, &items[index]));
+            }
+            let item = |index: usize| &items[index];
+            sort_indices_by(&chain, self.sorter.algorithm(), item, &mut indices);
+        }
+        self.sorter.notify_sorted(&indices);
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SortAlgorithm;
+
+    #[test]
+    fn test_sort_by_dyn_column() {
+        // Rows from a config: a label and an optional value per column
+        let rows = [("b", Some(2.0)), ("a", None), ("c", Some(1.0))];
+        let value = DynColumn::new("Value", |a: &(&str, Option<f64>), b| {
+            a.1?.partial_cmp(&b.1?)
+        });
+        let chain = [(value.clone(), Direction::Descending, NullHandling::First)];
+        let mut items = rows.to_vec();
+        sort_by(&chain, SortAlgorithm::Stable, &mut items);
+        assert_eq!(items, [("a", None), ("b", Some(2.0)), ("c", Some(1.0))]);
+
+        let chain = [(value, Direction::Ascending, NullHandling::Last)];
+        let mut indices = vec![0, 1, 2];
+        sort_indices_by(&chain, SortAlgorithm::Stable, |i| &rows[i], &mut indices);
+        assert_eq!(indices, [2, 0, 1]);
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{comparators, DynColumn, SortBy};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    hash::BuildHasher,
+};
+
+/// Type of a [`SchemaColumn`]'s values. Chooses how its cells compare.
+///
+/// With the `serde` feature this (de)serialises as `"string"`, `"number"`, `"date"` or `"bool"`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ColumnType {
+    /// Text in natural order: "file2" before "file10". See [`comparators::natural`].
+    #[default]
+    String,
+    /// Numbers by value e.g., "1,234.5". Text that isn't a number is `NULL`. See [`comparators::locale_number`].
+    Number,
+    /// ISO 8601 dates or date-times e.g., "2024-01-31" or "2024-01-31T09:00:00Z". Compared as text so date-times should share a UTC offset. Anything else is `NULL`.
+    Date,
+    /// "true" or "false" (or "1" and "0"), false first. Anything else is `NULL`.
+    Bool,
+}
+
+impl ColumnType {
+    /// Compares two cells of this type. Returns `None` if either is `NULL`.
+    pub fn compare(&self, a: &str, b: &str) -> Option<Ordering> {
+        match self {
+            Self::String => comparators::natural(a, b),
+            Self::Number => comparators::locale_number(a, b, '.'),
+            Self::Date => iso_date(a)?.partial_cmp(iso_date(b)?),
+            Self::Bool => parse_bool(a)?.partial_cmp(&parse_bool(b)?),
+        }
+    }
+}
+
+/// Returns the trimmed text if it starts with a `YYYY-MM-DD` date.
+fn iso_date(s: &str) -> Option<&str> {
+    let s = s.trim();
+    let date = s.as_bytes().get(..10)?;
+    let valid = date.iter().enumerate().all(|(i, c)| match i {
+        4 | 7 => *c == b'-',
+        _ => c.is_ascii_digit(),
+    });
+    valid.then_some(s)
+}
+
+fn parse_bool(s: &str) -> Option<bool> {
+    match s.trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        s if s.eq_ignore_ascii_case("true") => Some(true),
+        s if s.eq_ignore_ascii_case("false") => Some(false),
+        _ => None,
+    }
+}
+
+/// A column as declared by a [`DynamicSchema`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaColumn {
+    /// Identifies the column's cell in each row. See [`SchemaRow::cell`].
+    pub id: String,
+    /// Shown in the column's header.
+    pub label: String,
+    /// Type of the column's values. (De)serialised as `type`.
+    #[cfg_attr(feature = "serde", serde(rename = "type", default))]
+    pub kind: ColumnType,
+    /// True if users may sort by the column. Defaults to true when deserialised.
+    #[cfg_attr(feature = "serde", serde(default = "sortable_default"))]
+    pub sortable: bool,
+}
+
+#[cfg(feature = "serde")]
+fn sortable_default() -> bool {
+    true
+}
+
+impl SchemaColumn {
+    /// Creates a sortable column.
+    pub fn new(id: impl Into<String>, label: impl Into<String>, kind: ColumnType) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            kind,
+            sortable: true,
+        }
+    }
+
+    /// Sets whether users may sort by the column.
+    pub fn with_sortable(self, sortable: bool) -> Self {
+        Self { sortable, ..self }
+    }
+
+    /// Builds a [`DynColumn`] comparing this column's cells by its type. Missing cells are `NULL`.
+    pub fn dyn_column<T: SchemaRow>(&self) -> DynColumn<T> {
+        let (id, kind) = (self.id.clone(), self.kind);
+        let sort_by = match self.sortable {
+            true => SortBy::increasing_or_decreasing(),
+            false => SortBy::unsortable(),
+        };
+        DynColumn::new(self.label.clone(), move |a: &T, b: &T| {
+            kind.compare(a.cell(&id)?, b.cell(&id)?)
+        })
+        .with_sort_by(sort_by)
+    }
+}
+
+/// Rows of a table described by a [`DynamicSchema`]. Looks up cells by column id, returning `None` for a missing (`NULL`) cell.
+///
+/// Implemented for maps from column id to text. Implement it for your own rows e.g., to read from JSON objects.
+pub trait SchemaRow {
+    /// Returns the text of the row's cell in a column.
+    fn cell(&self, id: &str) -> Option<&str>;
+}
+
+impl<S: BuildHasher> SchemaRow for HashMap<String, String, S> {
+    fn cell(&self, id: &str) -> Option<&str> {
+        self.get(id).map(String::as_str)
+    }
+}
+
+impl SchemaRow for BTreeMap<String, String> {
+    fn cell(&self, id: &str) -> Option<&str> {
+        self.get(id).map(String::as_str)
+    }
+}
+
+/// Column definitions from a server at runtime: each column's id, label, type and whether it's sortable. Turns them into [`DynColumn`]s comparing cells by their declared type for [`crate::use_dyn_sorter()`], giving a fully data-driven table. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # use std::collections::HashMap;
+/// #[component]
+/// fn Report(schema: ReadOnlySignal<DynamicSchema>, rows: Vec<HashMap<String, String>>) -> Element {
+///     let schema = schema.read();
+///     let sorter = use_dyn_sorter(&schema.dyn_columns());
+///     let mut rows = rows;
+///     sorter.sort(&mut rows);
+///     rsx! {
+///         table {
+///             thead {
+///                 tr {
+///                     for index in 0..schema.len() {
+///                         DynTh { sorter: sorter, column: index }
+///                     }
+///                 }
+///             }
+///             tbody {
+///                 for row in rows {
+///                     tr {
+///                         for column in schema.columns() {
+///                             td { {row.get(&column.id).cloned()} }
+///                         }
+///                     }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// With the `serde` feature a schema deserialises from a list of columns e.g., `[{"id": "qty", "label": "Quantity", "type": "number", "sortable": true}]`. Columns keep their index so sort state carries over while the schema is unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct DynamicSchema {
+    columns: Vec<SchemaColumn>,
+}
+
+impl DynamicSchema {
+    /// Creates a schema from its columns, in display order.
+    pub fn new(columns: impl IntoIterator<Item = SchemaColumn>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+        }
+    }
+
+    /// Returns the columns in display order.
+    pub fn columns(&self) -> &[SchemaColumn] {
+        &self.columns
+    }
+
+    /// Returns the number of columns.
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns true if there are no columns.
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Returns the index of the column with an id. Pass it to [`crate::UseDynSorter::set_column`] and friends.
+    pub fn position(&self, id: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column.id == id)
+    }
+
+    /// Builds a [`DynColumn`] per column, in order. See [`SchemaColumn::dyn_column`].
+    pub fn dyn_columns<T: SchemaRow>(&self) -> Vec<DynColumn<T>> {
+        self.columns.iter().map(SchemaColumn::dyn_column).collect()
+    }
+}
+
+impl FromIterator<SchemaColumn> for DynamicSchema {
+    fn from_iter<I: IntoIterator<Item = SchemaColumn>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        sort::{sort_by, Compare},
+        Direction, NullHandling, SortAlgorithm,
+    };
+
+    fn row(cells: &[(&str, &str)]) -> BTreeMap<String, String> {
+        cells
+            .iter()
+            .map(|(id, text)| (id.to_string(), text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_dynamic_schema() {
+        let schema = DynamicSchema::new([
+            SchemaColumn::new("name", "Name", ColumnType::String),
+            SchemaColumn::new("qty", "Quantity", ColumnType::Number),
+            SchemaColumn::new("due", "Due", ColumnType::Date),
+            SchemaColumn::new("done", "Done", ColumnType::Bool).with_sortable(false),
+        ]);
+        let rows = [
+            row(&[("name", "item10"), ("qty", "1,200"), ("due", "2024-03-01")]),
+            row(&[("name", "item9"), ("qty", "80"), ("due", "soon")]),
+            row(&[("name", "item2"), ("qty", "n/a"), ("done", "true")]),
+        ];
+        let columns = schema.dyn_columns();
+        assert_eq!(schema.position("due"), Some(2));
+        assert_eq!(columns[1].label(), "Quantity");
+        assert_eq!(columns[3].sort_by(), None);
+        let names = |items: &[BTreeMap<String, String>]| {
+            items
+                .iter()
+                .map(|row| row["name"].clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut items = rows.to_vec();
+        let chain = [(columns[0].clone(), Direction::Ascending, NullHandling::Last)];
+        sort_by(&chain, SortAlgorithm::Stable, &mut items);
+        assert_eq!(names(&items), ["item2", "item9", "item10"]);
+
+        // Numbers by value, not text. Unparsable is NULL
+        let chain = [(
+            columns[1].clone(),
+            Direction::Descending,
+            NullHandling::Last,
+        )];
+        sort_by(&chain, SortAlgorithm::Stable, &mut items);
+        assert_eq!(names(&items), ["item10", "item9", "item2"]);
+
+        // Missing and invalid dates are NULL
+        assert_eq!(columns[2].compare(&rows[0], &rows[1]), None);
+        assert_eq!(columns[2].compare(&rows[0], &rows[2]), None);
+        assert_eq!(
+            ColumnType::Date.compare("2024-01-31T09:00:00Z", "2023-12-31"),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(ColumnType::Bool.compare("0", "TRUE"), Some(Ordering::Less));
+        assert_eq!(ColumnType::Bool.compare("yes", "true"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialise_schema() {
+        let json = r#"[
+            {"id": "qty", "label": "Quantity", "type": "number"},
+            {"id": "note", "label": "Note", "sortable": false}
+        ]"#;
+        let schema = serde_json::from_str::<DynamicSchema>(json).unwrap();
+        assert_eq!(
+            schema,
+            DynamicSchema::new([
+                SchemaColumn::new("qty", "Quantity", ColumnType::Number),
+                SchemaColumn::new("note", "Note", ColumnType::String).with_sortable(false),
+            ])
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{PartialOrdBy, Sortable, UseSorter};
+use std::{fmt, vec};
+
+/// Iterator over `(key, &T)` pairs in sorted order. Created by [`UseSorter::keyed_rows`] or [`crate::FilteredSorted::keyed`].
+///
+/// Use the key with Dioxus' `key:` attribute so that re-sorting moves existing rows rather than re-rendering every one:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Person { id: u32, name: String }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name }
+/// # impl PartialOrdBy<Person> for PersonField {
+/// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { a.name.partial_cmp(&b.name) }
+/// # }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn People(people: Vec<Person>) -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     rsx! {
+///         ul {
+///             for (id, person) in sorter.keyed_rows(&people, |person| person.id) {
+///                 li { key: "{id}", "{person.name}" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Keys must be unique within the rows. Prefer a stable ID from your data over a position so keys survive data changes too.
+pub struct KeyedRows<'b, T, G> {
+    items: &'b [T],
+    indices: vec::IntoIter<usize>,
+    key: G,
+}
+
+impl<'b, T, G> KeyedRows<'b, T, G> {
+    /// Creates an iterator over items at indices in the order given.
+    pub(crate) fn new(items: &'b [T], indices: Vec<usize>, key: G) -> Self {
+        Self {
+            items,
+            indices: indices.into_iter(),
+            key,
+        }
+    }
+}
+
+impl<'b, T, K, G: Fn(&T) -> K> Iterator for KeyedRows<'b, T, G> {
+    type Item = (K, &'b T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = &self.items[self.indices.next()?];
+        Some(((self.key)(item), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T, K, G: Fn(&T) -> K> ExactSizeIterator for KeyedRows<'_, T, G> {}
+
+impl<T, G> fmt::Debug for KeyedRows<'_, T, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyedRows")
+            .field("indices", &self.indices)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> UseSorter<F> {
+    /// Iterates over `(key, &T)` pairs in sorted order without modifying items. The key is taken from each item by `key` for use with Dioxus' `key:` attribute. See [`KeyedRows`].
+    ///
+    /// Otherwise behaves like [`Self::sorted_iter`].
+    pub fn keyed_rows<'b, T: 'static, K, G>(&self, items: &'b [T], key: G) -> KeyedRows<'b, T, G>
+    where
+        F: PartialOrdBy<T> + Sortable,
+        G: Fn(&T) -> K,
+    {
+        KeyedRows::new(items, self.sort_indices(items), key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyed_rows() {
+        let items = ["b", "c", "a"];
+        let rows = KeyedRows::new(&items, vec![2, 0, 1], |item: &&str| item.to_uppercase());
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows.collect::<Vec<_>>(),
+            vec![
+                ("A".to_string(), &"a"),
+                ("B".to_string(), &"b"),
+                ("C".to_string(), &"c")
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! # Sortable components for Dioxus
 //!
-//! Create sortable tables (and other components) of any type for [Dioxus](https://dioxuslabs.com/).
+//! Create sortable tables (and other components) of any type for [Dioxus](https://dioxuslabs.com/). Built on Dioxus 0.6 signals.
 //!
 //! The focus is on tables but this library can be used to create any type of sortable component. Your tables can be customised however you wish. Sorting state is kept separately from the data.
 //!
@@ -14,16 +14,80 @@
 //!
 //! 1. Create a `struct T` that you wish to sort. The table row.
 //! 2. Create an `enum F` that describes each sortable field in `T`.
-//! 3. Implement [`PartialOrdBy`] for `F`. This is used to sort `T` by `F`.
+//! 3. Implement [`PartialOrdBy`] for `F`. This is used to sort `T` by `F`. See [`comparators`] for common comparisons.
 //! 4. Implement [`Sortable`] for `F`. This is used to describe how `F` may be sorted.
-//! 5. Call [`use_sorter()`] in your component and get a [`UseSorter`].
+//! 5. Call [`use_sorter()`] in your component and get a [`UseSorter`]. It's `Copy` so pass it to child components and event handlers freely. Children that only display state can take [`UseSorter::field`] and [`UseSorter::direction`] as read-only signals.
 //! 6. Call [`UseSorter::sort`] to sort data. This may be called conditionally e.g., when waiting for data to arrive.
-//! 7. Create a table using [`Th`] or write your own with [`ThStatus`] and [`UseSorter::toggle_field`].
+//! 7. Create a table using [`SortableTable`], [`Th`] or write your own with [`ThStatus`] and [`UseSorter::toggle_field`].
+//!
+//! ### Beyond sorting
+//!
+//! Tables usually need more than sorting. These build on the same `T` and `F` types and are all optional. See each item for details.
+//!
+//! Sorting:
+//! - By several fields: Shift-click a [`Th`] or use [`UseSorter::add_sort`]. Choose where `NULL`s go with [`UseSorter::set_null_handling`] and [`NullsToggle`].
+//! - Comparisons: [`comparators`], [`cmp_by!`], [`SortFields`] for closures and [`check_partial_ord_by`] to find inconsistent ones.
+//! - Expensive keys: [`ComputedField`], [`PartialOrdBy::projection`] and [`UseSorter::sort_by_cached_key`] compute once per row.
+//! - Other data: [`UseSorter::sorted_keys`], [`UseSorter::sort_collection`], [`UseSorter::sort_deref`], [`SortedBySorter`], [`UseSorter::sort_top_n`] and [`UseSorter::insert_sorted`].
+//! - Row order: [`UseSorter::capture_baseline`] to return to load order, [`UseSorter::move_row`] for hand-dragged orders and [`UseSorter::disable_field`].
+//! - Large data: [`UseSorter::sort_async`], [`UseSorter::sort_incremental`] and [`UseSorter::use_debounced`].
+//! - Outside a component: [`sort_slice_by`] and [`SorterState::sort`]. Neither needs Dioxus.
+//!
+//! Layout:
+//! - Headers: [`AllTh`], [`ThGroup`], [`StickyThead`] and [`SelectionTh`]. Render your own indicators from an [`IndicatorState`].
+//! - Columns: [`use_columns()`], [`use_column_widths()`], [`use_column_order()`], [`use_pinned_columns()`] and [`Sortable::align`]. Give a [`Th`] visibility, widths and order with [`ThHooks`].
+//! - Cells: [`Td`], [`CellFormat`], [`NullableCell`], [`RowIndexCell`] and [`TreeCell`].
+//! - Without a table: [`SortableList`], [`SortControl`] and [`SortSummary`]. Label fields once with [`Sortable::label`].
+//! - Styling: provide a [`SorterTheme`] via context, including right to left layouts.
+//! - Lots of rows: [`use_virtual_rows()`] and [`use_infinite_scroll()`].
+//!
+//! Data shaping:
+//! - Filter with [`use_filter()`] and [`ColumnFilter`], group with [`use_grouper()`] and [`SortBy::GroupOnly`], and page with [`use_paginator()`].
+//! - Nested rows with [`TreeRow`] and section headings with [`UseSorter::sort_sections`].
+//! - Rows: [`KeyedRows`], [`RowClasses`], [`use_selection()`], [`use_sorted_data()`] and [`use_row_moves()`].
+//! - Footers from [`aggregates`] with [`TFoot`], and status bars from [`UseSorter::last_sort_stats`].
+//! - Runtime columns with [`DynColumn`] and [`DynamicSchema`].
+//!
+//! State, remote data and SSR:
+//! - Several sorters or tables: [`use_multi_sorter()`] and [`use_table_state()`].
+//! - State owned elsewhere: [`use_controlled_sorter()`], [`SorterMiddleware`] and [`UseSorter::changes`].
+//! - Server-side sorting: [`SortMode::Remote`] and [`SortRequest`]. See the [remote pages example](https://github.com/feral-dot-io/dioxus-sortable/blob/master/examples/remote_pages.rs).
+//! - Untrusted state, e.g., from URLs: [`UseSorterBuilder::try_with_state`] and [`UseSorter::try_restore`].
+//!
+//! Debugging: the `debug` and `test-utils` features below.
+//!
+//! ## Features
+//!
+//! Enabled by default:
+//! - `components`: the Dioxus components in this crate e.g., [`Th`], [`SortableTable`] and [`Paginator`]. Implies `core`.
+//! - `core`: the sorter and other hooks e.g., [`use_sorter()`] and [`use_filter()`]. Requires Dioxus.
+//!
+//! Without either the crate has no Dioxus dependency: the field traits, [`SortBy`], [`SorterState`], [`sort_slice_by`], [`SortFields`], [`SortRequest`] and [`comparators`] remain. Share the field enum between an API server and a frontend this way: depend on the crate with `default-features = false` on the server and sort with [`SorterState::sort`] exactly as the browser does.
+//!
+//! Optional:
+//! - `chrono`: compare dates and times written as text by parsing them with `chrono` in [`comparators`].
+//! - `debug`: record sort timings, comparison counts and state transitions with [`UseSorter::stats`] and show them in a [`SorterDebugPanel`]. Enable `web` too in the browser for its clock.
+//! - `icu`: locale-aware string collation and accent-insensitive comparison in [`comparators`].
+//! - `ordered-float`: compare `OrderedFloat`s with `NaN` as `NULL` using [`comparators::ordered_float`].
+//! - `serde`: serialise [`SorterState`] snapshots from [`UseSorter::save`] along with [`Direction`], [`NullHandling`] and [`SortBy`]. Deserialise a [`DynamicSchema`] sent by a server.
+//! - `test-utils`: mount [`Th`] headers in a virtual DOM, click them and check the resulting state with [`test_utils::ThHarness`]. For your own tests.
+//! - `time`: compare dates and times written as text by parsing them with `time` in [`comparators`].
+//! - `web`: persist sort state to the browser's `localStorage` with [`UseSorterBuilder::with_storage_key`], track scrolling in [`VirtualScroll`], load more from [`InfiniteScrollTrigger`] when scrolled into view, set [`SelectionTh`]'s checkbox indeterminate and let the browser render between chunks of [`UseSorter::sort_async`].
+//!
+//! ## Renderers
+//!
+//! Hooks and components only use Dioxus' cross-platform APIs so work with any renderer: web, desktop, mobile and liveview. Browser-only features are behind the `web` feature so leave it off elsewhere. Without it, call [`UseVirtualRows::set_scroll_top`] yourself.
+//!
+//! Components render the same markup given the same state, without raw HTML, so are safe to render server-side and with liveview. Start a sorter from a [`SorterState`] with [`UseSorterBuilder::with_state`] to render a pre-sorted table on the server.
 //!
 //! ## Examples
 //!
 //! See a full example of [British prime ministers](https://feral-dot-io.github.io/dioxus-sortable/examples/prime-ministers/) ([and the code](https://github.com/feral-dot-io/dioxus-sortable/blob/master/examples/prime_ministers.rs)). You can modify and run it locally with `dioxus serve --example prime_ministers`
 //!
+//! The [desktop example](https://github.com/feral-dot-io/dioxus-sortable/blob/master/examples/desktop/src/main.rs) runs the same components in a native window with `cargo run --manifest-path examples/desktop/Cargo.toml`.
+//!
+//! The [SSR example](https://github.com/feral-dot-io/dioxus-sortable/blob/master/examples/ssr/src/main.rs) renders a sorted table to HTML with `cargo run --manifest-path examples/ssr/Cargo.toml -- population descending`.
+//!
 //! A minimal example giving a tour of how to use the library is below.
 //!
 //! ```rust
@@ -65,17 +129,17 @@
 //!     }
 //! }
 //!
-//! #[inline_props]
-//! fn OurMinimalExampleTable(cx: Scope) -> Element {
+//! #[component]
+//! fn OurMinimalExampleTable() -> Element {
 //!     // Set up Dioxus state hooks. *Must* be called every time in the same order
-//!     let sorter = use_sorter::<PersonField>(cx);
+//!     let sorter = use_sorter::<PersonField>();
 //!     // Obtain our data. Either passed via props or pulled from a server
 //!     let mut data = load_data();
 //!     // Sort our data. This is optional but needed to apply the sort
 //!     sorter.sort(data.as_mut_slice());
 //!
 //!     // Render our table like normal.
-//!     cx.render(rsx! {
+//!     rsx! {
 //!         table {
 //!             thead {
 //!                 tr {
@@ -99,7 +163,7 @@
 //!                 }
 //!             }
 //!         }
-//!    })
+//!     }
 //! }
 //!
 //! # fn load_data() -> Vec<Person> {
@@ -112,7 +176,131 @@
 //! ```
 //!
 
+#[cfg(feature = "core")]
+pub mod aggregates;
+pub mod comparators;
+#[cfg(feature = "core")]
+mod computed;
+#[cfg(feature = "core")]
+mod debounce;
+#[cfg(feature = "debug")]
+pub mod debug;
+#[cfg(feature = "core")]
+pub use computed::ComputedField;
+#[cfg(feature = "core")]
+mod dyn_columns;
+#[cfg(feature = "core")]
+pub use dyn_columns::*;
+#[cfg(feature = "core")]
+mod dynamic_schema;
+#[cfg(feature = "core")]
+pub use dynamic_schema::*;
+#[cfg(feature = "core")]
+mod keyed_rows;
+#[cfg(feature = "core")]
+pub use keyed_rows::*;
+mod macros;
+#[cfg(feature = "core")]
+mod middleware;
+#[cfg(feature = "core")]
+pub use middleware::*;
+mod remote;
+pub use remote::*;
+#[cfg(feature = "core")]
+mod row_classes;
+#[cfg(feature = "core")]
+pub use row_classes::*;
+#[cfg(feature = "components")]
 mod rsx;
+#[cfg(feature = "components")]
 pub use rsx::*;
+mod sort;
+pub use sort::*;
+#[cfg(feature = "core")]
+mod sort_async;
+mod sort_fields;
+pub use sort_fields::*;
+#[cfg(feature = "core")]
+mod sorted_by;
+#[cfg(feature = "core")]
+pub use sorted_by::*;
+mod state;
+pub use state::*;
+#[cfg(feature = "web")]
+mod storage;
+#[cfg(feature = "components")]
+mod theme;
+#[cfg(feature = "components")]
+pub use theme::*;
+#[cfg(all(test, feature = "core"))]
+mod test_dom;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+#[cfg(feature = "core")]
+mod tree_rows;
+#[cfg(feature = "core")]
+pub use tree_rows::*;
+#[cfg(feature = "core")]
+mod use_column_order;
+#[cfg(feature = "core")]
+pub use use_column_order::*;
+#[cfg(feature = "core")]
+mod use_column_widths;
+#[cfg(feature = "core")]
+pub use use_column_widths::*;
+#[cfg(feature = "core")]
+mod use_columns;
+#[cfg(feature = "core")]
+pub use use_columns::*;
+#[cfg(feature = "core")]
+mod use_controlled_sorter;
+#[cfg(feature = "core")]
+pub use use_controlled_sorter::*;
+#[cfg(feature = "core")]
+mod use_filter;
+#[cfg(feature = "core")]
+pub use use_filter::*;
+#[cfg(feature = "core")]
+mod use_grouper;
+#[cfg(feature = "core")]
+pub use use_grouper::*;
+#[cfg(feature = "core")]
+mod use_infinite_scroll;
+#[cfg(feature = "core")]
+pub use use_infinite_scroll::*;
+#[cfg(feature = "core")]
+mod use_multi_sorter;
+#[cfg(feature = "core")]
+pub use use_multi_sorter::*;
+#[cfg(feature = "core")]
+mod use_paginator;
+#[cfg(feature = "core")]
+pub use use_paginator::*;
+#[cfg(feature = "core")]
+mod use_pinned_columns;
+#[cfg(feature = "core")]
+pub use use_pinned_columns::*;
+#[cfg(feature = "core")]
+mod use_row_moves;
+#[cfg(feature = "core")]
+pub use use_row_moves::*;
+#[cfg(feature = "core")]
+mod use_selection;
+#[cfg(feature = "core")]
+pub use use_selection::*;
+#[cfg(feature = "core")]
+mod use_sorted_data;
+#[cfg(feature = "core")]
+pub use use_sorted_data::*;
+#[cfg(feature = "core")]
 mod use_sorter;
+#[cfg(feature = "core")]
 pub use use_sorter::*;
+#[cfg(feature = "core")]
+mod use_table_state;
+#[cfg(feature = "core")]
+pub use use_table_state::*;
+#[cfg(feature = "core")]
+mod use_virtual_rows;
+#[cfg(feature = "core")]
+pub use use_virtual_rows::*;
@@ -0,0 +1,171 @@
+/// Compares two items by a path of fields for use inside [`crate::PartialOrdBy::partial_cmp_by`]. Each `?` in the path steps through an `Option`, where `None` makes the whole comparison `NULL`. For example:
+/// ```rust
+/// # use dioxus_sortable::{cmp_by, comparators, PartialOrdBy};
+/// # use std::cmp::Ordering;
+/// struct Address {
+///     city: String,
+///     postcode: Option<String>,
+/// }
+///
+/// struct Customer {
+///     name: String,
+///     address: Option<Address>,
+/// }
+///
+/// # #[derive(PartialEq)]
+/// enum CustomerField {
+///     Name,
+///     City,
+///     Postcode,
+/// }
+///
+/// impl PartialOrdBy<Customer> for CustomerField {
+///     fn partial_cmp_by(&self, a: &Customer, b: &Customer) -> Option<Ordering> {
+///         match self {
+///             // Optionally compare with a fn from `comparators` or your own
+///             CustomerField::Name => cmp_by!(a, b, .name, comparators::natural),
+///             // NULL if either customer has no address
+///             CustomerField::City => cmp_by!(a, b, .address?.city),
+///             // A trailing `?` makes the field itself optional
+///             CustomerField::Postcode => cmp_by!(a, b, .address?.postcode?),
+///         }
+///     }
+/// }
+/// ```
+///
+/// Paths may contain field names and tuple indices. Values are compared with [`PartialOrd`] by reference so nothing is cloned. A comparison fn given after the path receives references to both values and should return `Option<Ordering>`.
+#[macro_export]
+macro_rules! cmp_by {
+    ($a:expr, $b:expr, $($path:tt)+) => {
+        $crate::__cmp_by!([$a] [$b] [] $($path)+)
+    };
+}
+
+/// Splits [`cmp_by!`]'s path from any comparison fn.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cmp_by {
+    ([$a:expr] [$b:expr] [$($path:tt)+] , $cmp:expr) => {{
+        let (a, b) = (&$a, &$b);
+        // Called at once so `?` in the path returns from the closure
+        #[allow(clippy::redundant_closure_call)]
+        let a = (move || $crate::__cmp_by_path!((a) $($path)+))();
+        #[allow(clippy::redundant_closure_call)]
+        let b = (move || $crate::__cmp_by_path!((b) $($path)+))();
+        match (a, b) {
+            (::core::option::Option::Some(a), ::core::option::Option::Some(b)) => $cmp(a, b),
+            _ => ::core::option::Option::None,
+        }
+    }};
+    ([$a:expr] [$b:expr] [$($path:tt)+]) => {
+        $crate::__cmp_by!([$a] [$b] [$($path)+], |a, b| ::core::cmp::PartialOrd::partial_cmp(a, b))
+    };
+    ([$a:expr] [$b:expr] [$($path:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::__cmp_by!([$a] [$b] [$($path)* $next] $($rest)*)
+    };
+}
+
+/// Expands a [`cmp_by!`] path into an `Option` of a reference to the value. Only valid inside a closure as `?` returns early.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cmp_by_path {
+    (($($expr:tt)+) ?) => {
+        $($expr)+.as_ref()
+    };
+    (($($expr:tt)+)) => {
+        ::core::option::Option::Some(&$($expr)+)
+    };
+    (($($expr:tt)+) ? $($rest:tt)+) => {
+        $crate::__cmp_by_path!(($($expr)+.as_ref()?) $($rest)+)
+    };
+    (($($expr:tt)+) $next:tt $($rest:tt)*) => {
+        $crate::__cmp_by_path!(($($expr)+ $next) $($rest)*)
+    };
+}
+
+/// Implements [`crate::AllFields`] for a field enum of unit variants. Lists the variants once in display order and checks them against the enum with an exhaustive `match`, so adding a variant without listing it is a compile error. For example:
+/// ```rust
+/// # use dioxus_sortable::{all_fields, AllFields};
+/// # #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum PersonField {
+///     Name,
+///     Age,
+/// }
+///
+/// all_fields!(PersonField { Name, Age });
+/// assert_eq!(PersonField::ALL, [PersonField::Name, PersonField::Age]);
+/// ```
+///
+/// Variants may be listed in any order. Listing one twice shows its column twice.
+#[macro_export]
+macro_rules! all_fields {
+    ($field:ident { $($variant:ident),+ $(,)? }) => {
+        impl $crate::AllFields for $field {
+            const ALL: &'static [Self] = &[$($field::$variant),+];
+        }
+
+        // Fails to compile if a variant isn't listed
+        const _: fn(&$field) = |field| match field {
+            $($field::$variant => ()),+
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparators, AllFields};
+    use std::cmp::Ordering;
+
+    struct Inner {
+        value: f64,
+        label: Option<String>,
+    }
+
+    struct Outer {
+        inner: Option<Inner>,
+        pair: (u8, u8),
+    }
+
+    fn outer(value: f64, label: Option<&str>) -> Outer {
+        Outer {
+            inner: Some(Inner {
+                value,
+                label: label.map(str::to_string),
+            }),
+            pair: (0, value as u8),
+        }
+    }
+
+    #[test]
+    fn test_cmp_by() {
+        let (a, b) = (outer(1.0, Some("file10")), outer(2.0, Some("file2")));
+        let missing = Outer {
+            inner: None,
+            pair: (0, 0),
+        };
+        assert_eq!(cmp_by!(a, b, .inner?.value), Some(Ordering::Less));
+        assert_eq!(cmp_by!(a, b, .pair.1), Some(Ordering::Less));
+        assert_eq!(cmp_by!(a, b, .inner?.label?), Some(Ordering::Less));
+        assert_eq!(
+            cmp_by!(a, b, .inner?.label?, |a: &String, b: &String| comparators::natural(a, b)),
+            Some(Ordering::Greater)
+        );
+        // Missing values are NULL
+        assert_eq!(cmp_by!(a, missing, .inner?.value), None);
+        assert_eq!(cmp_by!(a, outer(1.0, None), .inner?.label?), None);
+        assert_eq!(cmp_by!(a, outer(f64::NAN, None), .inner?.value), None);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Field {
+        Name,
+        Age,
+    }
+
+    all_fields!(Field { Age, Name });
+
+    #[test]
+    fn test_all_fields() {
+        assert_eq!(Field::ALL, [Field::Age, Field::Name]);
+    }
+}
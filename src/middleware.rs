@@ -0,0 +1,33 @@
+use crate::SorterState;
+
+/// Intercepts changes to a [`crate::UseSorter`]'s state. Register with [`crate::UseSorter::with_middleware`].
+///
+/// Every change passes through middleware whether from clicking a header, [`crate::UseSorter::set_field`], [`crate::UseSorter::clear`] or [`crate::UseSorter::restore`]. Use it to forbid sorting while data is loading, log analytics events or throttle rapid clicks. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::{SorterMiddleware, SorterState};
+/// /// Ignores sort changes while `loading` is true.
+/// struct BlockWhileLoading(Signal<bool>);
+///
+/// impl<F> SorterMiddleware<F> for BlockWhileLoading {
+///     fn before_change(&self, _: &SorterState<F>, to: SorterState<F>) -> Option<SorterState<F>> {
+///         (!*self.0.peek()).then_some(to)
+///     }
+/// }
+/// ```
+///
+/// Both methods are provided and do nothing so implement only what you need.
+pub trait SorterMiddleware<F> {
+    /// Called before the state changes from `from` to `to`. Return the state to apply, which may differ from `to`, or `None` to cancel the change.
+    ///
+    /// The returned state is applied as-is so it should be valid for the field's [`crate::Sortable::sort_by`]. Returning `from` also cancels the change.
+    fn before_change(&self, from: &SorterState<F>, to: SorterState<F>) -> Option<SorterState<F>> {
+        let _ = from;
+        Some(to)
+    }
+
+    /// Called after the state changed from `from` to `to` and any [`crate::UseSorter::on_change`] callback has fired.
+    fn after_change(&self, from: &SorterState<F>, to: &SorterState<F>) {
+        let _ = (from, to);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{Direction, NullHandling};
+#[cfg(feature = "core")]
+use crate::{Sortable, UseSorter};
+use std::fmt;
+
+/// Trait used to name a field when sorting remotely e.g., a SQL column or REST query parameter. This must be implemented on the field enum. See [`UseSorter::sort_request`].
+///
+/// ```rust
+/// # use dioxus_sortable::FieldName;
+/// # #[derive(PartialEq)]
+/// enum PersonField {
+///     Name,
+///     Age,
+/// }
+///
+/// impl FieldName for PersonField {
+///     fn field_name(&self) -> &'static str {
+///         match self {
+///             PersonField::Name => "name",
+///             PersonField::Age => "age",
+///         }
+///     }
+/// }
+/// ```
+pub trait FieldName {
+    /// Returns the name the server knows this field by.
+    fn field_name(&self) -> &'static str;
+}
+
+/// The current sort state in a form suitable for building a server query. Created by [`UseSorter::sort_request`].
+///
+/// Displays as a SQL `ORDER BY` expression e.g., `name ASC NULLS LAST`. Field names come from [`FieldName`] rather than user input. For [`NullHandling::Remove`] the server should also filter out `NULL` values.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SortRequest {
+    /// Name of the field to sort by. See [`FieldName`].
+    pub field_name: &'static str,
+    /// Direction to sort in.
+    pub direction: Direction,
+    /// Where `NULL` values go. See [`Sortable::null_handling`].
+    pub nulls: NullHandling,
+}
+
+impl fmt::Display for SortRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Ascending => "ASC",
+            Direction::Descending => "DESC",
+        };
+        // Removing NULLs needs a filter, not just an order. Spelt out for SQL defaults too as not every database agrees
+        let nulls = if self.nulls.nulls_first(self.direction) {
+            "FIRST"
+        } else {
+            "LAST"
+        };
+        write!(f, "{} {direction} NULLS {nulls}", self.field_name)
+    }
+}
+
+impl SortRequest {
+    /// Returns query parameters for a URL e.g., `[("sort", "name"), ("dir", "asc"), ("nulls", "last")]`. Parse them on the server with [`Self::from_query`].
+    ///
+    /// `nulls` is `first`, `last` or `remove`. [`NullHandling::SqlDefault`] is sent as `first` or `last` for the direction so the server needn't know the default.
+    pub fn query_pairs(&self) -> [(&'static str, &'static str); 3] {
+        let nulls = match self.nulls {
+            NullHandling::Remove => "remove",
+            _ if self.nulls.nulls_first(self.direction) => "first",
+            _ => "last",
+        };
+        [
+            ("sort", self.field_name),
+            ("dir", self.direction.as_param()),
+            ("nulls", nulls),
+        ]
+    }
+
+    /// Returns a URL query string e.g., `sort=name&dir=asc&nulls=last`. Field names are used as-is so should be URL safe. See [`Self::query_pairs`].
+    pub fn to_query(&self) -> String {
+        self.query_pairs()
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Parses a query string from [`Self::to_query`]. The field must be one of `fields` so untrusted input can't name arbitrary columns. Unknown parameters are ignored. Returns `None` if the field or direction is missing or invalid. `nulls` defaults to [`NullHandling::Last`].
+    pub fn from_query<F: FieldName>(query: &str, fields: &[F]) -> Option<Self> {
+        let (mut field_name, mut direction, mut nulls) = (None, None, NullHandling::Last);
+        for pair in query.trim_start_matches('?').split('&') {
+            match pair.split_once('=') {
+                Some(("sort", value)) => {
+                    field_name = fields
+                        .iter()
+                        .map(FieldName::field_name)
+                        .find(|name| *name == value);
+                }
+                Some(("dir", value)) => direction = Direction::from_param(value),
+                Some(("nulls", "first")) => nulls = NullHandling::First,
+                Some(("nulls", "last")) => nulls = NullHandling::Last,
+                Some(("nulls", "remove")) => nulls = NullHandling::Remove,
+                _ => (),
+            }
+        }
+        Some(Self {
+            field_name: field_name?,
+            direction: direction?,
+            nulls,
+        })
+    }
+}
+
+#[cfg(feature = "core")]
+impl<F: Copy + FieldName + Sortable> UseSorter<F> {
+    /// Returns the current sort state for a server query. Returns `None` if toggled back to unsorted (see [`Self::is_sorted`]) in which case the server's default order should be used.
+    ///
+    /// Usually paired with [`crate::SortMode::Remote`] and [`Self::on_change`] to refetch data when the sort changes.
+    pub fn sort_request(&self) -> Option<SortRequest> {
+        if !self.is_sorted() {
+            return None;
+        }
+        let (field, direction) = self.get_state();
+        Some(SortRequest {
+            field_name: field.field_name(),
+            direction,
+            nulls: self.null_handling(&field),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort_request_display() {
+        let request = SortRequest {
+            field_name: "left_office",
+            direction: Direction::Descending,
+            nulls: NullHandling::First,
+        };
+        assert_eq!(request.to_string(), "left_office DESC NULLS FIRST");
+
+        let request = SortRequest {
+            nulls: NullHandling::SqlDefault,
+            ..request
+        };
+        assert_eq!(request.to_string(), "left_office DESC NULLS FIRST");
+        let request = SortRequest {
+            direction: Direction::Ascending,
+            ..request
+        };
+        assert_eq!(request.to_string(), "left_office ASC NULLS LAST");
+    }
+
+    #[test]
+    fn test_sort_request_query() {
+        #[derive(PartialEq)]
+        struct Column(&'static str);
+
+        impl FieldName for Column {
+            fn field_name(&self) -> &'static str {
+                self.0
+            }
+        }
+
+        let columns = [Column("name"), Column("left_office")];
+        let request = SortRequest {
+            field_name: "left_office",
+            direction: Direction::Descending,
+            nulls: NullHandling::SqlDefault,
+        };
+        let query = request.to_query();
+        assert_eq!(query, "sort=left_office&dir=desc&nulls=first");
+        assert_eq!(
+            SortRequest::from_query(&query, &columns),
+            Some(SortRequest {
+                nulls: NullHandling::First,
+                ..request
+            })
+        );
+        // Only known fields are accepted
+        assert_eq!(
+            SortRequest::from_query("?sort=password&dir=asc", &columns),
+            None
+        );
+        assert_eq!(
+            SortRequest::from_query("?sort=name&dir=asc&page=2", &columns),
+            Some(SortRequest {
+                field_name: "name",
+                direction: Direction::Ascending,
+                nulls: NullHandling::Last,
+            })
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::GroupedRows;
+
+/// Class names for rows based on their position after sorting: odd and even stripes, the first and last rows and the first and last rows of each group. Styling by position this way follows the sort rather than the data. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Person { name: String }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name }
+/// # impl PartialOrdBy<Person> for PersonField {
+/// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { a.name.partial_cmp(&b.name) }
+/// # }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn People(people: Vec<Person>) -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     let mut people = people;
+///     sorter.sort(&mut people);
+///     let classes = RowClasses::default();
+///     rsx! {
+///         for (class, person) in classes.rows(&people) {
+///             tr { class: class, td { "{person.name}" } }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Positions count from zero but the first row is odd, matching CSS's `:nth-child(odd)`. An empty class name is left out. Leave hover effects to CSS e.g., `tr:hover`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowClasses {
+    /// Class of the 1st, 3rd, 5th… rows. Defaults to "odd".
+    pub odd: String,
+    /// Class of the 2nd, 4th, 6th… rows. Defaults to "even".
+    pub even: String,
+    /// Class of the first row. Defaults to "first".
+    pub first: String,
+    /// Class of the last row. Defaults to "last".
+    pub last: String,
+    /// Class of the first row of each group. Only added by grouped methods. Defaults to "group-first".
+    pub group_first: String,
+    /// Class of the last row of each group. Only added by grouped methods. Defaults to "group-last".
+    pub group_last: String,
+}
+
+impl Default for RowClasses {
+    fn default() -> Self {
+        Self {
+            odd: "odd".to_string(),
+            even: "even".to_string(),
+            first: "first".to_string(),
+            last: "last".to_string(),
+            group_first: "group-first".to_string(),
+            group_last: "group-last".to_string(),
+        }
+    }
+}
+
+impl RowClasses {
+    /// Returns the classes of the row at a sorted position out of `total` rows.
+    pub fn class(&self, position: usize, total: usize) -> String {
+        join([
+            Some(self.stripe(position)),
+            (position == 0).then_some(&self.first),
+            (position + 1 == total).then_some(&self.last),
+        ])
+    }
+
+    /// Returns the classes of the row at a sorted position out of `total` rows that is at `group_position` within a group of `group_len` rows.
+    pub fn grouped_class(
+        &self,
+        position: usize,
+        total: usize,
+        group_position: usize,
+        group_len: usize,
+    ) -> String {
+        join([
+            Some(self.stripe(position)),
+            (position == 0).then_some(&self.first),
+            (position + 1 == total).then_some(&self.last),
+            (group_position == 0).then_some(&self.group_first),
+            (group_position + 1 == group_len).then_some(&self.group_last),
+        ])
+    }
+
+    /// Iterates over sorted rows with their classes. See [`Self::class`].
+    pub fn rows<'a, 'b, T>(&'a self, rows: &'b [T]) -> impl Iterator<Item = (String, &'b T)> + 'a
+    where
+        'b: 'a,
+    {
+        let total = rows.len();
+        rows.iter()
+            .enumerate()
+            .map(move |(position, row)| (self.class(position, total), row))
+    }
+
+    /// Iterates over sorted rows with their classes, marking the first and last rows of each run of rows sharing a group value. Sort with [`crate::UseGrouper::sort`] first so each group is contiguous. See [`GroupedRows`].
+    pub fn grouped_rows<'a, 'b, T, K: PartialEq>(
+        &'a self,
+        rows: &'b [T],
+        group_value: impl Fn(&T) -> K + 'a,
+    ) -> impl Iterator<Item = (String, &'b T)> + 'a
+    where
+        'b: 'a,
+    {
+        let total = rows.len();
+        let mut position = 0;
+        GroupedRows::new(rows, group_value).flat_map(move |(_, group)| {
+            let start = position;
+            position += group.len();
+            group.iter().enumerate().map(move |(group_position, row)| {
+                let class =
+                    self.grouped_class(start + group_position, total, group_position, group.len());
+                (class, row)
+            })
+        })
+    }
+
+    fn stripe(&self, position: usize) -> &String {
+        match position % 2 {
+            0 => &self.odd,
+            _ => &self.even,
+        }
+    }
+}
+
+/// Joins non-empty class names with spaces.
+fn join<'a>(classes: impl IntoIterator<Item = Option<&'a String>>) -> String {
+    classes
+        .into_iter()
+        .flatten()
+        .filter(|class| !class.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_classes() {
+        let classes = RowClasses::default();
+        let rows = [1, 2, 3];
+        let rows = classes
+            .rows(&rows)
+            .map(|(class, _)| class)
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["odd first", "even", "odd last"]);
+
+        let rows = [("a", 1), ("a", 2), ("b", 3)];
+        let grouped = classes
+            .grouped_rows(&rows, |row| row.0)
+            .map(|(class, _)| class)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            grouped,
+            vec![
+                "odd first group-first",
+                "even group-last",
+                "odd last group-first group-last"
+            ]
+        );
+
+        let classes = RowClasses {
+            first: String::new(),
+            ..RowClasses::default()
+        };
+        assert_eq!(classes.class(0, 1), "odd last");
+    }
+}
@@ -1,89 +1,2342 @@
 #![allow(non_snake_case)]
-use crate::{Direction, SortBy, Sortable, UseSorter};
+use crate::aggregates::Footer;
+use crate::sort::{describe_sort, sortable_by};
+use crate::theme::{use_theme, ThLabels};
+use crate::{
+    AllFields, CellSort, Direction, FilterBy, NullHandling, PartialOrdBy, RowClasses,
+    SelectionMode, SortBySelection, Sortable, UseColumnOrder, UseColumnWidths, UseColumns,
+    UseDynSorter, UseFilter, UseGrouper, UseInfiniteScroll, UsePaginator, UsePinnedColumns,
+    UseSelection, UseSorter, UseVirtualRows, MIN_COLUMN_WIDTH,
+};
 use dioxus::prelude::*;
+use std::{fmt, rc::Rc};
 
 /// See [`Th`].
-#[derive(Props)]
-pub struct ThProps<'a, F: 'static> {
-    sorter: UseSorter<'a, F>,
+#[derive(Props, Clone, PartialEq)]
+pub struct ThProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
     field: F,
-    children: Element<'a>,
+    /// Optional `class` attribute for the `<th>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional `style` attribute for the `<th>`.
+    #[props(into)]
+    style: Option<String>,
+    /// Optional `rowspan` attribute for the `<th>`. Use 2 for headers beside a [`ThGroup`] so they span both rows.
+    rowspan: Option<u32>,
+    /// Optional custom status icons. See [`ThIcons`].
+    icons: Option<ThIcons>,
+    /// Optional renderer for the whole status indicator. See [`ThStatusProps`].
+    indicator: Option<Callback<IndicatorState, Element>>,
+    /// Optional screen reader text for the sort state. See [`ThLabels`].
+    labels: Option<ThLabels>,
+    /// Optional column hooks that each add a feature to the header. See [`ThHooks`].
+    #[props(default)]
+    hooks: ThHooks<F>,
+    /// Optional tooltip text shown by the browser. Set as the `title` attribute.
+    #[props(into)]
+    title: Option<String>,
+    /// Optional custom tooltip shown below the header on hover or focus. Use in place of `title` for rich content.
+    popover: Option<Element>,
+    /// Optional secondary line under the label e.g., units.
+    #[props(into)]
+    sublabel: Option<String>,
+    /// Optional filter input under the label e.g., a [`ColumnFilter`]. Clicks and keys inside it don't change the sort.
+    filter: Option<Element>,
+    /// Modifier keys that add the field as a secondary sort on click. Defaults to Shift. See [`ThClick::Add`].
+    #[props(default = Modifiers::SHIFT)]
+    add_modifiers: Modifiers,
+    /// Modifier keys that remove the field from the sort on click. Defaults to Ctrl. See [`ThClick::Remove`].
+    #[props(default = Modifiers::CONTROL)]
+    remove_modifiers: Modifiers,
+    /// Optional handler called after a click (or Enter or Space) with what it did and the modifier keys held.
+    onclick: Option<EventHandler<(ThClick, Modifiers)>>,
+    /// Optional right to left layout. Replaces the theme's [`crate::SorterTheme::rtl`].
+    rtl: Option<bool>,
+    children: Element,
+}
+
+/// Column hooks a [`Th`] works with. Each is optional and adds a feature to the header. Build one and pass it to every header in the row. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name, Age }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Headers() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     let hooks = ThHooks {
+///         widths: Some(use_column_widths()),
+///         order: Some(use_column_order(&[PersonField::Name, PersonField::Age])),
+///         ..Default::default()
+///     };
+///     rsx! {
+///         tr {
+///             Th { sorter, field: PersonField::Name, hooks, "Name" }
+///             Th { sorter, field: PersonField::Age, hooks, "Age" }
+///         }
+///     }
+/// }
+/// ```
+#[derive(PartialEq)]
+pub struct ThHooks<F: 'static> {
+    /// Column visibility. Nothing is rendered if the field's column is hidden.
+    pub columns: Option<UseColumns<F>>,
+    /// Column widths. Adds a resize grip to the header's right edge.
+    pub widths: Option<UseColumnWidths<F>>,
+    /// Column order. Adds a drag handle to reorder columns.
+    pub order: Option<UseColumnOrder<F>>,
+    /// Grouping. Clicking a [`crate::SortBy::GroupOnly`] field groups by it. Other fields ignore this.
+    pub grouper: Option<UseGrouper<F>>,
+}
+
+impl<F> Clone for ThHooks<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for ThHooks<F> {}
+
+impl<F> Default for ThHooks<F> {
+    fn default() -> Self {
+        Self {
+            columns: None,
+            widths: None,
+            order: None,
+            grouper: None,
+        }
+    }
+}
+
+/// What activating a [`Th`] did to the sort. Chosen by the modifier keys held. See [`ThProps`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ThClick {
+    /// Replaced the sort with the field. See [`UseSorter::toggle_field`].
+    Replace,
+    /// Added the field as a secondary sort. See [`UseSorter::add_sort`].
+    Add,
+    /// Removed the field from the sort. See [`UseSorter::remove_sort`].
+    Remove,
+}
+
+impl ThClick {
+    /// Returns the action for the modifier keys held. Removing takes precedence. Empty modifiers never match.
+    fn from_modifiers(held: Modifiers, add: Modifiers, remove: Modifiers) -> Self {
+        let matches = |wanted: Modifiers| !wanted.is_empty() && held.contains(wanted);
+        if matches(remove) {
+            Self::Remove
+        } else if matches(add) {
+            Self::Add
+        } else {
+            Self::Replace
+        }
+    }
+}
+
+/// Secondary line under a [`Th`] label.
+const SUBLABEL_STYLE: &str = "display: block; font-size: smaller; font-weight: normal;";
+/// Custom tooltip below a [`Th`].
+const POPOVER_STYLE: &str =
+    "position: absolute; top: 100%; inset-inline-start: 0; z-index: 3; font-weight: normal; background: Canvas;";
+/// Resize grip along the trailing edge of a [`Th`]: the left edge when right to left.
+const GRIP_STYLE: &str = "position: absolute; top: 0; inset-inline-end: 0; bottom: 0; width: 6px; cursor: col-resize; touch-action: none; user-select: none;";
+/// Drag handle before a [`Th`] label.
+const DRAG_HANDLE_STYLE: &str = "cursor: grab; user-select: none; padding-inline-end: 0.25em;";
+/// Covers the page while resizing so the pointer is tracked outside the header.
+const RESIZE_OVERLAY_STYLE: &str = "position: fixed; inset: 0; z-index: 1000; cursor: col-resize;";
+
+/// Returns true for keys that activate a focused header: Enter and Space.
+fn activates(key: &Key) -> bool {
+    match key {
+        Key::Enter => true,
+        Key::Character(c) => c == " ",
+        _ => false,
+    }
 }
 
 /// Convenience helper. Builds a `<th>` element with a click handler that calls [`UseSorter::toggle_field`]. Renders the current state using [`ThStatus`].
-pub fn Th<'a, F: Copy + Sortable>(cx: Scope<'a, ThProps<'a, F>>) -> Element<'a> {
-    let sorter = cx.props.sorter;
-    let field = cx.props.field;
-    cx.render(rsx! {
+///
+/// Accessible by default: has `role="columnheader"` and an `aria-sort` of `ascending`, `descending` or `none`. Sortable headers are focusable and toggle on Enter or Space.
+///
+/// Takes optional column hooks in a [`ThHooks`]. Renders nothing if given [`UseColumns`] and the field's column is hidden.
+///
+/// Adds the active or inactive class from any [`crate::SorterTheme`] in context.
+///
+/// Fields disabled with [`UseSorter::disable_field`] render as a plain header: no arrow, not focusable, ignore clicks and have `aria-disabled="true"`.
+///
+/// [`crate::SortBy::GroupOnly`] fields have no arrow. Given a [`UseGrouper`], clicking one groups by it (or stops grouping) and it's active while grouped by.
+///
+/// Given [`UseColumnWidths`], the header is sized to the field's width and gets a `.resize-grip` to drag. Double-click the grip to restore the natural width. Dragging doesn't change the sort.
+///
+/// Given [`UseColumnOrder`], the header gets a `.drag-handle` before its label. Drop it on another header to move the column there. The header being dragged over gets a `dragover` class.
+///
+/// A `sublabel` is rendered as a `.th-sublabel` line below the label and sort indicator, e.g., "Height" over "cm". A `filter` such as a [`ColumnFilter`] is rendered in a `.th-filter` below that. A `popover` is rendered in a `.th-popover` with `role="tooltip"` while the header is hovered or focused.
+///
+/// Sorts by several fields like a spreadsheet: a plain click replaces the sort, Shift-click adds the field as a secondary sort and Ctrl-click removes it. Change the keys with `add_modifiers` and `remove_modifiers`, e.g., `Modifiers::META` for Cmd on macOS where Ctrl-click opens a context menu. Pass `Modifiers::empty()` to turn an action off.
+pub fn Th<F: Copy + Sortable + 'static>(props: ThProps<F>) -> Element {
+    let theme = use_theme();
+    let mut mounted = use_signal(|| None::<Rc<MountedData>>);
+    let mut hovered = use_signal(|| false);
+    let mut dragged_over = use_signal(|| false);
+    let sorter = props.sorter;
+    let field = props.field;
+    let rtl = props.rtl.unwrap_or(theme.rtl);
+    if let Some(columns) = props.hooks.columns {
+        if !columns.is_visible(&field) {
+            return rsx! {};
+        }
+    }
+    let (active_field, active_dir) = sorter.get_state();
+    let active = sorter.is_sorted() && active_field == field;
+    let aria_sort = match (active, active_dir) {
+        (true, Direction::Ascending) => "ascending",
+        (true, Direction::Descending) => "descending",
+        (false, _) => "none",
+    };
+    // Group only fields are toggled through the grouper rather than the sorter
+    let group_only = field
+        .sort_by()
+        .is_some_and(|sort_by| sort_by.is_group_only());
+    let grouper = props.hooks.grouper.filter(|_| group_only);
+    let grouped = grouper.is_some_and(|grouper| grouper.field() == Some(field));
+    let enabled = sorter.is_enabled(&field);
+    let focusable = (sortable_by(&field).is_some() || grouper.is_some()) && enabled;
+    let tabindex = if focusable { "0" } else { "-1" };
+    let class = [
+        props.class.as_deref().unwrap_or_default(),
+        theme.class(active || grouped),
+        if dragged_over() { "dragover" } else { "" },
+    ]
+    .into_iter()
+    .filter(|class| !class.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    // Positioned so the grip and popover sit against the header. Later styles take precedence: the user's over resized widths over the field's hints
+    let position = match props.hooks.widths.is_some() || props.popover.is_some() {
+        true => "position: relative;",
+        false => "",
+    };
+    let width_hint = field
+        .width_hint()
+        .map(|width| format!("width: {width};"))
+        .unwrap_or_default();
+    let widths = props
+        .hooks
+        .widths
+        .map(|widths| widths.style(&field))
+        .unwrap_or_default();
+    let style = [
+        position,
+        align_style(&field),
+        &width_hint,
+        &widths,
+        props.style.as_deref().unwrap_or_default(),
+    ]
+    .into_iter()
+    .filter(|style| !style.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    let sublabel = props.sublabel.map(|sublabel| {
+        rsx! {
+            span { class: "th-sublabel", style: SUBLABEL_STYLE, "{sublabel}" }
+        }
+    });
+    // Typing a space or clicking into the input mustn't toggle the sort
+    let filter = props.filter.map(|filter| {
+        rsx! {
+            div {
+                class: "th-filter",
+                onclick: move |evt| evt.stop_propagation(),
+                onkeydown: move |evt| evt.stop_propagation(),
+                {filter}
+            }
+        }
+    });
+    let popover = props.popover.filter(|_| hovered()).map(|popover| {
+        rsx! {
+            div { class: "th-popover", style: POPOVER_STYLE, role: "tooltip", {popover} }
+        }
+    });
+    let handle = props.hooks.order.map(|order| {
+        rsx! {
+            span {
+                class: "drag-handle",
+                style: DRAG_HANDLE_STYLE,
+                draggable: "true",
+                "aria-hidden": "true",
+                onclick: move |evt| evt.stop_propagation(),
+                ondragstart: move |_| order.start_drag(field),
+                ondragend: move |_| order.end_drag(),
+                "⠿"
+            }
+        }
+    });
+    let order = props.hooks.order;
+    let (add_modifiers, remove_modifiers) = (props.add_modifiers, props.remove_modifiers);
+    let onclick = props.onclick;
+    let activate = move |held: Modifiers| {
+        if !enabled {
+            return;
+        }
+        if group_only {
+            if let Some(grouper) = grouper {
+                grouper.toggle_field(field);
+            }
+            return;
+        }
+        let click = ThClick::from_modifiers(held, add_modifiers, remove_modifiers);
+        match click {
+            ThClick::Replace => sorter.toggle_field(field),
+            ThClick::Add => sorter.add_sort(field),
+            ThClick::Remove => sorter.remove_sort(field),
+        }
+        if let Some(onclick) = onclick {
+            onclick.call((click, held));
+        }
+    };
+    // Dragging a grip on the left edge leftwards widens the column
+    let pointer_x = move |client_x: f64| if rtl { -client_x } else { client_x };
+    let grip = props.hooks.widths.map(|widths| {
+        rsx! {
+            div {
+                class: "resize-grip",
+                style: GRIP_STYLE,
+                onpointerdown: move |evt| {
+                    evt.stop_propagation();
+                    evt.prevent_default();
+                    let client_x = pointer_x(evt.client_coordinates().x);
+                    if let Some(width) = widths.width(&field) {
+                        widths.start_resize(field, client_x, width);
+                        return;
+                    }
+                    // Not resized yet so measure the natural width
+                    let mounted = mounted.peek().clone();
+                    spawn(async move {
+                        let width = match mounted {
+                            Some(mounted) => mounted
+                                .get_client_rect()
+                                .await
+                                .map_or(MIN_COLUMN_WIDTH, |rect| rect.width()),
+                            None => MIN_COLUMN_WIDTH,
+                        };
+                        widths.start_resize(field, client_x, width);
+                    });
+                },
+                onclick: move |evt| evt.stop_propagation(),
+                ondoubleclick: move |evt| {
+                    evt.stop_propagation();
+                    widths.reset(&field);
+                },
+            }
+        }
+    });
+    // Rendered outside the header so releasing the pointer doesn't click it
+    let overlay = props
+        .hooks
+        .widths
+        .filter(|widths| widths.resizing() == Some(field))
+        .map(|widths| {
+            rsx! {
+                div {
+                    style: RESIZE_OVERLAY_STYLE,
+                    onpointermove: move |evt| widths.resize_to(pointer_x(evt.client_coordinates().x)),
+                    onpointerup: move |_| widths.end_resize(),
+                    onpointercancel: move |_| widths.end_resize(),
+                }
+            }
+        });
+    rsx! {
         th {
-            onclick: move |_| sorter.toggle_field(field),
-            &cx.props.children
-            ThStatus {
-                sorter: sorter,
+            class: class,
+            style: style,
+            role: "columnheader",
+            "aria-sort": aria_sort,
+            "aria-disabled": (!enabled).then_some("true"),
+            tabindex: tabindex,
+            rowspan: props.rowspan,
+            title: props.title,
+            onclick: move |evt| activate(evt.modifiers()),
+            onkeydown: move |evt| {
+                if activates(&evt.key()) {
+                    // Space would otherwise scroll the page
+                    evt.prevent_default();
+                    activate(evt.modifiers());
+                }
+            },
+            onmounted: move |evt| mounted.set(Some(evt.data())),
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
+            onfocus: move |_| hovered.set(true),
+            onblur: move |_| hovered.set(false),
+            ondragover: move |evt| {
+                // Allows dropping. Only columns of this header's order are accepted
+                if order.is_some_and(|order| order.dragging().is_some()) {
+                    evt.prevent_default();
+                    dragged_over.set(true);
+                }
+            },
+            ondragleave: move |_| dragged_over.set(false),
+            ondrop: move |evt| {
+                evt.prevent_default();
+                dragged_over.set(false);
+                if let Some(order) = order {
+                    order.drop_on(field);
+                }
+            },
+            {handle}
+            if rtl {
+                ThStatus {
+                    sorter: sorter,
+                    field: field,
+                    icons: props.icons.clone(),
+                    indicator: props.indicator,
+                    labels: props.labels.clone(),
+                    rtl: rtl,
+                }
+            }
+            {props.children}
+            if !rtl {
+                ThStatus {
+                    sorter: sorter,
+                    field: field,
+                    icons: props.icons,
+                    indicator: props.indicator,
+                    labels: props.labels,
+                    rtl: rtl,
+                }
+            }
+            {sublabel}
+            {filter}
+            {popover}
+            {grip}
+        }
+        {overlay}
+    }
+}
+
+/// See [`AllTh`].
+#[derive(Props, Clone, PartialEq)]
+pub struct AllThProps<F: Copy + Sortable + AllFields> {
+    sorter: UseSorter<F>,
+    /// Optional labels replacing the [`Sortable::label`] of some fields. See [`FieldLabels`].
+    #[props(into, default)]
+    labels: FieldLabels<F>,
+    /// Optional `class` attribute for each `<th>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional column visibility. Hidden fields' headers aren't rendered.
+    columns: Option<UseColumns<F>>,
+}
+
+/// Convenience helper. Renders a [`Th`] for every field in [`AllFields::ALL`] order, labelled by [`Sortable::label`]. Headers stay in step with the field enum: implement [`AllFields`] with [`crate::all_fields!`] and adding a variant won't compile until it has a column. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// enum PersonField {
+///     #[default]
+///     Name,
+///     Age,
+/// }
+///
+/// all_fields!(PersonField { Name, Age });
+///
+/// impl Sortable for PersonField {
+///     fn sort_by(&self) -> Option<SortBy> {
+///         SortBy::increasing_or_decreasing()
+///     }
+///
+///     fn label(&self) -> &str {
+///         match self {
+///             Self::Name => "Name",
+///             Self::Age => "Age",
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn People() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     rsx! {
+///         table {
+///             thead {
+///                 tr { AllTh { sorter: sorter, labels: vec![(PersonField::Age, "Age in years")] } }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Use [`Th`] directly for per-column options such as tooltips, widths or filters.
+pub fn AllTh<F: Copy + Sortable + AllFields>(props: AllThProps<F>) -> Element {
+    let label = |field: &F| {
+        let custom = props.labels.iter().find(|(f, _)| *f == field);
+        custom
+            .map_or_else(|| field.label(), |(_, label)| label)
+            .to_string()
+    };
+    rsx! {
+        for &field in F::ALL {
+            Th {
+                sorter: props.sorter,
                 field: field,
+                class: props.class.clone(),
+                hooks: ThHooks {
+                    columns: props.columns,
+                    ..Default::default()
+                },
+                "{label(&field)}"
             }
         }
-    })
+    }
+}
+
+/// See [`ThGroup`].
+#[derive(Props, Clone, PartialEq)]
+pub struct ThGroupProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
+    /// Fields of the sub-columns spanned, rendered as [`Th`]s in the row below.
+    fields: Vec<F>,
+    /// Optional `class` attribute for the `<th>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional `style` attribute for the `<th>`.
+    #[props(into)]
+    style: Option<String>,
+    /// Optional column visibility. Hidden sub-columns aren't spanned. Pass the same [`UseColumns`] as the sub-columns' [`Th`]s.
+    columns: Option<UseColumns<F>>,
+    children: Element,
+}
+
+/// Convenience helper. Renders a `<th>` labelling a group of sortable sub-columns in a two-row header e.g., "Office" over "Took office" and "Left office". The group itself doesn't sort: render a [`Th`] per field in the next row as usual. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name, TookOffice, LeftOffice }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Header() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     rsx! {
+///         thead {
+///             tr {
+///                 Th { sorter: sorter, field: PersonField::Name, rowspan: 2, "Name" }
+///                 ThGroup {
+///                     sorter: sorter,
+///                     fields: vec![PersonField::TookOffice, PersonField::LeftOffice],
+///                     "Office"
+///                 }
+///             }
+///             tr {
+///                 Th { sorter: sorter, field: PersonField::TookOffice, "Took office" }
+///                 Th { sorter: sorter, field: PersonField::LeftOffice, "Left office" }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Has `scope="colgroup"` and a `colspan` of the visible sub-columns. Renders nothing if they're all hidden. Adds the active class from any [`crate::SorterTheme`] in context while any sub-column is sorted by, otherwise the inactive class.
+pub fn ThGroup<F: Copy + Sortable + 'static>(props: ThGroupProps<F>) -> Element {
+    let theme = use_theme();
+    let sorter = props.sorter;
+    let visible = props
+        .fields
+        .iter()
+        .filter(|field| {
+            props
+                .columns
+                .is_none_or(|columns| columns.is_visible(field))
+        })
+        .collect::<Vec<_>>();
+    if visible.is_empty() {
+        return rsx! {};
+    }
+    let colspan = visible.len();
+    let active = visible.iter().any(|field| sorter.priority(field).is_some());
+    let class = [
+        props.class.as_deref().unwrap_or_default(),
+        theme.class(active),
+    ]
+    .into_iter()
+    .filter(|class| !class.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    rsx! {
+        th {
+            class: class,
+            style: props.style,
+            scope: "colgroup",
+            colspan: "{colspan}",
+            {props.children}
+        }
+    }
+}
+
+/// See [`DynTh`].
+#[derive(Props, Clone, PartialEq)]
+pub struct DynThProps<T: Clone + PartialEq + 'static> {
+    sorter: UseDynSorter<T>,
+    /// Index of the column.
+    column: usize,
+    /// Optional `class` attribute for the `<th>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional `style` attribute for the `<th>`.
+    #[props(into)]
+    style: Option<String>,
+}
+
+/// Convenience helper. Renders a [`Th`] for a column of a [`UseDynSorter`], labelled with the column's [`crate::DynColumn::label`]. Renders nothing for unknown columns.
+///
+/// For other [`Th`] features or [`ThStatus`] on its own, pass [`UseDynSorter::sorter`] and [`UseDynSorter::field`] instead.
+pub fn DynTh<T: Clone + PartialEq + 'static>(props: DynThProps<T>) -> Element {
+    let sorter = props.sorter;
+    let (Some(field), Some(column)) = (sorter.field(props.column), sorter.column(props.column))
+    else {
+        return rsx! {};
+    };
+    rsx! {
+        Th {
+            sorter: sorter.sorter(),
+            field: field,
+            class: props.class,
+            style: props.style,
+            "{column.label()}"
+        }
+    }
+}
+
+/// Custom icons rendered by [`ThStatus`] in place of the default arrows. Use to match a design system e.g., with SVG icons or icon font classes.
+///
+/// Fields sortable in one direction only share the ascending and descending icons. To tell them apart from reversible fields, pass an `indicator` instead and check [`IndicatorState::reversible`].
+#[derive(Clone, PartialEq)]
+pub struct ThIcons {
+    /// Field is sorted (or can only be sorted) in ascending order.
+    pub ascending: Element,
+    /// Field is sorted (or can only be sorted) in descending order.
+    pub descending: Element,
+    /// Field can be sorted in either direction but is not the active field.
+    pub neutral: Element,
+    /// Field cannot be sorted.
+    pub unsortable: Element,
+}
+
+/// A header's sort state as shown by [`ThStatus`]. Passed to a custom indicator so it can render anything e.g., SVG icons or badges, without working out the state itself.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IndicatorState {
+    /// True if the field can be sorted by: sortable, not [`crate::SortBy::GroupOnly`] and not disabled with [`UseSorter::disable_field`].
+    pub sortable: bool,
+    /// True if the field can be sorted in both directions.
+    pub reversible: bool,
+    /// True if the field is sorted by, either as the active field or a secondary sort. See [`UseSorter::sort_keys`].
+    pub active: bool,
+    /// Direction the default arrow points: the field's direction if sorted by or sortable in one direction only. `None` for a double-headed arrow or if unsortable.
+    pub direction: Option<Direction>,
+    /// Position from 1 in [`UseSorter::sort_keys`] when sorting by several fields. `None` if not sorted by or the only field.
+    pub priority: Option<usize>,
 }
 
 /// See [`ThStatus`].
-#[derive(PartialEq, Props)]
-pub struct ThStatusProps<'a, F: 'static> {
-    sorter: UseSorter<'a, F>,
+#[derive(Props, Clone, PartialEq)]
+pub struct ThStatusProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
     field: F,
+    /// Optional custom icons. Replaces the default arrows and styling.
+    icons: Option<ThIcons>,
+    /// Optional renderer for the whole indicator given its [`IndicatorState`]. Replaces the icon and priority badge, including any `icons`. The screen reader text is still rendered after it.
+    indicator: Option<Callback<IndicatorState, Element>>,
+    /// Optional screen reader text. Replaces the theme's [`crate::SorterTheme::labels`].
+    labels: Option<ThLabels>,
+    /// Optional right to left layout, rendering the glyph for placement before the label. Replaces the theme's [`crate::SorterTheme::rtl`].
+    rtl: Option<bool>,
+}
+
+/// Hides text visually while keeping it available to screen readers.
+const SR_ONLY_STYLE: &str = "position: absolute; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; border: 0;";
+
+/// Priority badge after a [`ThStatus`] glyph.
+const PRIORITY_STYLE: &str = "font-size: smaller; vertical-align: super;";
+
+/// Which status icon [`ThStatus`] should render.
+enum ThIcon {
+    Ascending,
+    Descending,
+    /// Only sortable in ascending order.
+    FixedAscending,
+    /// Only sortable in descending order.
+    FixedDescending,
+    Neutral,
+    Unsortable,
 }
 
 /// Convenience helper. Renders the [`Sortable`] value for a given [`UseSorter`] and field.
-///  - If the field is unsortable then render an empty string.
-///  - If the field is sortable in one direction then render an arrow pointing in that direction.
-///  - If the field is sortable in both directions then render an arrow pointing in the active direction, or a double-headed arrow if the field is inactive.
+///  - If the field is unsortable, [`crate::SortBy::GroupOnly`] or disabled with [`UseSorter::disable_field`] then render an empty string. Or the theme's [`crate::SorterTheme::unsortable`] glyph, or an invisible placeholder with [`crate::SorterTheme::reserve_unsortable`] so headers line up.
+///  - If the field is sortable in one direction then render an outlined arrow pointing in that direction, so it can be told apart from a field sortable both ways. See [`crate::SorterTheme::fixed_ascending`].
+///  - If the field is sortable in both directions then render an arrow pointing in the active direction, or a double-headed arrow if the field is inactive or unsorted.
 ///
 /// Active fields will be shown in bold (i.e., the current field being sorted by). Inactive fields will be greyed out.
-pub fn ThStatus<'a, F: Copy + Sortable>(cx: Scope<'a, ThStatusProps<'a, F>>) -> Element<'a> {
-    let sorter = &cx.props.sorter;
-    let field = cx.props.field;
-    let (active_field, active_dir) = sorter.get_state();
-    let active = *active_field == field;
+///
+/// Glyphs, colours and classes come from any [`crate::SorterTheme`] in context. Pass [`ThIcons`] to render your own icons instead. They are rendered as-is without the theme's styling. For full control, pass an `indicator` rendering the [`IndicatorState`] however you like. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// fn chevron(state: IndicatorState) -> Element {
+///     let rotate = match state.direction {
+///         Some(Direction::Ascending) => "rotate(180deg)",
+///         _ => "none",
+///     };
+///     rsx! {
+///         if state.sortable {
+///             svg {
+///                 class: if state.active { "chevron active" } else { "chevron" },
+///                 style: "transform: {rotate};",
+///                 width: "12",
+///                 height: "12",
+///                 path { d: "M2 4l4 4 4-4" }
+///             }
+///         }
+///         if let Some(priority) = state.priority {
+///             sup { "{priority}" }
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn Header() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     rsx! {
+///         Th { sorter: sorter, field: PersonField::Name, indicator: chevron, "Name" }
+///     }
+/// }
+/// ```
+///
+/// Sortable fields are followed by a visually hidden `.sr-only` span describing the state for screen readers e.g., "sorted ascending". Pass [`ThLabels`] or set them on the theme to localise.
+///
+/// When sorting by several fields (see [`UseSorter::add_sort`]), each sorted field is active and shows its own direction followed by a `.th-priority` badge numbering it from 1 in [`UseSorter::sort_keys`] order. Style badges with [`crate::SorterTheme::priority_class`] or turn them off with [`crate::SorterTheme::show_priority`].
+///
+/// With [`crate::SorterTheme::rtl`] the badge comes first and the gap is on the glyph's other side, so render it before the label. [`Th`] does this for you.
+pub fn ThStatus<F: Copy + Sortable + 'static>(props: ThStatusProps<F>) -> Element {
+    let theme = use_theme();
+    let sorter = props.sorter;
+    let field = props.field;
+    let rtl = props.rtl.unwrap_or(theme.rtl);
+    let gap = if rtl { THSPAN_RTL_STYLE } else { THSPAN_STYLE };
+    let keys = sorter.sort_keys();
+    let position = keys.iter().position(|(other, _)| *other == field);
+    let (active, active_dir) = match position {
+        Some(position) => (true, keys[position].1),
+        None => (false, sorter.get_state().1),
+    };
+    // Only numbered when there's more than one to tell apart
+    let priority = position
+        .filter(|_| keys.len() > 1)
+        .map(|position| position + 1);
 
-    cx.render(match field.sort_by() {
-        None => rsx!(""),
+    let icon = match sortable_by(&field).filter(|_| sorter.is_enabled(&field)) {
+        None => ThIcon::Unsortable,
         Some(sort_by) => {
             use Direction::*;
-            use SortBy::*;
-            match sort_by {
-                Fixed(Ascending) => rsx!(ThSpan { active: active, "↓" }),
-                Fixed(Descending) => rsx!(ThSpan { active: active, "↑" }),
+            match (sort_by.is_reversible(), active, active_dir) {
+                (false, _, _) => match sort_by.direction() {
+                    Ascending => ThIcon::FixedAscending,
+                    Descending => ThIcon::FixedDescending,
+                },
+                (true, true, Ascending) => ThIcon::Ascending,
+                (true, true, Descending) => ThIcon::Descending,
+                (true, false, _) => ThIcon::Neutral,
+            }
+        }
+    };
+
+    let state = IndicatorState {
+        sortable: !matches!(icon, ThIcon::Unsortable),
+        reversible: sortable_by(&field).is_some_and(|sort_by| sort_by.is_reversible()),
+        active,
+        direction: match icon {
+            ThIcon::Ascending | ThIcon::FixedAscending => Some(Direction::Ascending),
+            ThIcon::Descending | ThIcon::FixedDescending => Some(Direction::Descending),
+            ThIcon::Neutral | ThIcon::Unsortable => None,
+        },
+        priority,
+    };
+
+    let labels = props.labels.as_ref().unwrap_or(&theme.labels);
+    let label = match (&icon, active, active_dir) {
+        (ThIcon::Unsortable, _, _) => "",
+        (_, true, Direction::Ascending) => &labels.ascending,
+        (_, true, Direction::Descending) => &labels.descending,
+        (_, false, _) => &labels.unsorted,
+    };
+    let label = match priority {
+        Some(priority) if !label.is_empty() => format!("{label}, {} {priority}", labels.priority),
+        _ => label.to_string(),
+    };
+    let label = (!label.is_empty()).then(|| {
+        rsx! {
+            span { class: "sr-only", style: SR_ONLY_STYLE, "{label}" }
+        }
+    });
+    if let Some(indicator) = props.indicator {
+        let indicator = indicator.call(state);
+        return rsx! {
+            {indicator}
+            {label}
+        };
+    }
+    let badge = priority.filter(|_| theme.show_priority).map(|priority| {
+        let class = format!("th-priority {}", theme.priority_class);
+        let style = match theme.colour(true) {
+            "" => PRIORITY_STYLE.to_string(),
+            colour => format!("{PRIORITY_STYLE} color: {colour};"),
+        };
+        rsx! {
+            span {
+                class: class.trim_end(),
+                style: style,
+                "aria-hidden": "true",
+                "{priority}"
+            }
+        }
+    });
 
-                Reversible(_) => rsx!(
+    let icon = match props.icons {
+        Some(icons) => match icon {
+            ThIcon::Ascending | ThIcon::FixedAscending => icons.ascending,
+            ThIcon::Descending | ThIcon::FixedDescending => icons.descending,
+            ThIcon::Neutral => icons.neutral,
+            ThIcon::Unsortable => icons.unsortable,
+        },
+        None => {
+            let glyph = match icon {
+                ThIcon::Unsortable if !theme.unsortable.is_empty() => &theme.unsortable,
+                ThIcon::Unsortable if theme.reserve_unsortable => {
+                    return rsx! {
+                        span {
+                            style: "{gap} visibility: hidden;",
+                            "aria-hidden": "true",
+                            "{theme.neutral}"
+                        }
+                    };
+                }
+                ThIcon::Unsortable => return rsx! {},
+                ThIcon::Ascending => &theme.ascending,
+                ThIcon::Descending => &theme.descending,
+                ThIcon::FixedAscending => &theme.fixed_ascending,
+                ThIcon::FixedDescending => &theme.fixed_descending,
+                ThIcon::Neutral => &theme.neutral,
+            };
+            rsx! {
                 ThSpan {
-                    active: active,
-                    match (active, active_dir) {
-                        (true, Direction::Ascending) => "↓",
-                        (true, Direction::Descending) => "↑",
-                        (false, _) => "↕",
-                    }
-                }),
+                    colour: theme.colour(active),
+                    class: theme.class(active),
+                    gap: gap,
+                    "{glyph}"
+                }
             }
         }
-    })
+    };
+    // Keeps the glyph beside the label with the badge on the far side
+    if rtl {
+        return rsx! {
+            {badge}
+            {icon}
+            {label}
+        };
+    }
+    rsx! {
+        {icon}
+        {badge}
+        {label}
+    }
 }
 
 /// See [`ThSpan`].
-#[derive(Props)]
-struct ThSpan<'a> {
-    active: bool,
-    children: Element<'a>,
+#[derive(Props, Clone, PartialEq)]
+struct ThSpanProps {
+    #[props(into)]
+    colour: String,
+    #[props(into)]
+    class: String,
+    /// Either [`THSPAN_STYLE`] or [`THSPAN_RTL_STYLE`].
+    gap: &'static str,
+    children: Element,
+}
+
+/// Gap between a [`Th`] label and its status glyph. Padding rather than a `&nbsp;` so markup is plain text on every renderer.
+const THSPAN_STYLE: &str = "padding-inline-start: 0.25em;";
+/// Gap between a status glyph and the [`Th`] label after it when right to left.
+const THSPAN_RTL_STYLE: &str = "padding-inline-end: 0.25em;";
+
+/// Convenience helper. Renders a status glyph in the given colour and class.
+fn ThSpan(props: ThSpanProps) -> Element {
+    let style = if props.colour.is_empty() {
+        props.gap.to_string()
+    } else {
+        format!("{} color: {};", props.gap, props.colour)
+    };
+    rsx! {
+        span { class: props.class, style: style, {props.children} }
+    }
+}
+
+/// A column of a [`SortableTable`]. Type `T` is the row and `F` is the field enum.
+pub struct TableColumn<T, F> {
+    /// Header label rendered inside a [`Th`].
+    pub label: &'static str,
+    /// Field used to sort this column.
+    pub field: F,
+    /// Renders the contents of this column's `<td>` for a row.
+    pub cell: Rc<dyn Fn(&T) -> Element>,
+    /// Optionally renders the `<td>` contents knowing how the column is sorted, in place of `cell`. See [`Self::with_format`].
+    pub format: Option<Rc<dyn CellFormat<T, F>>>,
+}
+
+impl<T, F> TableColumn<T, F> {
+    /// Creates a column. The `cell` closure renders the contents of each `<td>` e.g., `|row: &Person| rsx!("{row.name}")`.
+    pub fn new(label: &'static str, field: F, cell: impl Fn(&T) -> Element + 'static) -> Self {
+        Self {
+            label,
+            field,
+            cell: Rc::new(cell),
+            format: None,
+        }
+    }
+
+    /// Renders cells with a [`CellFormat`] that's told how the column is sorted, in place of the `cell` closure. For example, `|row: &Person, sort: CellSort<PersonField>| if sort.is_active() { rsx!(b { "{row.name}" }) } else { rsx!("{row.name}") }`.
+    pub fn with_format(mut self, format: impl CellFormat<T, F> + 'static) -> Self {
+        self.format = Some(Rc::new(format));
+        self
+    }
+}
+
+impl<T, F: Clone> Clone for TableColumn<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            label: self.label,
+            field: self.field.clone(),
+            cell: self.cell.clone(),
+            format: self.format.clone(),
+        }
+    }
+}
+
+impl<T, F: PartialEq> PartialEq for TableColumn<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && self.field == other.field
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && match (&self.format, &other.format) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+/// Renders a [`TableColumn`]'s cell knowing how the column is sorted. Lets cells adapt to the sort e.g., bold the sorted column's values or show how far a row's rank moved. Implemented for closures taking the row and a [`CellSort`]. See [`TableColumn::with_format`].
+///
+/// [`SortableTable`] calls it for each cell. Manual tables can get the same [`CellSort`] from [`UseSorter::cell_sort`] or check [`UseSorter::is_active`].
+pub trait CellFormat<T, F> {
+    /// Renders the contents of a row's `<td>` for the column's sort state.
+    fn format(&self, row: &T, sort: CellSort<F>) -> Element;
+}
+
+impl<T, F, C: Fn(&T, CellSort<F>) -> Element> CellFormat<T, F> for C {
+    fn format(&self, row: &T, sort: CellSort<F>) -> Element {
+        self(row, sort)
+    }
+}
+
+impl<T, F: fmt::Debug> fmt::Debug for TableColumn<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableColumn")
+            .field("label", &self.label)
+            .field("field", &self.field)
+            .finish_non_exhaustive()
+    }
+}
+
+/// See [`SortableTable`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SortableTableProps<T: Clone + PartialEq + 'static, F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
+    /// Columns in display order.
+    columns: Vec<TableColumn<T, F>>,
+    /// Rows to render. Sorted with [`UseSorter::sort_indices`] so the data itself is left untouched.
+    data: Vec<T>,
+    /// Adds `data-index` (position in `data`) and `data-position` (sorted position) attributes to each row. Useful for driving CSS transitions alongside [`UseSorter::on_sorted`].
+    #[props(default)]
+    data_attributes: bool,
+    /// Optional column widths. Makes headers resizable. See [`Th`].
+    widths: Option<UseColumnWidths<F>>,
+    /// Keeps the header visible while scrolling. See [`StickyThead`].
+    #[props(default)]
+    sticky: bool,
+    /// Optional pinned columns. Frozen at the table's edges while scrolling sideways. See [`UsePinnedColumns`].
+    pinned: Option<UsePinnedColumns<F>>,
+    /// Optional column order. Lets users drag headers to reorder columns. See [`UseColumnOrder`].
+    order: Option<UseColumnOrder<F>>,
+    /// Adds a leading `#` column numbering rows in sorted order. See [`RowIndexCell`].
+    #[props(default)]
+    row_numbers: bool,
+    /// Optional classes for each row by sorted position e.g., odd and even stripes. See [`RowClasses`].
+    row_classes: Option<RowClasses>,
+}
+
+/// Convenience helper. Renders a whole `<table>` from a column spec: a [`Th`] per column and a row per item in sorted order.
+///
+/// Use this for the common case. Build the table yourself with [`Th`] if you need more control over the markup.
+///
+/// Rows are keyed by their position in `data` so re-sorting moves existing rows rather than re-rendering them.
+pub fn SortableTable<T, F>(props: SortableTableProps<T, F>) -> Element
+where
+    T: Clone + PartialEq + 'static,
+    F: Copy + PartialOrdBy<T> + Sortable + 'static,
+{
+    let sorter = props.sorter;
+    let data = &props.data;
+    let indices = sorter.sort_indices(data);
+    let total = indices.len();
+    // Reordered by the user, then pinned columns move to the edges and stick there
+    let fields = props
+        .columns
+        .iter()
+        .map(|column| column.field)
+        .collect::<Vec<_>>();
+    let fields = match props.order {
+        Some(order) => order.order(&fields),
+        None => fields,
+    };
+    let order = match props.pinned {
+        Some(pinned) => pinned.order(&fields),
+        None => fields,
+    };
+    let columns = order
+        .iter()
+        .filter_map(|field| props.columns.iter().find(|column| column.field == *field))
+        .map(|column| {
+            let style = props
+                .pinned
+                .map(|pinned| pinned.style(&column.field, &order, props.widths))
+                .unwrap_or_default();
+            (column, style, sorter.cell_sort(&column.field))
+        })
+        .collect::<Vec<_>>();
+    let headers = rsx! {
+        tr {
+            if props.row_numbers {
+                th { scope: "col", "#" }
+            }
+            for (column, style, _) in columns.iter() {
+                Th {
+                    sorter: sorter,
+                    field: column.field,
+                    style: style.clone(),
+                    hooks: ThHooks {
+                        widths: props.widths,
+                        order: props.order,
+                        ..Default::default()
+                    },
+                    "{column.label}"
+                }
+            }
+        }
+    };
+
+    rsx! {
+        table {
+            if props.sticky {
+                StickyThead { {headers} }
+            } else {
+                thead { {headers} }
+            }
+            tbody {
+                for (position, index) in indices.into_iter().enumerate() {
+                    tr {
+                        key: "{index}",
+                        class: props.row_classes.as_ref().map(|classes| classes.class(position, total)),
+                        "data-index": props.data_attributes.then(|| index.to_string()),
+                        "data-position": props.data_attributes.then(|| position.to_string()),
+                        if props.row_numbers {
+                            RowIndexCell { position: position }
+                        }
+                        for (column, style, sort) in columns.iter() {
+                            td {
+                                style: "{align_style(&column.field)} {style}",
+                                match &column.format {
+                                    Some(format) => format.format(&data[index], *sort),
+                                    None => (column.cell)(&data[index]),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// See [`StickyThead`].
+#[derive(Props, Clone, PartialEq)]
+pub struct StickyTheadProps {
+    /// Optional `class` attribute for the `<thead>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Distance from the top of the scroll container e.g., the height of a fixed navbar. Defaults to `0`.
+    #[props(into)]
+    top: Option<String>,
+    /// Background behind the headers so rows don't show through. Defaults to the page's (`Canvas`).
+    #[props(into)]
+    background: Option<String>,
+    children: Element,
+}
+
+/// Convenience helper. A `<thead>` that stays visible while scrolling a long table. Use in place of `thead` with [`Th`] headers as usual: sorting and resizing work the same.
+///
+/// Sticks to the nearest scrolling ancestor: the page, or a wrapper such as `div { style: "max-height: 20em; overflow: auto" }`. The table must not have `overflow` set itself.
+pub fn StickyThead(props: StickyTheadProps) -> Element {
+    let style = sticky_style(props.top.as_deref(), props.background.as_deref());
+    rsx! {
+        thead {
+            class: props.class.unwrap_or_default(),
+            style: style,
+            {props.children}
+        }
+    }
+}
+
+/// Keeps an element visible while scrolling, for [`StickyThead`] and [`SortSummary`]. Defaults to the top of the scroll container over the page's background.
+fn sticky_style(top: Option<&str>, background: Option<&str>) -> String {
+    format!(
+        "position: sticky; top: {}; z-index: 2; background: {};",
+        top.unwrap_or("0"),
+        background.unwrap_or("Canvas"),
+    )
+}
+
+/// Fields along with their labels, in display order. Taken by [`SortControl`], [`SortableList`] and [`ColumnPicker`].
+///
+/// Converts from a `Vec` of `(field, label)` pairs, or of fields alone using [`Sortable::label`]. Use [`Self::short`] for [`Sortable::short_label`] instead. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name, Age }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// #     fn label(&self) -> &str { match self { Self::Name => "Name", Self::Age => "Age" } }
+/// # }
+/// #[component]
+/// fn People() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     let columns = use_columns::<PersonField>();
+///     rsx! {
+///         SortControl { sorter: sorter, fields: vec![PersonField::Name, PersonField::Age] }
+///         ColumnPicker { columns: columns, fields: vec![(PersonField::Age, "Age in years")] }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldLabels<F>(Vec<(F, String)>);
+
+impl<F> FieldLabels<F> {
+    /// Labels fields with their [`Sortable::short_label`]. Useful in narrow layouts e.g., on mobile.
+    pub fn short(fields: impl IntoIterator<Item = F>) -> Self
+    where
+        F: Sortable,
+    {
+        let fields = fields.into_iter().map(|field| {
+            let label = field.short_label().to_string();
+            (field, label)
+        });
+        Self(fields.collect())
+    }
+
+    /// Returns each field and its label in display order.
+    pub fn iter(&self) -> impl Iterator<Item = (&F, &str)> {
+        self.0.iter().map(|(field, label)| (field, label.as_str()))
+    }
+}
+
+impl<F> Default for FieldLabels<F> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
 }
 
-/// Convenience helper. Renders an active or inactive gielement.
-fn ThSpan<'a>(cx: Scope<'a, ThSpan<'a>>) -> Element<'a> {
-    let colour = if cx.props.active { "#555" } else { "#ccc" };
-    let nbsp = "&nbsp;";
-    cx.render(rsx! {
+impl<F> From<Vec<(F, &str)>> for FieldLabels<F> {
+    fn from(fields: Vec<(F, &str)>) -> Self {
+        let fields = fields.into_iter().map(|(f, label)| (f, label.to_string()));
+        Self(fields.collect())
+    }
+}
+
+impl<F: Sortable> From<Vec<F>> for FieldLabels<F> {
+    fn from(fields: Vec<F>) -> Self {
+        let fields = fields.into_iter().map(|field| {
+            let label = field.label().to_string();
+            (field, label)
+        });
+        Self(fields.collect())
+    }
+}
+
+/// See [`SortControl`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SortControlProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
+    /// Fields offered in the dropdown along with their labels. In display order. See [`FieldLabels`].
+    #[props(into)]
+    fields: FieldLabels<F>,
+    /// Direction button text when sorted in ascending order, after the theme's glyph. Defaults to "Ascending".
+    #[props(into, default = "Ascending".to_string())]
+    ascending_label: String,
+    /// Direction button text when sorted in descending order, after the theme's glyph. Defaults to "Descending".
+    #[props(into, default = "Descending".to_string())]
+    descending_label: String,
+}
+
+/// Convenience helper. Renders a `<select>` of fields and a direction toggle button. Lets any component, not just tables, change the [`UseSorter`] state.
+///
+/// Choosing a field calls [`UseSorter::toggle_field`] so the field's initial direction is used. The direction button calls [`UseSorter::set_direction`], keeping any secondary sorts, and is disabled for fields that can only be sorted in one direction. Its glyph comes from the [`crate::SorterTheme`], using the outlined glyphs for those fields.
+pub fn SortControl<F: Copy + Sortable + 'static>(props: SortControlProps<F>) -> Element {
+    let theme = use_theme();
+    let sorter = props.sorter;
+    let (active_field, active_dir) = sorter.get_state();
+    let reversible = sortable_by(&active_field).is_some_and(|sort_by| sort_by.is_reversible());
+    let glyph = match (reversible, active_dir) {
+        (true, Direction::Ascending) => &theme.ascending,
+        (true, Direction::Descending) => &theme.descending,
+        (false, Direction::Ascending) => &theme.fixed_ascending,
+        (false, Direction::Descending) => &theme.fixed_descending,
+    };
+    let dir_label = match active_dir {
+        Direction::Ascending => format!("{glyph} {}", props.ascending_label),
+        Direction::Descending => format!("{glyph} {}", props.descending_label),
+    };
+    let fields = props.fields.clone();
+
+    rsx! {
         span {
-            style: "color: {colour};",
-            span { dangerous_inner_html: "{nbsp}", }
-            &cx.props.children
+            select {
+                onchange: move |evt| {
+                    let field = evt
+                        .value()
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| fields.0.get(index));
+                    if let Some((field, _)) = field {
+                        sorter.toggle_field(*field);
+                    }
+                },
+                for (index, (field, label)) in props.fields.iter().enumerate() {
+                    option {
+                        value: "{index}",
+                        selected: *field == active_field,
+                        disabled: sortable_by(field).is_none() || !sorter.is_enabled(field),
+                        "{label}"
+                    }
+                }
+            }
+            button {
+                disabled: !reversible,
+                onclick: move |_| sorter.set_direction(active_dir.invert()),
+                "{dir_label}"
+            }
+        }
+    }
+}
+
+/// See [`SortSummary`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SortSummaryProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
+    /// Optional `class` attribute for the `<div>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Keeps the bar visible while scrolling. Defaults to true.
+    #[props(default = true)]
+    sticky: bool,
+    /// Distance from the top of the scroll container when sticky e.g., the height of a fixed navbar. Defaults to `0`.
+    #[props(into)]
+    top: Option<String>,
+    /// Background behind the bar when sticky so rows don't show through. Defaults to the page's (`Canvas`).
+    #[props(into)]
+    background: Option<String>,
+    /// Text before the first field. Defaults to "Sorted by".
+    #[props(into, default = "Sorted by".to_string())]
+    prefix: String,
+    /// Text before each secondary sort. Defaults to "then".
+    #[props(into, default = "then".to_string())]
+    then_label: String,
+    /// Text shown while unsorted. Defaults to "Not sorted".
+    #[props(into, default = "Not sorted".to_string())]
+    unsorted_label: String,
+    /// Text of the clear button. Defaults to "Clear".
+    #[props(into, default = "Clear".to_string())]
+    clear_label: String,
+}
+
+/// Convenience helper. Renders a bar describing the current sort in plain language e.g., "Sorted by Left office, newest first" followed by a button to clear it. Secondary sorts follow e.g., ", then Name, ascending". Keeps users aware of the sort when the headers have scrolled away.
+///
+/// Names and directions come from [`Sortable::label`] and [`Sortable::direction_label`]. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// enum PersonField {
+///     #[default]
+///     Name,
+///     LeftOffice,
+/// }
+///
+/// impl Sortable for PersonField {
+///     fn sort_by(&self) -> Option<SortBy> {
+///         SortBy::increasing_or_decreasing()
+///     }
+///
+///     fn label(&self) -> &str {
+///         match self {
+///             Self::Name => "Name",
+///             Self::LeftOffice => "Left office",
+///         }
+///     }
+///
+///     fn direction_label(&self, dir: Direction) -> &str {
+///         match (self, dir) {
+///             (Self::LeftOffice, Direction::Ascending) => "oldest first",
+///             (Self::LeftOffice, Direction::Descending) => "newest first",
+///             (_, Direction::Ascending) => "A to Z",
+///             (_, Direction::Descending) => "Z to A",
+///         }
+///     }
+/// }
+///
+/// #[component]
+/// fn People() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     rsx! {
+///         SortSummary { sorter: sorter }
+///         // Table using `Th` as usual
+///     }
+/// }
+/// ```
+///
+/// The button calls [`UseSorter::clear`] and is only shown while sorted. The text has `role="status"` so screen readers announce changes.
+pub fn SortSummary<F: Copy + Sortable + 'static>(props: SortSummaryProps<F>) -> Element {
+    let sorter = props.sorter;
+    let keys = sorter.sort_keys();
+    let summary = describe_sort(&keys, &props.then_label);
+    let summary = match keys.is_empty() {
+        true => props.unsorted_label,
+        false => format!("{} {summary}", props.prefix),
+    };
+    let style = props
+        .sticky
+        .then(|| sticky_style(props.top.as_deref(), props.background.as_deref()));
+    rsx! {
+        div {
+            class: props.class.unwrap_or_default(),
+            style: style,
+            span { class: "sort-summary", role: "status", "{summary}" }
+            if !keys.is_empty() {
+                button {
+                    class: "sort-summary-clear",
+                    onclick: move |_| sorter.clear(),
+                    "{props.clear_label}"
+                }
+            }
         }
-    })
+    }
+}
+
+/// See [`NullsToggle`].
+#[derive(Props, Clone, PartialEq)]
+pub struct NullsToggleProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
+    field: F,
+    /// Optional `class` attribute for the `<button>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Text shown while `NULL` values come first. Defaults to "Nulls first".
+    #[props(into, default = "Nulls first".to_string())]
+    first_label: String,
+    /// Text shown while `NULL` values come last. Defaults to "Nulls last".
+    #[props(into, default = "Nulls last".to_string())]
+    last_label: String,
+    /// Text shown while `NULL` values are removed. Defaults to "Nulls hidden".
+    #[props(into, default = "Nulls hidden".to_string())]
+    removed_label: String,
+}
+
+/// Convenience helper. Renders a `<button>` flipping whether a field's `NULL` values come first or last using [`UseSorter::set_null_handling`]. Shows where they are placed in the field's current direction.
+///
+/// Fields ordering `NULL`s by [`NullHandling::SqlDefault`] return to it with [`UseSorter::reset_null_handling`] rather than being pinned first or last. The button is disabled while `NULL`s are removed by [`NullHandling::Remove`] as they have no place to flip.
+///
+/// Has `aria-pressed` set while `NULL` values come first.
+pub fn NullsToggle<F: Copy + Sortable + 'static>(props: NullsToggleProps<F>) -> Element {
+    let sorter = props.sorter;
+    let field = props.field;
+    let direction = sorter
+        .sort_keys()
+        .into_iter()
+        .find(|(f, _)| *f == field)
+        .map_or_else(
+            || field.sort_by().unwrap_or_default().direction(),
+            |(_, dir)| dir,
+        );
+    let current = sorter.null_handling(&field);
+    let removed = current == NullHandling::Remove;
+    let nulls_first = !removed && current.nulls_first(direction);
+    let label = match (removed, nulls_first) {
+        (true, _) => props.removed_label,
+        (false, true) => props.first_label,
+        (false, false) => props.last_label,
+    };
+    let next = next_nulls(current, field.null_handling(), direction);
+    rsx! {
+        button {
+            class: props.class.unwrap_or_default(),
+            disabled: removed,
+            aria_pressed: "{nulls_first}",
+            onclick: move |_| match next {
+                Some(nulls) => sorter.set_null_handling(field, nulls),
+                None => sorter.reset_null_handling(&field),
+            },
+            "{label}"
+        }
+    }
+}
+
+/// Returns the [`NullsToggle`] state after `current`, given the field's own handling: the other end, or `None` to reset to [`NullHandling::SqlDefault`] when that puts `NULL`s there.
+fn next_nulls(
+    current: NullHandling,
+    own: NullHandling,
+    direction: Direction,
+) -> Option<NullHandling> {
+    let nulls_first = current.nulls_first(direction);
+    if own == NullHandling::SqlDefault
+        && current != own
+        && own.nulls_first(direction) != nulls_first
+    {
+        return None;
+    }
+    match nulls_first {
+        true => Some(NullHandling::Last),
+        false => Some(NullHandling::First),
+    }
+}
+
+/// See [`SortableList`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SortableListProps<F: Copy + Sortable + 'static> {
+    sorter: UseSorter<F>,
+    /// Fields offered by the [`SortControl`] along with their labels. See [`FieldLabels`].
+    #[props(into)]
+    fields: FieldLabels<F>,
+    children: Element,
+}
+
+/// Convenience helper. Renders a [`SortControl`] above a `<ul>` of children. For sortable lists and cards that don't use `<table>` markup.
+///
+/// Like [`Th`], sorting state is kept separately from the data. Sort your data with [`UseSorter::sort`] and render each item as an `li` child.
+pub fn SortableList<F: Copy + Sortable + 'static>(props: SortableListProps<F>) -> Element {
+    rsx! {
+        div {
+            SortControl {
+                sorter: props.sorter,
+                fields: props.fields,
+            }
+            ul {
+                {props.children}
+            }
+        }
+    }
+}
+
+/// See [`ColumnPicker`].
+#[derive(Props, Clone, PartialEq)]
+pub struct ColumnPickerProps<F: Copy + PartialEq + 'static> {
+    columns: UseColumns<F>,
+    /// Fields that may be shown or hidden along with their labels. In display order. See [`FieldLabels`].
+    #[props(into)]
+    fields: FieldLabels<F>,
+}
+
+/// Convenience helper. Renders a checkbox per field to show or hide its column using [`UseColumns`].
+pub fn ColumnPicker<F: Copy + PartialEq + 'static>(props: ColumnPickerProps<F>) -> Element {
+    let columns = props.columns;
+    rsx! {
+        fieldset {
+            for (&field, label) in props.fields.iter() {
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: columns.is_visible(&field),
+                        onchange: move |_| columns.toggle(field),
+                    }
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+/// See [`ColumnFilter`].
+#[derive(Props, Clone, PartialEq)]
+pub struct ColumnFilterProps<T: Clone + PartialEq + 'static, F: Copy + FilterBy<T> + 'static> {
+    filter: UseFilter<T, F>,
+    field: F,
+    /// Optional values to choose from. Renders a `<select>` rather than a text box.
+    options: Option<Vec<String>>,
+    /// Optional `class` attribute for the `<input>` or `<select>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional placeholder text of the text box.
+    #[props(into)]
+    placeholder: Option<String>,
+    /// Optional accessible name e.g., "Filter by name". Set as the `aria-label` attribute.
+    #[props(into)]
+    label: Option<String>,
+    /// Text of the `<select>` option that removes the filter. Defaults to "All".
+    #[props(into, default = "All".to_string())]
+    all_label: String,
+}
+
+/// Convenience helper. Renders a text box, or a `<select>` given `options`, that sets a field's filter with [`UseFilter::set_field_filter`]. Clearing it removes the filter. What matches is up to the field's [`FilterBy`].
+///
+/// Pass one as a [`Th`]'s `filter` to render it in the header, or put them in a second header row for a classic filter row. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Person { name: String, country: String }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name, Country }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// # impl FilterBy<Person> for PersonField {
+/// #     fn filter_by(&self, item: &Person, value: &str) -> bool { true }
+/// # }
+/// #[component]
+/// fn Header() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     let filter = use_filter::<Person, PersonField>(&[]);
+///     rsx! {
+///         tr {
+///             Th {
+///                 sorter: sorter,
+///                 field: PersonField::Name,
+///                 filter: rsx! {
+///                     ColumnFilter { filter: filter, field: PersonField::Name, label: "Filter by name" }
+///                 },
+///                 "Name"
+///             }
+///             Th {
+///                 sorter: sorter,
+///                 field: PersonField::Country,
+///                 filter: rsx! {
+///                     ColumnFilter {
+///                         filter: filter,
+///                         field: PersonField::Country,
+///                         options: vec!["France".to_string(), "Japan".to_string()],
+///                     }
+///                 },
+///                 "Country"
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub fn ColumnFilter<T: Clone + PartialEq + 'static, F: Copy + FilterBy<T> + 'static>(
+    props: ColumnFilterProps<T, F>,
+) -> Element {
+    let filter = props.filter;
+    let field = props.field;
+    let value = filter.field_filter(&field);
+    match props.options {
+        Some(options) => rsx! {
+            select {
+                class: props.class,
+                "aria-label": props.label,
+                onchange: move |evt| filter.set_field_filter(field, evt.value()),
+                option { value: "", selected: value.is_empty(), "{props.all_label}" }
+                for option in options {
+                    option {
+                        selected: option == value,
+                        value: "{option}",
+                        "{option}"
+                    }
+                }
+            }
+        },
+        None => rsx! {
+            input {
+                r#type: "search",
+                class: props.class,
+                placeholder: props.placeholder,
+                "aria-label": props.label,
+                value: value,
+                oninput: move |evt| filter.set_field_filter(field, evt.value()),
+            }
+        },
+    }
+}
+
+/// Returns a `style` declaration aligning a field's header or cells by [`Sortable::align`], if any.
+fn align_style<F: Sortable>(field: &F) -> &'static str {
+    field.align().map_or("", |align| align.style())
+}
+
+/// See [`Td`].
+#[derive(Props, Clone, PartialEq)]
+pub struct TdProps<F: Copy + Sortable + 'static> {
+    field: F,
+    /// Optional `class` attribute for the `<td>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional `style` attribute for the `<td>`. Takes precedence over the field's alignment.
+    #[props(into)]
+    style: Option<String>,
+    children: Element,
+}
+
+/// Convenience helper. Renders a `<td>` aligned by its field's [`Sortable::align`] so cells line up with their [`Th`] without per-table CSS.
+pub fn Td<F: Copy + Sortable + 'static>(props: TdProps<F>) -> Element {
+    let style = format!(
+        "{} {}",
+        align_style(&props.field),
+        props.style.as_deref().unwrap_or_default()
+    );
+    let style = style.trim();
+    rsx! {
+        td {
+            class: props.class,
+            style: (!style.is_empty()).then_some(style),
+            {props.children}
+        }
+    }
+}
+
+/// See [`NullableCell`].
+#[derive(Props, Clone, PartialEq)]
+pub struct NullableCellProps<V: fmt::Display + Clone + PartialEq + 'static> {
+    /// Value to show or `None` if `NULL`.
+    #[props(!optional)]
+    value: Option<V>,
+    /// Optionally marks the cell `NULL` in place of checking `value` e.g., from [`UseSorter::is_null`] so cells shown as unknown match how rows sort.
+    null: Option<bool>,
+    /// Optional placeholder for `NULL`. Replaces the theme's [`crate::SorterTheme::null_text`].
+    placeholder: Option<Element>,
+    /// Optional `class` attribute for the `<td>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional `style` attribute for the `<td>`.
+    #[props(into)]
+    style: Option<String>,
+}
+
+/// Convenience helper. Renders a `<td>` with a value or, if it's `NULL`, a placeholder: "Unknown" in an `<em>` by default. Set the placeholder app-wide with [`crate::SorterTheme::null_text`] so every table shows `NULL`s the same way. See [`render_nullable`] to render just the contents.
+pub fn NullableCell<V: fmt::Display + Clone + PartialEq + 'static>(
+    props: NullableCellProps<V>,
+) -> Element {
+    let value = props.value.filter(|_| props.null != Some(true));
+    rsx! {
+        td { class: props.class, style: props.style, {render_nullable(value, props.placeholder)} }
+    }
+}
+
+/// Renders a value or, if `None`, a placeholder for `NULL`. Without a `placeholder`, renders the [`crate::SorterTheme::null_text`] of any theme in context in an `<em>`. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::render_nullable;
+/// fn moons(moons: Option<u32>) -> Element {
+///     rsx! {
+///         td { {render_nullable(moons, None)} }
+///         td { {render_nullable(moons, Some(rsx!("–")))} }
+///     }
+/// }
+/// ```
+///
+/// Not a hook so may be called conditionally e.g., per cell.
+pub fn render_nullable<V: fmt::Display>(value: Option<V>, placeholder: Option<Element>) -> Element {
+    match (value, placeholder) {
+        (Some(value), _) => rsx!("{value}"),
+        (None, Some(placeholder)) => placeholder,
+        (None, None) => {
+            let theme = try_consume_context::<crate::SorterTheme>().unwrap_or_default();
+            let class = (!theme.null_class.is_empty()).then_some(theme.null_class);
+            rsx! {
+                em { class: class, "{theme.null_text}" }
+            }
+        }
+    }
+}
+
+/// See [`TreeCell`].
+#[derive(Props, Clone, PartialEq)]
+pub struct TreeCellProps {
+    /// Nesting depth: 0 for parent rows, 1 for their children.
+    #[props(default)]
+    depth: usize,
+    /// Optional indent per level as a CSS length. Defaults to "1.5em".
+    #[props(into)]
+    indent: Option<String>,
+    /// Optionally shows an expand / collapse button, pointing down if true. Omit for rows without children.
+    expanded: Option<bool>,
+    /// Optional handler for the expand / collapse button.
+    ontoggle: Option<EventHandler<MouseEvent>>,
+    /// Optional `class` attribute for the `<td>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional `style` attribute for the `<td>`.
+    #[props(into)]
+    style: Option<String>,
+    children: Element,
+}
+
+/// Convenience helper. Renders the first `<td>` of a [`crate::TreeRow`]'s parent or child row, indented by depth so children sit beneath their parent. See [`UseSorter::sort_tree`].
+///
+/// Given `expanded`, starts with a button toggling the children's visibility. Track which parents are expanded yourself e.g., in a `Signal<HashSet<K>>`, and skip rendering the children of collapsed ones.
+pub fn TreeCell(props: TreeCellProps) -> Element {
+    let indent = props.indent.as_deref().unwrap_or("1.5em");
+    let style = format!(
+        "padding-inline-start: calc({} * {indent}); {}",
+        props.depth,
+        props.style.as_deref().unwrap_or_default()
+    );
+    rsx! {
+        td { class: props.class, style: style.trim(),
+            if let Some(expanded) = props.expanded {
+                button {
+                    r#type: "button",
+                    class: "tree-toggle",
+                    aria_expanded: "{expanded}",
+                    onclick: move |evt| {
+                        if let Some(ontoggle) = props.ontoggle {
+                            ontoggle.call(evt);
+                        }
+                    },
+                    if expanded { "▾" } else { "▸" }
+                }
+            }
+            {props.children}
+        }
+    }
+}
+
+/// See [`SelectCell`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SelectCellProps<T: Clone + PartialEq + 'static, K: Clone + PartialEq + 'static> {
+    selection: UseSelection<T, K>,
+    row: T,
+}
+
+/// Convenience helper. Renders a `<td>` with a checkbox (or radio button in [`SelectionMode::Single`]) that selects the row using [`UseSelection`].
+pub fn SelectCell<T, K>(props: SelectCellProps<T, K>) -> Element
+where
+    T: Clone + PartialEq + 'static,
+    K: Clone + PartialEq + 'static,
+{
+    let selection = props.selection;
+    let row = props.row;
+    let input_type = match selection.mode() {
+        SelectionMode::Single => "radio",
+        SelectionMode::Multiple => "checkbox",
+    };
+    let checked = selection.is_selected(&row);
+    rsx! {
+        td {
+            input {
+                r#type: input_type,
+                checked: checked,
+                // Radios can't be unchecked by clicking so toggle instead
+                onclick: move |_| selection.toggle(&row),
+            }
+        }
+    }
+}
+
+/// See [`SelectionTh`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SelectionThProps<T: Clone + PartialEq + 'static, K: Clone + PartialEq + 'static> {
+    sort: SortBySelection<T, K>,
+    /// Rows the checkbox selects e.g., all rows passing a filter.
+    rows: Vec<T>,
+    /// Optional `class` attribute for the `<th>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Optional screen reader label for the checkbox.
+    #[props(into, default = "Select all".to_string())]
+    label: String,
+}
+
+/// Convenience helper. Builds a `<th>` for a column of [`SelectCell`]s. Clicking the header toggles [`SortBySelection`] like a [`Th`] toggles its field. Holds a three-state checkbox that selects all rows or none.
+///
+/// The checkbox is checked if every row is selected and mixed if only some are, shown with an `indeterminate` class and `data-state="mixed"`. With the `web` feature the checkbox is also set indeterminate.
+///
+/// Adds the active or inactive class from any [`crate::SorterTheme`] in context.
+pub fn SelectionTh<T, K>(props: SelectionThProps<T, K>) -> Element
+where
+    T: Clone + PartialEq + 'static,
+    K: Clone + PartialEq + 'static,
+{
+    let theme = use_theme();
+    #[cfg(feature = "web")]
+    let mut mounted = use_signal(|| None::<Rc<MountedData>>);
+    let sort = props.sort;
+    let selection = sort.selection();
+    let rows = props.rows;
+    let selected = rows.iter().filter(|row| selection.is_selected(row)).count();
+    let all = selected > 0 && selected == rows.len();
+    let mixed = selected > 0 && !all;
+    #[cfg(feature = "web")]
+    use_effect(use_reactive!(|mixed| {
+        let mounted = mounted.read();
+        let element = mounted
+            .as_ref()
+            .and_then(|mounted| mounted.downcast::<web_sys::Element>());
+        if let Some(element) = element {
+            let _ = js_sys::Reflect::set(element, &"indeterminate".into(), &mixed.into());
+        }
+    }));
+    let state = match (all, mixed) {
+        (true, _) => "checked",
+        (_, true) => "mixed",
+        _ => "unchecked",
+    };
+    let active = sort.is_enabled();
+    let aria_sort = if active { "other" } else { "none" };
+    let class = [
+        props.class.as_deref().unwrap_or_default(),
+        theme.class(active),
+    ]
+    .into_iter()
+    .filter(|class| !class.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ");
+    rsx! {
+        th {
+            class: class,
+            role: "columnheader",
+            "aria-sort": aria_sort,
+            tabindex: "0",
+            onclick: move |_| sort.toggle(),
+            onkeydown: move |evt| {
+                if activates(&evt.key()) {
+                    evt.prevent_default();
+                    sort.toggle();
+                }
+            },
+            input {
+                r#type: "checkbox",
+                class: if mixed { "indeterminate" },
+                "data-state": state,
+                "aria-label": props.label,
+                checked: all,
+                onmounted: move |evt| {
+                    #[cfg(feature = "web")]
+                    mounted.set(Some(evt.data()));
+                    #[cfg(not(feature = "web"))]
+                    let _ = evt;
+                },
+                onclick: move |evt| {
+                    evt.stop_propagation();
+                    if all {
+                        selection.clear();
+                    } else {
+                        selection.select_all(rows.iter());
+                    }
+                },
+                // Space checks the box rather than toggling the sort
+                onkeydown: move |evt| evt.stop_propagation(),
+            }
+        }
+    }
+}
+
+/// See [`SelectableRow`].
+#[derive(Props, Clone, PartialEq)]
+pub struct SelectableRowProps<T: Clone + PartialEq + 'static, K: Clone + PartialEq + 'static> {
+    selection: UseSelection<T, K>,
+    row: T,
+    children: Element,
+}
+
+/// Convenience helper. Renders a `<tr>` starting with a [`SelectCell`] followed by children. Selected rows have the `selected` class and `aria-selected` attribute.
+pub fn SelectableRow<T, K>(props: SelectableRowProps<T, K>) -> Element
+where
+    T: Clone + PartialEq + 'static,
+    K: Clone + PartialEq + 'static,
+{
+    let selection = props.selection;
+    let selected = selection.is_selected(&props.row);
+    rsx! {
+        tr {
+            class: if selected { "selected" } else { "" },
+            "aria-selected": "{selected}",
+            SelectCell {
+                selection: selection,
+                row: props.row,
+            }
+            {props.children}
+        }
+    }
+}
+
+/// See [`RowIndexCell`].
+#[derive(Props, Clone, PartialEq)]
+pub struct RowIndexCellProps {
+    /// Zero-based position of the row as rendered e.g., from `enumerate()` over sorted rows.
+    position: usize,
+    /// Number of rows before those rendered e.g., on earlier pages. Get it from [`UsePaginator::range`] or [`crate::TableView::offset`].
+    #[props(default)]
+    offset: usize,
+    /// Optional zero-based index of the row in the original data. Shown in place of the display position so numbers stay with their rows when re-sorted.
+    original: Option<usize>,
+    /// Optional `class` attribute for the `<td>`.
+    #[props(into)]
+    class: Option<String>,
+}
+
+/// Convenience helper. Renders a `<td>` with the row's 1-based number in display order i.e., after sorting and paging. Numbering continues across pages given an `offset`.
+///
+/// Given an `original` index, numbers the row by its place in the original data instead.
+pub fn RowIndexCell(props: RowIndexCellProps) -> Element {
+    let number = props
+        .original
+        .unwrap_or(props.offset + props.position)
+        .saturating_add(1);
+    rsx! {
+        td { class: props.class, "{number}" }
+    }
+}
+
+/// See [`TFoot`].
+#[derive(Props, Clone, PartialEq)]
+pub struct TFootProps<T: Clone + PartialEq + 'static> {
+    /// A footer per column in display order.
+    footers: Vec<Footer<T>>,
+    /// Rows to aggregate in view order e.g., from [`crate::TableView::filtered`] or after [`UseSorter::sort`].
+    rows: Vec<T>,
+    /// Optional `class` attribute for the `<tfoot>`.
+    #[props(into)]
+    class: Option<String>,
+}
+
+/// Convenience helper. Renders a `<tfoot>` with a cell per column computed by its [`Footer`] e.g., totals. See [`crate::aggregates`].
+///
+/// Pass the same rows the table shows so footers match the view.
+pub fn TFoot<T: Clone + PartialEq + 'static>(props: TFootProps<T>) -> Element {
+    let rows = props.rows.iter().collect::<Vec<_>>();
+    rsx! {
+        tfoot { class: props.class,
+            tr {
+                for footer in props.footers.iter() {
+                    td { {footer.render(&rows)} }
+                }
+            }
+        }
+    }
+}
+
+/// See [`Paginator`].
+#[derive(Props, Clone, PartialEq)]
+pub struct PaginatorProps {
+    paginator: UsePaginator,
+    /// Total number of items being paged. Usually the length of the sorted data.
+    total: usize,
+    /// Page sizes offered to the user. Hidden if empty.
+    #[props(default = vec![10, 25, 50, 100])]
+    page_sizes: Vec<usize>,
+}
+
+/// Convenience helper. Renders first / previous / next / last buttons, a page number input to jump to a page and a page size selector for a [`UsePaginator`].
+pub fn Paginator(props: PaginatorProps) -> Element {
+    let paginator = props.paginator;
+    let total = props.total;
+    let page = paginator.page(total);
+    let count = paginator.page_count(total);
+    let page_size = paginator.page_size();
+    let is_first = page == 0;
+    let is_last = page + 1 == count;
+
+    rsx! {
+        nav {
+            button {
+                disabled: is_first,
+                onclick: move |_| paginator.set_page(0, total),
+                "«"
+            }
+            button {
+                disabled: is_first,
+                onclick: move |_| paginator.previous(total),
+                "‹"
+            }
+            span {
+                " Page "
+                input {
+                    r#type: "number",
+                    min: "1",
+                    max: "{count}",
+                    value: "{page + 1}",
+                    onchange: move |evt| {
+                        if let Ok(page) = evt.value().parse::<usize>() {
+                            paginator.set_page(page.saturating_sub(1), total);
+                        }
+                    },
+                }
+                " of {count} "
+            }
+            button {
+                disabled: is_last,
+                onclick: move |_| paginator.next(total),
+                "›"
+            }
+            button {
+                disabled: is_last,
+                onclick: move |_| paginator.set_page(count - 1, total),
+                "»"
+            }
+            if !props.page_sizes.is_empty() {
+                select {
+                    onchange: move |evt| {
+                        if let Ok(size) = evt.value().parse::<usize>() {
+                            paginator.set_page_size(size);
+                        }
+                    },
+                    for size in props.page_sizes {
+                        option {
+                            value: "{size}",
+                            selected: size == page_size,
+                            "{size} per page"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// See [`VirtualScroll`].
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualScrollProps {
+    virtual_rows: UseVirtualRows,
+    /// Unique element ID. Used to read the scroll position.
+    #[props(into)]
+    id: String,
+    children: Element,
+}
+
+/// Convenience helper. Renders a scrolling `<div>` the height of the viewport given to [`crate::use_virtual_rows()`]. Wrap a `<table>` using [`VirtualTableBody`] with it.
+///
+/// Tracks the scroll position when the `web` feature is enabled. Otherwise call [`UseVirtualRows::set_scroll_top`] yourself.
+pub fn VirtualScroll(props: VirtualScrollProps) -> Element {
+    let virtual_rows = props.virtual_rows;
+    let id = props.id.clone();
+    let height = virtual_rows.viewport_height();
+    rsx! {
+        div {
+            id: "{props.id}",
+            style: "height: {height}px; overflow-y: auto;",
+            onscroll: move |_| {
+                #[cfg(feature = "web")]
+                {
+                    let element = web_sys::window()
+                        .and_then(|window| window.document())
+                        .and_then(|document| document.get_element_by_id(&id));
+                    if let Some(element) = element {
+                        virtual_rows.set_scroll_top(element.scroll_top() as f64);
+                    }
+                }
+                #[cfg(not(feature = "web"))]
+                let _ = (&id, virtual_rows);
+            },
+            {props.children}
+        }
+    }
+}
+
+/// See [`VirtualTableBody`].
+#[derive(Props, Clone, PartialEq)]
+pub struct VirtualTableBodyProps<T: Clone + PartialEq + 'static> {
+    virtual_rows: UseVirtualRows,
+    /// All rows, already sorted e.g., with [`UseSorter::sort`].
+    rows: Vec<T>,
+    /// Renders a `<tr>` for a row. Each row must be the height given to [`crate::use_virtual_rows()`].
+    row: Callback<T, Element>,
+}
+
+/// Convenience helper. Renders a `<tbody>` containing only the rows visible in a [`VirtualScroll`] viewport. Spacer rows above and below keep the scroll height correct.
+///
+/// Rows are picked by position so re-sorting shows the newly sorted rows at the current scroll position.
+pub fn VirtualTableBody<T: Clone + PartialEq + 'static>(
+    props: VirtualTableBodyProps<T>,
+) -> Element {
+    let virtual_rows = props.virtual_rows;
+    let rows = &props.rows;
+    let window = virtual_rows.window(rows.len());
+    let row_height = virtual_rows.row_height();
+    let top = window.start as f64 * row_height;
+    let bottom = (rows.len() - window.end) as f64 * row_height;
+
+    rsx! {
+        tbody {
+            tr { style: "height: {top}px;" }
+            for row in rows[window].iter() {
+                {props.row.call(row.clone())}
+            }
+            tr { style: "height: {bottom}px;" }
+        }
+    }
+}
+
+/// See [`InfiniteScrollTrigger`].
+#[derive(Props, Clone, PartialEq)]
+pub struct InfiniteScrollTriggerProps {
+    scroll: UseInfiniteScroll,
+    /// Total number of items. Usually the length of the sorted data.
+    total: usize,
+    /// Optional `class` attribute for the `<div>`.
+    #[props(into)]
+    class: Option<String>,
+    /// Text of the button that loads more. Defaults to "Load more".
+    #[props(into, default = "Load more".to_string())]
+    label: String,
+}
+
+/// Convenience helper. Renders a `<div>` with a button loading the next chunk of a [`UseInfiniteScroll`]. Place it after the items. Renders nothing once everything is loaded.
+///
+/// With the `web` feature, also loads more when the `<div>` scrolls within 200px of view using an `IntersectionObserver`. The button stays for keyboard users and other renderers.
+pub fn InfiniteScrollTrigger(props: InfiniteScrollTriggerProps) -> Element {
+    let scroll = props.scroll;
+    let total = props.total;
+    #[cfg(feature = "web")]
+    let observer = use_intersection(move || scroll.load_more(total));
+    #[cfg(feature = "web")]
+    {
+        // Observing again reports the current intersection so loading continues while still in view
+        let loaded = scroll.loaded(total);
+        use_effect(use_reactive!(|loaded| {
+            let _ = loaded;
+            observer.reobserve();
+        }));
+    }
+    if !scroll.has_more(total) {
+        return rsx! {};
+    }
+    rsx! {
+        div {
+            class: props.class,
+            onmounted: move |evt| {
+                #[cfg(feature = "web")]
+                observer.observe(evt.data());
+                #[cfg(not(feature = "web"))]
+                let _ = evt;
+            },
+            button { r#type: "button", onclick: move |_| scroll.load_more(total), "{props.label}" }
+        }
+    }
+}
+
+/// Calls back when an element scrolls near view. See [`use_intersection`].
+#[cfg(feature = "web")]
+#[derive(Copy, Clone)]
+struct UseIntersection {
+    callback: CopyValue<Option<Rc<dyn Fn()>>>,
+    observed: CopyValue<Option<IntersectionState>>,
+}
+
+#[cfg(feature = "web")]
+struct IntersectionState {
+    observer: web_sys::IntersectionObserver,
+    element: web_sys::Element,
+    _closure: wasm_bindgen::closure::Closure<dyn FnMut(js_sys::Array)>,
+}
+
+/// Creates hooks to call `on_visible` while an observed element is within 200px of view. Disconnects on drop.
+#[cfg(feature = "web")]
+fn use_intersection(on_visible: impl Fn() + 'static) -> UseIntersection {
+    let mut callback = use_hook(|| CopyValue::new(None));
+    callback.set(Some(Rc::new(on_visible) as Rc<dyn Fn()>));
+    let mut observed = use_hook(|| CopyValue::new(None::<IntersectionState>));
+    use_drop(move || {
+        if let Some(state) = observed.take() {
+            state.observer.disconnect();
+        }
+    });
+    UseIntersection { callback, observed }
+}
+
+#[cfg(feature = "web")]
+impl UseIntersection {
+    /// Starts observing a mounted element, replacing any earlier one.
+    fn observe(&self, mounted: Rc<MountedData>) {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let Some(element) = mounted.downcast::<web_sys::Element>().cloned() else {
+            return;
+        };
+        let callback = self.callback;
+        let closure = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            let visible = entries.iter().any(|entry| {
+                entry
+                    .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                    .is_intersecting()
+            });
+            // Cloned out so the callback may render
+            let callback = callback.peek().clone();
+            if let (true, Some(callback)) = (visible, callback) {
+                callback();
+            }
+        });
+        let options = web_sys::IntersectionObserverInit::new();
+        options.set_root_margin("200px");
+        let Ok(observer) = web_sys::IntersectionObserver::new_with_options(
+            closure.as_ref().unchecked_ref(),
+            &options,
+        ) else {
+            return;
+        };
+        observer.observe(&element);
+        let mut observed = self.observed;
+        if let Some(previous) = observed.replace(Some(IntersectionState {
+            observer,
+            element,
+            _closure: closure,
+        })) {
+            previous.observer.disconnect();
+        }
+    }
+
+    /// Observes the element again so the observer reports whether it's still in view.
+    fn reobserve(&self) {
+        if let Some(state) = &*self.observed.peek() {
+            state.observer.unobserve(&state.element);
+            state.observer.observe(&state.element);
+        }
+    }
+}
+
+/// See [`SorterDebugPanel`].
+#[cfg(feature = "debug")]
+#[derive(Props, Clone, PartialEq)]
+pub struct SorterDebugPanelProps<F: Copy + PartialEq + fmt::Debug + 'static> {
+    sorter: UseSorter<F>,
+    /// Optional `class` attribute for the `<details>`.
+    #[props(into)]
+    class: Option<String>,
+}
+
+/// Convenience helper. Renders a collapsible `<details>` showing a sorter's [`UseSorter::stats`]: the last and slowest sorts, the mean time and recent state transitions. Requires the `debug` feature.
+///
+/// Stats aren't reactive so the panel reads them after each render of the sorter's state and on "Refresh", which catches sorts of changed data.
+#[cfg(feature = "debug")]
+pub fn SorterDebugPanel<F: Copy + PartialEq + fmt::Debug + 'static>(
+    props: SorterDebugPanelProps<F>,
+) -> Element {
+    use crate::debug::SortTiming;
+
+    let sorter = props.sorter;
+    let mut shown = use_signal(|| sorter.stats());
+    // Subscribes to the sort state. Effects run once rendering is done so this sees sorts from the same render
+    use_effect(move || {
+        sorter.sort_keys();
+        shown.set(sorter.stats());
+    });
+    let stats = shown.cloned();
+    let timing = |timing: Option<SortTiming>| match timing {
+        Some(t) => format!(
+            "{} items, {} comparisons, {:.2} ms",
+            t.items, t.comparisons, t.millis
+        ),
+        None => "-".to_string(),
+    };
+    let last = timing(stats.last);
+    let slowest = timing(stats.slowest);
+    let mean = stats
+        .mean_millis()
+        .map_or_else(|| "-".to_string(), |mean| format!("{mean:.2} ms"));
+    let state = |state: &crate::SorterState<F>| match state.sorted {
+        true => format!("{:?} {:?}", state.field, state.direction),
+        false => format!("{:?} unsorted", state.field),
+    };
+    rsx! {
+        details { class: props.class,
+            summary { "Sort debug" }
+            dl {
+                dt { "Sorts" }
+                dd { "{stats.sorts}" }
+                dt { "Last" }
+                dd { "{last}" }
+                dt { "Slowest" }
+                dd { "{slowest}" }
+                dt { "Mean" }
+                dd { "{mean}" }
+            }
+            ol {
+                for (from , to) in stats.transitions.iter() {
+                    li { "{state(from)} → {state(to)}" }
+                }
+            }
+            button {
+                r#type: "button",
+                onclick: move |_| shown.set(sorter.stats()),
+                "Refresh"
+            }
+            button {
+                r#type: "button",
+                onclick: move |_| {
+                    sorter.reset_stats();
+                    shown.set(sorter.stats());
+                },
+                "Reset"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_nulls() {
+        use Direction::*;
+        use NullHandling::*;
+        // Flips between first and last
+        assert_eq!(next_nulls(Last, Last, Ascending), Some(First));
+        assert_eq!(next_nulls(First, Last, Descending), Some(Last));
+        // SQL ordering flips to the other end then back to SQL ordering
+        assert_eq!(next_nulls(SqlDefault, SqlDefault, Ascending), Some(First));
+        assert_eq!(next_nulls(First, SqlDefault, Ascending), None);
+        assert_eq!(next_nulls(SqlDefault, SqlDefault, Descending), Some(Last));
+        assert_eq!(next_nulls(Last, SqlDefault, Descending), None);
+        // Already at SQL ordering's end so pin the other
+        assert_eq!(next_nulls(Last, SqlDefault, Ascending), Some(First));
+        // Removed by default: overrides flip without returning to removal
+        assert_eq!(next_nulls(First, Remove, Ascending), Some(Last));
+    }
+
+    #[test]
+    fn test_activates() {
+        assert!(activates(&Key::Enter));
+        assert!(activates(&Key::Character(" ".to_string())));
+        assert!(!activates(&Key::Character("a".to_string())));
+        assert!(!activates(&Key::Tab));
+    }
 }
@@ -0,0 +1,1034 @@
+use std::{cmp::Ordering, fmt, rc::Rc};
+
+/// Trait used by [UseSorter](crate::UseSorter) to sort a struct by a specific field. This must be implemented on the field enum. Type `T` represents the struct (table row) that is being sorted.
+///
+/// The implementation should use the [`PartialOrd::partial_cmp`] trait to compare the field values and return the result. For example:
+/// ```rust
+/// # use dioxus_sortable::PartialOrdBy;
+/// # #[derive(PartialEq)]
+/// struct MyStruct {
+///     first: String,
+///     second: f64, // <- Note: can return None if f64::NAN
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum MyStructField {
+///     First,
+///     Second,
+/// }
+///
+/// impl PartialOrdBy<MyStruct> for MyStructField {
+///     fn partial_cmp_by(&self, a: &MyStruct, b: &MyStruct) -> Option<std::cmp::Ordering> {
+///         match self {
+///             MyStructField::First => a.first.partial_cmp(&b.first),
+///             MyStructField::Second => a.second.partial_cmp(&b.second),
+///         }
+///     }
+/// }
+/// ```
+///
+/// Be careful when using [`Option::None`] or a custom enum to represent missing data (`NULL` values). As `partial_cmp` as `None` is less than `Some`:
+///
+/// ```rust
+/// # use std::cmp::Ordering;
+/// assert_eq!(Ordering::Less, None.cmp(&Some(0)));
+/// ```
+///
+pub trait PartialOrdBy<T>: PartialEq {
+    /// Compare two values of type `T` by the field's enum. Return values of `None` are treated as `NULL` values. See [`Sortable`] for more information.
+    ///
+    /// Be careful when comparing types like `Option` which implement `Ord`. This means that `None` and `Some` have an order where we might use them as unknown / `NULL` values. This can be a surprise.
+    ///
+    /// Another issue is `f64` only implements `PartialOrd` and not `Ord` because a value can hold `f64::NAN`. In this situation `partial_cmp` will return `None` and we'll treat these values as `NULL` as expected.
+    fn partial_cmp_by(&self, a: &T, b: &T) -> Option<Ordering>;
+
+    /// Optionally projects items to keys compared in place of [`Self::partial_cmp_by`] when sorting. Keys are computed once per item rather than twice per comparison (a Schwartzian transform) so return one for fields whose comparisons format or parse e.g., dates held as text. Return `None`, as by default, to compare with [`Self::partial_cmp_by`]. For example:
+    /// ```rust
+    /// # use dioxus_sortable::{PartialOrdBy, Projection};
+    /// # use std::cmp::Ordering;
+    /// struct Invoice {
+    ///     /// e.g., "1,234.50"
+    ///     amount: String,
+    /// }
+    ///
+    /// # #[derive(PartialEq)]
+    /// enum InvoiceField {
+    ///     Amount,
+    /// }
+    ///
+    /// fn parse(amount: &str) -> Option<f64> {
+    ///     amount.replace(',', "").parse().ok()
+    /// }
+    ///
+    /// impl PartialOrdBy<Invoice> for InvoiceField {
+    ///     fn partial_cmp_by(&self, a: &Invoice, b: &Invoice) -> Option<Ordering> {
+    ///         parse(&a.amount)?.partial_cmp(&parse(&b.amount)?)
+    ///     }
+    ///
+    ///     fn projection(&self) -> Option<Projection<Invoice>> {
+    ///         Some(Projection::new(|invoice: &Invoice| parse(&invoice.amount)))
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Keys must order items as [`Self::partial_cmp_by`] does, with `None` keys as `NULL`s: it's still used where keys would cost more than they save e.g., [`crate::UseSorter::insert_sorted`] and [`sort_slice_by`]. Used by [`crate::UseSorter::sort`], [`crate::UseSorter::sort_indices`] and friends unless replaced with [`crate::UseSorter::with_comparator`] or [`crate::UseSorter::with_computed`].
+    fn projection(&self) -> Option<Projection<T>> {
+        None
+    }
+}
+
+/// Compares two items by index using keys computed once per item. See [`Projection`].
+pub(crate) type KeyCmp = Box<dyn Fn(usize, usize) -> Option<Ordering>>;
+type ProjectKeys<T> = Rc<dyn Fn(&mut dyn Iterator<Item = (usize, &T)>) -> KeyCmp>;
+
+/// Projects items of `T` to keys compared in place of a field's comparisons. Returned by [`PartialOrdBy::projection`]. Hides the key type so fields of one enum may project to different types.
+pub struct Projection<T> {
+    keys: ProjectKeys<T>,
+}
+
+impl<T> Clone for Projection<T> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Projection<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Projection").finish_non_exhaustive()
+    }
+}
+
+impl<T> Projection<T> {
+    /// Creates a projection from a fn returning an item's key, or `None` if it's `NULL`.
+    pub fn new<K: PartialOrd + 'static>(project: impl Fn(&T) -> Option<K> + 'static) -> Self {
+        Self {
+            keys: Rc::new(move |items| project_keys(items, &project)),
+        }
+    }
+
+    /// Projects items given with their index. Returns a comparison by index of items given.
+    #[cfg_attr(not(feature = "core"), allow(dead_code))]
+    pub(crate) fn keys(&self, items: &mut dyn Iterator<Item = (usize, &T)>) -> KeyCmp {
+        (self.keys)(items)
+    }
+}
+
+/// Computes the key of each item given with its index. Returns a comparison by index of items given.
+pub(crate) fn project_keys<T, K: PartialOrd + 'static>(
+    items: &mut dyn Iterator<Item = (usize, &T)>,
+    project: impl Fn(&T) -> Option<K>,
+) -> KeyCmp {
+    let mut keys = Vec::new();
+    for (index, item) in items {
+        if keys.len() <= index {
+            keys.resize_with(index + 1, || None);
+        }
+        keys[index] = project(item);
+    }
+    Box::new(move |a, b| keys[a].as_ref()?.partial_cmp(keys[b].as_ref()?))
+}
+
+/// Alternative to [`PartialOrdBy`] for fields with a total order and no `NULL` values e.g., integers, strings or dates. Implementing it also implements [`PartialOrdBy`] so every sorting fn works. Use [`crate::UseSorter::sort_ord`] to skip `NULL` handling entirely. For example:
+/// ```rust
+/// # use dioxus_sortable::OrdBy;
+/// # use std::cmp::Ordering;
+/// struct MyStruct {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum MyStructField {
+///     Name,
+///     Age,
+/// }
+///
+/// impl OrdBy<MyStruct> for MyStructField {
+///     fn cmp_by(&self, a: &MyStruct, b: &MyStruct) -> Ordering {
+///         match self {
+///             MyStructField::Name => a.name.cmp(&b.name),
+///             MyStructField::Age => a.age.cmp(&b.age),
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Sortable::null_handling`] has no effect as no value is ever `NULL`.
+pub trait OrdBy<T>: PartialEq {
+    /// Compare two values of type `T` by the field's enum.
+    fn cmp_by(&self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T, F: OrdBy<T>> PartialOrdBy<T> for F {
+    fn partial_cmp_by(&self, a: &T, b: &T) -> Option<Ordering> {
+        Some(self.cmp_by(a, b))
+    }
+}
+
+/// Alternative to [`PartialOrdBy`] for expensive comparisons. Returns a sort key per row which [`crate::UseSorter::sort_by_cached_key`] computes once per item instead of once per comparison. Useful for locale-aware collation, parsing or other computed fields.
+///
+/// Returning `None` marks the item as `NULL`. All fields share one `Key` type so an enum with a variant per field works well. Only keys from the same field are compared. For example:
+/// ```rust
+/// # use dioxus_sortable::SortKeyBy;
+/// struct MyStruct {
+///     name: String,
+///     score: Option<u32>,
+/// }
+///
+/// # #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum MyStructField {
+///     Name,
+///     Score,
+/// }
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// enum MyStructKey {
+///     Name(String),
+///     Score(u32),
+/// }
+///
+/// impl SortKeyBy<MyStruct> for MyStructField {
+///     type Key = MyStructKey;
+///
+///     fn sort_key_by(&self, item: &MyStruct) -> Option<MyStructKey> {
+///         match self {
+///             MyStructField::Name => Some(MyStructKey::Name(item.name.to_lowercase())),
+///             MyStructField::Score => item.score.map(MyStructKey::Score),
+///         }
+///     }
+/// }
+/// ```
+pub trait SortKeyBy<T>: PartialEq {
+    /// Key type compared when sorting.
+    type Key: Ord;
+
+    /// Returns the sort key for an item by the field's enum. Return values of `None` are treated as `NULL` values. See [`Sortable`] for more information.
+    fn sort_key_by(&self, item: &T) -> Option<Self::Key>;
+}
+
+/// Trait used to describe how a field can be sorted. This must be implemented on the field enum.
+///
+/// Our [`PartialOrdBy`] fn may result in `None` values which we refer to as `NULL`. We borrow from SQL here to handle these values in a similar way to the [SQL ORDER BY clause](https://www.postgresql.org/docs/current/sql-select.html#SQL-ORDERBY). The PostgreSQL general form is `ORDER BY expression [ ASC | DESC | USING operator ] [ NULLS { FIRST | LAST } ] [, ...]` where:
+/// - `expression` is the field being sorted.
+/// - `ASC` and `DESC` are the sort [`Direction`].
+/// - `USING operator` is implied by [`PartialOrdBy`].
+/// - `NULLS { FIRST | LAST }` corresponds to [`NullHandling`].
+///
+/// Meaning you can sort by ascending or descending and optionally specify `NULL` ordering.
+pub trait Sortable: PartialEq {
+    /// Describes how this field can be sorted.
+    fn sort_by(&self) -> Option<SortBy>;
+
+    /// Describes how `NULL` values (when [`PartialOrdBy`] returns `None`) should be ordered when sorting. Either all at the start or the end, or by direction as SQL does (see [`NullHandling::SqlDefault`]).
+    ///
+    /// Provided implementation relies on the default (all at the end) and should be overridden if you want to change this generally or on a per-field basis. Users may override it at runtime with [`crate::UseSorter::set_null_handling`].
+    fn null_handling(&self) -> NullHandling {
+        NullHandling::default()
+    }
+
+    /// Fields used to order items that compare as equal on this field, in priority order. For example ties on a date could be broken by name. Each tie-breaker uses its own initial direction (see [`SortBy::direction`]) and [`Self::null_handling`].
+    ///
+    /// Provided implementation has no tie-breakers so equal items keep their relative order (see [`SortAlgorithm`]).
+    fn tie_breakers(&self) -> Vec<Self>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+
+    /// Human-readable name of the field e.g., "Left office". Lets generic components such as [`crate::SortSummary`], [`crate::SortControl`] and [`crate::ColumnPicker`] name fields without a parallel `match`. See [`crate::FieldLabels`].
+    ///
+    /// Provided implementation returns an empty string.
+    fn label(&self) -> &str {
+        ""
+    }
+
+    /// Abbreviated [`Self::label`] for narrow layouts e.g., "Left" for "Left office". See [`crate::FieldLabels::short`].
+    ///
+    /// Provided implementation returns [`Self::label`].
+    fn short_label(&self) -> &str {
+        self.label()
+    }
+
+    /// Describes sorting this field in a direction in plain language e.g., "newest first" for a date in descending order. Used alongside [`Self::label`].
+    ///
+    /// Provided implementation returns "ascending" or "descending".
+    fn direction_label(&self, dir: Direction) -> &str {
+        match dir {
+            Direction::Ascending => "ascending",
+            Direction::Descending => "descending",
+        }
+    }
+
+    /// How the field's header and cells align their text e.g., [`Align::Right`] for numbers so digits line up. Applied by [`crate::Th`], [`crate::Td`] and [`crate::SortableTable`].
+    ///
+    /// Provided implementation returns `None` to leave alignment to CSS.
+    fn align(&self) -> Option<Align> {
+        None
+    }
+
+    /// Suggested width of the field's column as a CSS length e.g., "8em". Set on the [`crate::Th`] so applies to the whole column. Replaced once resized with [`crate::UseColumnWidths`].
+    ///
+    /// Provided implementation returns `None` to leave the width to the browser.
+    fn width_hint(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Text alignment of a field's column. Returned by [`Sortable::align`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Align to the left e.g., for text.
+    Left,
+    /// Align to the right e.g., for numbers and amounts.
+    Right,
+    /// Centre e.g., for icons or checkmarks.
+    Center,
+}
+
+impl Align {
+    /// Returns a `style` declaration for the alignment e.g., `text-align: right;`.
+    pub fn style(&self) -> &'static str {
+        match self {
+            Self::Left => "text-align: left;",
+            Self::Right => "text-align: right;",
+            Self::Center => "text-align: center;",
+        }
+    }
+}
+
+/// Lists every variant of a field enum in declaration order. Lets components such as [`crate::AllTh`] render a column per field so they can't fall out of step with the enum.
+///
+/// Implement with [`crate::all_fields!`] which fails to compile if a variant is missing.
+pub trait AllFields: Sized + 'static {
+    /// Every field in display order.
+    const ALL: &'static [Self];
+}
+
+/// Slice sort algorithm used by [`crate::UseSorter::sort`]. Set with [`crate::UseSorterBuilder::with_algorithm`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum SortAlgorithm {
+    /// Guarantees that items comparing as equal (including `NULL`s) keep their relative order. Uses [`slice::sort_by`]. The default.
+    #[default]
+    Stable,
+    /// Makes no guarantee about the order of equal items. Uses [`slice::sort_unstable_by`] which can be faster on large datasets.
+    Unstable,
+}
+
+/// Describes how a field should be sorted. Returned by [`Sortable::sort_by`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortBy {
+    /// This field is limited to being sorted in the one direction specified.
+    Fixed(Direction),
+    /// Like [`Self::Fixed`] but toggling again returns to the unsorted (original) order e.g., a score column that toggles between highest first and unsorted, never lowest first.
+    FixedWithReset(Direction),
+    /// This field can be sorted in either direction. The direction specifies the initial direction. Fields of this sort can be toggled between directions.
+    Reversible(Direction),
+    /// Like [`Self::Reversible`] but toggling past both directions returns to the unsorted (original) order. The direction specifies the initial direction.
+    ReversibleWithReset(Direction),
+    /// This field steps through the given states in order, wrapping back to the first. The first state is used when switching to the field. For example `&[SortState::Sorted(Direction::Descending), SortState::Sorted(Direction::Ascending), SortState::Unsorted]` matches platforms that sort newest first. An empty cycle never changes the sort.
+    ///
+    /// Serialized as its states but can't be deserialized as they are `'static`.
+    #[cfg_attr(feature = "serde", serde(skip_deserializing))]
+    Cycle(&'static [SortState]),
+    /// This field isn't sorted by but may group rows e.g., a category used with [`crate::UseGrouper`]. [`crate::UseSorter`] treats it as unsortable. [`crate::Th`] renders it without an arrow, groups by it when clicked given a [`crate::UseGrouper`] and highlights it while grouped by.
+    GroupOnly,
+}
+
+/// A step in a [`SortBy::Cycle`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortState {
+    /// Sorted in the given direction.
+    Sorted(Direction),
+    /// Unsorted (original) order.
+    Unsorted,
+}
+
+/// Sort direction. Does not have a default -- implied by the field via [`SortBy`].
+///
+/// Actual sorting is done by [`PartialOrdBy`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Ascending sort. A-Z, 0-9, little to big, etc.
+    Ascending,
+    /// Descending sort. Z-A, opposite of ascending.
+    Descending,
+}
+
+impl Direction {
+    /// Inverts the direction.
+    pub fn invert(&self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    /// Short form used in URLs and storage: `asc` or `desc`.
+    pub(crate) fn as_param(&self) -> &'static str {
+        match self {
+            Self::Ascending => "asc",
+            Self::Descending => "desc",
+        }
+    }
+
+    /// Parses the short form from [`Self::as_param`].
+    pub(crate) fn from_param(param: &str) -> Option<Self> {
+        match param {
+            "asc" => Some(Self::Ascending),
+            "desc" => Some(Self::Descending),
+            _ => None,
+        }
+    }
+
+    fn from_field<F: Sortable>(field: &F) -> Direction {
+        field.sort_by().unwrap_or_default().direction()
+    }
+}
+
+/// Describes how `NULL` values should be ordered when sorting. We refer to `None` values returned from [`PartialOrdBy::partial_cmp_by`] as `NULL`. Warning: Rust's `Option::None` is not strictly equivalent to SQL's `NULL` but we borrow from SQL terminology to handle them.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NullHandling {
+    /// Places all `NULL` values first.
+    First,
+    /// Places all `NULL` values last. The default.
+    #[default]
+    Last,
+    /// Leaves out items where the active field is `NULL`. Applies to [`crate::UseSorter::sort_indices`], [`crate::UseSorter::sorted_iter`], [`crate::FilteredSorted`] and [`crate::UseSorter::sort_and_remove_nulls`]. Sorting in place with [`crate::UseSorter::sort`] can't remove items so places them last instead, as do tie-breakers.
+    Remove,
+    /// Places `NULL` values last when ascending and first when descending. Matches the default of SQL databases such as PostgreSQL, as if `NULL` were larger than any value.
+    SqlDefault,
+}
+
+impl NullHandling {
+    /// Returns true if `NULL` values are placed first when sorting in the given direction.
+    pub fn nulls_first(&self, dir: Direction) -> bool {
+        match self {
+            Self::First => true,
+            Self::Last | Self::Remove => false,
+            Self::SqlDefault => dir == Direction::Descending,
+        }
+    }
+}
+
+fn cycle_directions(states: &[SortState]) -> impl Iterator<Item = Direction> + '_ {
+    states.iter().filter_map(|state| match state {
+        SortState::Sorted(dir) => Some(*dir),
+        SortState::Unsorted => None,
+    })
+}
+
+impl Default for SortBy {
+    fn default() -> SortBy {
+        Self::increasing_or_decreasing().unwrap()
+    }
+}
+
+impl SortBy {
+    /// Field may not be sorted. Convenience fn for specifying how a field may be sorted.
+    pub fn unsortable() -> Option<Self> {
+        None
+    }
+    /// Field may only be sorted in ascending order.
+    pub fn increasing() -> Option<Self> {
+        Some(Self::Fixed(Direction::Ascending))
+    }
+    /// Field may only be sorted in descending order.
+    pub fn decreasing() -> Option<Self> {
+        Some(Self::Fixed(Direction::Descending))
+    }
+    /// Field may only be sorted in ascending order before returning to unsorted.
+    pub fn increasing_or_unsorted() -> Option<Self> {
+        Some(Self::FixedWithReset(Direction::Ascending))
+    }
+    /// Field may only be sorted in descending order before returning to unsorted.
+    pub fn decreasing_or_unsorted() -> Option<Self> {
+        Some(Self::FixedWithReset(Direction::Descending))
+    }
+    /// Field may be sorted in either direction. The initial direction is ascending. This is the default.
+    pub fn increasing_or_decreasing() -> Option<Self> {
+        Some(Self::Reversible(Direction::Ascending))
+    }
+    /// Field may be sorted in either direction. The initial direction is descending.
+    pub fn decreasing_or_increasing() -> Option<Self> {
+        Some(Self::Reversible(Direction::Descending))
+    }
+    /// Field may be sorted in either direction before returning to unsorted. The initial direction is ascending.
+    pub fn increasing_decreasing_or_unsorted() -> Option<Self> {
+        Some(Self::ReversibleWithReset(Direction::Ascending))
+    }
+    /// Field may be sorted in either direction before returning to unsorted. The initial direction is descending.
+    pub fn decreasing_increasing_or_unsorted() -> Option<Self> {
+        Some(Self::ReversibleWithReset(Direction::Descending))
+    }
+
+    /// Field steps through a custom sequence of states. See [`Self::Cycle`].
+    pub fn cycle(states: &'static [SortState]) -> Option<Self> {
+        Some(Self::Cycle(states))
+    }
+
+    /// Field may group rows but not be sorted by. See [`Self::GroupOnly`].
+    pub fn group_only() -> Option<Self> {
+        Some(Self::GroupOnly)
+    }
+
+    /// Returns true for [`Self::GroupOnly`].
+    pub fn is_group_only(&self) -> bool {
+        *self == Self::GroupOnly
+    }
+
+    /// Returns the initial / implied direction of the sort. For a [`Self::Cycle`] this is the first direction in the cycle, or ascending if there is none. Ascending for [`Self::GroupOnly`].
+    pub fn direction(&self) -> Direction {
+        match self {
+            Self::Fixed(dir) | Self::FixedWithReset(dir) => *dir,
+            Self::Reversible(dir) => *dir,
+            Self::ReversibleWithReset(dir) => *dir,
+            Self::Cycle(states) => cycle_directions(states)
+                .next()
+                .unwrap_or(Direction::Ascending),
+            Self::GroupOnly => Direction::Ascending,
+        }
+    }
+
+    /// Returns true if the field may be sorted in both directions.
+    pub fn is_reversible(&self) -> bool {
+        match self {
+            Self::Fixed(_) | Self::FixedWithReset(_) | Self::GroupOnly => false,
+            Self::Reversible(_) | Self::ReversibleWithReset(_) => true,
+            Self::Cycle(states) => {
+                let dir = self.direction();
+                cycle_directions(states).any(|other| other != dir)
+            }
+        }
+    }
+
+    /// Returns true if the field may return to the unsorted (original) order.
+    pub fn can_reset(&self) -> bool {
+        match self {
+            Self::Fixed(_) | Self::Reversible(_) | Self::GroupOnly => false,
+            Self::FixedWithReset(_) | Self::ReversibleWithReset(_) => true,
+            Self::Cycle(states) => states.contains(&SortState::Unsorted),
+        }
+    }
+
+    /// Returns a copy starting in `dir` instead. Fixed and cycling fields can't change where they start so are returned as-is.
+    pub fn with_initial_direction(self, dir: Direction) -> Self {
+        match self {
+            Self::Reversible(_) => Self::Reversible(dir),
+            Self::ReversibleWithReset(_) => Self::ReversibleWithReset(dir),
+            Self::Fixed(_) | Self::FixedWithReset(_) | Self::Cycle(_) | Self::GroupOnly => self,
+        }
+    }
+
+    pub(crate) fn ensure_direction(&self, dir: Direction) -> Direction {
+        use SortBy::*;
+        match self {
+            // Must match allowed
+            Fixed(allowed) | FixedWithReset(allowed) if *allowed == dir => dir,
+            // Did not match allowed
+            Fixed(allowed) | FixedWithReset(allowed) => *allowed,
+            // Any allowed
+            Reversible(_) | ReversibleWithReset(_) => dir,
+            // Must be in the cycle
+            Cycle(states) if cycle_directions(states).any(|other| other == dir) => dir,
+            Cycle(_) => self.direction(),
+            GroupOnly => dir,
+        }
+    }
+
+    /// Returns the state after toggling. `current` is the field's state if it's the active field, otherwise `None`. Returns `None` if the sort should not change.
+    pub fn next_state(&self, current: Option<SortState>) -> Option<SortState> {
+        use SortBy::*;
+        use SortState::*;
+        Some(match (self, current) {
+            (GroupOnly, _) => return None,
+            (Fixed(dir), _) => Sorted(*dir),
+            // Toggle off if sorted, otherwise on
+            (FixedWithReset(_), Some(Sorted(_))) => Unsorted,
+            (FixedWithReset(dir), _) => Sorted(*dir),
+            // Invert direction if the same field
+            (Reversible(_), Some(Sorted(dir))) => Sorted(dir.invert()),
+            // Reset state to new field
+            (Reversible(dir), _) => Sorted(*dir),
+            // Cycle: initial direction -> inverted -> unsorted
+            (ReversibleWithReset(dir), Some(Sorted(current))) if current == *dir => {
+                Sorted(dir.invert())
+            }
+            (ReversibleWithReset(_), Some(Sorted(_))) => Unsorted,
+            (ReversibleWithReset(dir), _) => Sorted(*dir),
+            (Cycle(states), current) => {
+                let next = current
+                    .and_then(|current| states.iter().position(|s| *s == current))
+                    .map_or(0, |i| (i + 1) % states.len());
+                *states.get(next)?
+            }
+        })
+    }
+}
+
+/// Returns how a field may be sorted by a [`crate::UseSorter`]. `None` for unsortable and [`SortBy::GroupOnly`] fields.
+pub(crate) fn sortable_by<F: Sortable>(field: &F) -> Option<SortBy> {
+    field.sort_by().filter(|sort_by| !sort_by.is_group_only())
+}
+
+/// Sorts items by a field outside of any hook e.g., on a server or in tests. Uses the same comparison as [`crate::UseSorter::sort`]: a stable sort with `NULL` items placed by `nulls`.
+///
+/// Tie-breakers aren't used and [`NullHandling::Remove`] places `NULL` items last as items can't be removed in place.
+///
+/// ```rust
+/// # use dioxus_sortable::{sort_slice_by, Direction, NullHandling, PartialOrdBy};
+/// # #[derive(PartialEq)]
+/// struct ByValue;
+///
+/// impl PartialOrdBy<f64> for ByValue {
+///     fn partial_cmp_by(&self, a: &f64, b: &f64) -> Option<std::cmp::Ordering> {
+///         a.partial_cmp(b)
+///     }
+/// }
+///
+/// let mut items = [2.0, f64::NAN, 1.0];
+/// sort_slice_by(&ByValue, Direction::Descending, NullHandling::Last, &mut items);
+/// assert_eq!(items[..2], [2.0, 1.0]);
+/// assert!(items[2].is_nan());
+/// ```
+pub fn sort_slice_by<T, F: PartialOrdBy<T>>(
+    field: &F,
+    dir: Direction,
+    nulls: NullHandling,
+    items: &mut [T],
+) {
+    sort_by(&[(field, dir, nulls)], SortAlgorithm::Stable, items);
+}
+
+/// How a field's comparisons contradict each other. Returned by [`check_partial_ord_by`]. Holds the positions of the items involved.
+///
+/// Sorting assumes comparisons are consistent. When they're not, items end up in an order that depends on the sort algorithm's internals.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InconsistentCmp {
+    /// Comparing an item with itself returned `Less` or `Greater`. Should be `Equal`, or `None` for `NULL`s.
+    Reflexive(usize),
+    /// Comparing two items returned `None` though neither is `NULL`: both compare `Equal` with themselves. An item is `NULL` only if comparing it with itself returns `None`.
+    Null(usize, usize),
+    /// Comparing `a` with `b` isn't the reverse of comparing `b` with `a`.
+    Antisymmetric(usize, usize),
+    /// `a` is less than or equal to `b` and `b` to `c` but `a` is greater than `c`.
+    Transitive(usize, usize, usize),
+}
+
+impl fmt::Display for InconsistentCmp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reflexive(a) => write!(f, "item {a} is not equal to itself"),
+            Self::Null(a, b) => write!(
+                f,
+                "items {a} and {b} compare as None but neither is NULL (compares as None with itself)"
+            ),
+            Self::Antisymmetric(a, b) => write!(
+                f,
+                "comparing items {a} and {b} isn't the reverse of comparing {b} and {a}"
+            ),
+            Self::Transitive(a, b, c) => write!(
+                f,
+                "item {a} <= {b} and {b} <= {c} but {a} > {c} (not transitive)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InconsistentCmp {}
+
+/// Checks that a field's [`PartialOrdBy`] impl is consistent on a sample of items: equal to itself, antisymmetric (comparing `a` with `b` is the reverse of `b` with `a`), transitive and only returning `None` for `NULL`s. Useful in unit tests of hand-written comparisons. For example:
+/// ```rust
+/// # use dioxus_sortable::*;
+/// # use std::cmp::Ordering;
+/// #[derive(PartialEq)]
+/// struct ByLength;
+///
+/// impl PartialOrdBy<&str> for ByLength {
+///     // Oops: "a" < "b" and "b" < "a"
+///     fn partial_cmp_by(&self, a: &&str, b: &&str) -> Option<Ordering> {
+///         Some(if a.len() <= b.len() { Ordering::Less } else { Ordering::Greater })
+///     }
+/// }
+///
+/// assert!(check_partial_ord_by(&ByLength, &["a", "b"]).is_err());
+/// ```
+///
+/// Large slices are sampled evenly rather than compared exhaustively: a success doesn't prove consistency. See [`crate::UseSorterBuilder::with_checked_comparisons`] to check while sorting.
+pub fn check_partial_ord_by<T, F: PartialOrdBy<T>>(
+    field: &F,
+    items: &[T],
+) -> Result<(), InconsistentCmp> {
+    check_compare(&field, |i| &items[i], items.len())
+}
+
+/// Items compared in pairs by [`check_compare`]. Triples use fewer.
+const CHECK_PAIRS: usize = 32;
+const CHECK_TRIPLES: usize = 12;
+
+/// Checks a comparer on an even sample of items. See [`check_partial_ord_by`].
+pub(crate) fn check_compare<'a, T: 'a, C: Compare<T>>(
+    cmp: &C,
+    item: impl Fn(usize) -> &'a T,
+    len: usize,
+) -> Result<(), InconsistentCmp> {
+    let sample = (0..len)
+        .step_by((len / CHECK_PAIRS).max(1))
+        .take(CHECK_PAIRS)
+        .collect::<Vec<_>>();
+    let compare = |a: usize, b: usize| cmp.compare(item(a), item(b));
+    for &a in &sample {
+        if compare(a, a).is_some_and(|ord| ord.is_ne()) {
+            return Err(InconsistentCmp::Reflexive(a));
+        }
+    }
+    for (i, &a) in sample.iter().enumerate() {
+        for &b in &sample[i + 1..] {
+            let ab = compare(a, b);
+            if ab.is_none() && compare(a, a).is_some() && compare(b, b).is_some() {
+                return Err(InconsistentCmp::Null(a, b));
+            }
+            if ab.map(Ordering::reverse) != compare(b, a) {
+                return Err(InconsistentCmp::Antisymmetric(a, b));
+            }
+        }
+    }
+    // NULLs have no order so only values can break transitivity
+    let values = sample
+        .into_iter()
+        .filter(|&a| compare(a, a).is_some())
+        .take(CHECK_TRIPLES)
+        .collect::<Vec<_>>();
+    let is_le = |a, b| compare(a, b).is_some_and(Ordering::is_le);
+    for &a in &values {
+        for &b in values.iter().filter(|&&b| is_le(a, b)) {
+            for &c in values.iter().filter(|&&c| is_le(b, c)) {
+                if compare(a, c).is_some_and(Ordering::is_gt) {
+                    return Err(InconsistentCmp::Transitive(a, b, c));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A comparer and how to apply it. Sorting uses a chain of these: the active field followed by any tie-breakers.
+pub(crate) type SortKey<C> = (C, Direction, NullHandling);
+
+/// Compares items by one link of a sort chain. Implemented for borrowed fields and [`FieldCmp`]. Kept separate from [`PartialOrdBy`] so it can't overlap with the blanket impl for [`OrdBy`].
+pub(crate) trait Compare<T> {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering>;
+}
+
+impl<T, F: PartialOrdBy<T> + ?Sized> Compare<T> for &F {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        self.partial_cmp_by(a, b)
+    }
+}
+
+/// Describes sort keys in plain language from [`Sortable::label`] and [`Sortable::direction_label`] e.g., "Name, A to Z, then Age, ascending".
+#[cfg_attr(not(feature = "core"), allow(dead_code))]
+pub(crate) fn describe_sort<F: Sortable>(keys: &[(F, Direction)], then: &str) -> String {
+    keys.iter()
+        .map(|(field, dir)| format!("{}, {}", field.label(), field.direction_label(*dir)))
+        .collect::<Vec<_>>()
+        .join(&format!(", {then} "))
+}
+
+/// Builds the comparison chain for a field: the field itself followed by any secondary sorts and then its tie-breakers.
+pub(crate) fn sort_chain<'f, F: Sortable>(
+    field: &'f F,
+    dir: Direction,
+    then_by: &'f [(F, Direction)],
+    tie_breakers: &'f [F],
+) -> Vec<SortKey<&'f F>> {
+    let mut chain = vec![(field, dir, field.null_handling())];
+    chain.extend(
+        then_by
+            .iter()
+            .map(|(then, dir)| (then, *dir, then.null_handling())),
+    );
+    chain.extend(
+        tie_breakers
+            .iter()
+            .map(|tb| (tb, Direction::from_field(tb), tb.null_handling())),
+    );
+    chain
+}
+
+/// Sorts items by a chain. Returns the number of `NULL`s on the primary field.
+pub(crate) fn sort_by<T, C: Compare<T>>(
+    chain: &[SortKey<C>],
+    algorithm: SortAlgorithm,
+    items: &mut [T],
+) -> usize {
+    let mut indices = (0..items.len()).collect::<Vec<_>>();
+    let (mid, front_chain, back_chain) = partition_nulls(chain, |i| &items[i], &mut indices);
+    apply_permutation(items, indices);
+    let (front, back) = items.split_at_mut(mid);
+    sort_group(algorithm, front, |a, b| cmp_chain(front_chain, a, b));
+    sort_group(algorithm, back, |a, b| cmp_chain(back_chain, a, b));
+    null_count(chain, mid, items.len())
+}
+
+/// Moves the first `n` items in sorted order to the front, sorted, without sorting the rest. Ties are broken by position for [`SortAlgorithm::Stable`] so the front matches a full sort. Returns the number of `NULL`s on the primary field.
+#[cfg_attr(not(feature = "core"), allow(dead_code))]
+pub(crate) fn sort_top_n_by<T, C: Compare<T>>(
+    chain: &[SortKey<C>],
+    algorithm: SortAlgorithm,
+    items: &mut [T],
+    n: usize,
+) -> usize {
+    let mut indices = (0..items.len()).collect::<Vec<_>>();
+    let (mid, front_chain, back_chain) = partition_nulls(chain, |i| &items[i], &mut indices);
+    let (front, back) = indices.split_at_mut(mid);
+    select_top_n(algorithm, front, n, |a, b| {
+        cmp_chain(front_chain, &items[*a], &items[*b])
+    });
+    select_top_n(algorithm, back, n.saturating_sub(mid), |a, b| {
+        cmp_chain(back_chain, &items[*a], &items[*b])
+    });
+    apply_permutation(items, indices);
+    null_count(chain, mid, items.len())
+}
+
+/// Moves the first `n` indices in order to the front of the group and sorts them. Selection is unstable so ties are broken by index to keep a stable order.
+#[cfg_attr(not(feature = "core"), allow(dead_code))]
+fn select_top_n(
+    algorithm: SortAlgorithm,
+    group: &mut [usize],
+    n: usize,
+    mut cmp: impl FnMut(&usize, &usize) -> Ordering,
+) {
+    let n = n.min(group.len());
+    if n == 0 {
+        return;
+    }
+    let mut cmp = |a: &usize, b: &usize| {
+        #[cfg(feature = "debug")]
+        crate::debug::count_comparison();
+        let ord = cmp(a, b);
+        match algorithm {
+            SortAlgorithm::Stable => ord.then(a.cmp(b)),
+            SortAlgorithm::Unstable => ord,
+        }
+    };
+    if n < group.len() {
+        group.select_nth_unstable_by(n - 1, &mut cmp);
+    }
+    group[..n].sort_unstable_by(cmp);
+}
+
+/// Sorts by a chain of totally ordered fields. No `NULL`s so no partitioning.
+#[cfg_attr(not(feature = "core"), allow(dead_code))]
+pub(crate) fn sort_ord_by<T, F: OrdBy<T>>(
+    chain: &[SortKey<&F>],
+    algorithm: SortAlgorithm,
+    items: &mut [T],
+) {
+    sort_group(algorithm, items, |a, b| {
+        chain
+            .iter()
+            .map(|(field, dir, _)| {
+                let ord = field.cmp_by(a, b);
+                match dir {
+                    Direction::Ascending => ord,
+                    Direction::Descending => ord.reverse(),
+                }
+            })
+            .find(|ord| ord.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+/// Sorts indices into items. Items are looked up by index so they may be held behind pointers or in other collections. Returns the number of `NULL`s on the primary field.
+pub(crate) fn sort_indices_by<'a, T: 'a, C: Compare<T>>(
+    chain: &[SortKey<C>],
+    algorithm: SortAlgorithm,
+    item: impl Fn(usize) -> &'a T + Copy,
+    indices: &mut [usize],
+) -> usize {
+    let (mid, front_chain, back_chain) = partition_nulls(chain, item, indices);
+    let (front, back) = indices.split_at_mut(mid);
+    sort_group(algorithm, front, |a, b| {
+        cmp_chain(front_chain, item(*a), item(*b))
+    });
+    sort_group(algorithm, back, |a, b| {
+        cmp_chain(back_chain, item(*a), item(*b))
+    });
+    null_count(chain, mid, indices.len())
+}
+
+/// Sorts indices by comparing the indices themselves, for chains that look items up or cache their values by index. Otherwise like [`sort_indices_by`].
+#[cfg_attr(not(feature = "core"), allow(dead_code))]
+pub(crate) fn sort_indices_by_index<C: Compare<usize>>(
+    chain: &[SortKey<C>],
+    algorithm: SortAlgorithm,
+    indices: &mut [usize],
+) -> usize {
+    let (mid, front_chain, back_chain) =
+        partition_nulls_by(chain, |field, index| is_null(field, &index), indices);
+    let (front, back) = indices.split_at_mut(mid);
+    sort_group(algorithm, front, |a, b| cmp_chain(front_chain, a, b));
+    sort_group(algorithm, back, |a, b| cmp_chain(back_chain, a, b));
+    null_count(chain, mid, indices.len())
+}
+
+/// Stable partitions `indices` into NULL and non-NULL groups on the primary field, ordered by its [`NullHandling`]. This takes one `is_null` check per item instead of two per comparison against a NULL. Returns the split point and the chain to sort each group by: NULLs are equal on the primary field so only its tie-breakers apply.
+pub(crate) fn partition_nulls<'a, 'c, T: 'a, C: Compare<T>>(
+    chain: &'c [SortKey<C>],
+    item: impl Fn(usize) -> &'a T,
+    indices: &mut [usize],
+) -> (usize, &'c [SortKey<C>], &'c [SortKey<C>]) {
+    partition_nulls_by(chain, |field, i| is_null(field, item(i)), indices)
+}
+
+/// Like [`partition_nulls`] with a check of whether the item at an index is `NULL` on a field.
+fn partition_nulls_by<'c, C>(
+    chain: &'c [SortKey<C>],
+    is_null: impl Fn(&C, usize) -> bool,
+    indices: &mut [usize],
+) -> (usize, &'c [SortKey<C>], &'c [SortKey<C>]) {
+    let Some(((field, dir, nulls), rest)) = chain.split_first() else {
+        return (indices.len(), chain, chain);
+    };
+    let (values, null_indices): (Vec<usize>, Vec<usize>) =
+        indices.iter().copied().partition(|&i| !is_null(field, i));
+    let (front, back, front_chain, back_chain) = if nulls.nulls_first(*dir) {
+        (null_indices, values, rest, chain)
+    } else {
+        (values, null_indices, chain, rest)
+    };
+    indices[..front.len()].copy_from_slice(&front);
+    indices[front.len()..].copy_from_slice(&back);
+    (front.len(), front_chain, back_chain)
+}
+
+/// Returns how many of `len` items [`partition_nulls`] put on the `NULL` side of `mid`.
+fn null_count<C>(chain: &[SortKey<C>], mid: usize, len: usize) -> usize {
+    match chain.first() {
+        Some((_, dir, nulls)) if nulls.nulls_first(*dir) => mid,
+        Some(_) => len - mid,
+        None => 0,
+    }
+}
+
+fn sort_group<T>(algorithm: SortAlgorithm, group: &mut [T], cmp: impl FnMut(&T, &T) -> Ordering) {
+    #[cfg(feature = "debug")]
+    let cmp = {
+        let mut cmp = cmp;
+        move |a: &T, b: &T| {
+            crate::debug::count_comparison();
+            cmp(a, b)
+        }
+    };
+    match algorithm {
+        SortAlgorithm::Stable => group.sort_by(cmp),
+        SortAlgorithm::Unstable => group.sort_unstable_by(cmp),
+    }
+}
+
+/// Moves each item to its sorted position where `indices[i]` is the original position of the item that belongs at `i`.
+pub(crate) fn apply_permutation<T>(items: &mut [T], mut indices: Vec<usize>) {
+    for i in 0..items.len() {
+        let mut index = indices[i];
+        // Follow the chain of earlier swaps to find where the item now lives
+        while index < i {
+            index = indices[index];
+        }
+        indices[i] = index;
+        items.swap(i, index);
+    }
+}
+
+#[cfg_attr(not(feature = "core"), allow(dead_code))]
+pub(crate) fn sort_by_cached_key<T, F: SortKeyBy<T>>(
+    sort_by: &F,
+    dir: Direction,
+    nulls: NullHandling,
+    algorithm: SortAlgorithm,
+    items: &mut [T],
+) -> usize {
+    // Same approach as `slice::sort_by_cached_key`: sort (key, index) pairs then permute
+    let mut indices = items
+        .iter()
+        .map(|item| sort_by.sort_key_by(item))
+        .enumerate()
+        .map(|(i, key)| (key, i))
+        .collect::<Vec<_>>();
+    let null_keys = indices.iter().filter(|(key, _)| key.is_none()).count();
+    let cmp = |(a, _): &(Option<F::Key>, usize), (b, _): &(Option<F::Key>, usize)| match (a, b) {
+        (Some(a), Some(b)) => match dir {
+            Direction::Ascending => a.cmp(b),
+            Direction::Descending => b.cmp(a),
+        },
+        _ => null_ordering(a.is_none(), b.is_none(), nulls.nulls_first(dir)),
+    };
+    sort_group(algorithm, &mut indices, cmp);
+    apply_permutation(items, indices.into_iter().map(|(_, i)| i).collect());
+    null_keys
+}
+
+/// Compares by each field in turn until one differs.
+pub(crate) fn cmp_chain<T, C: Compare<T>>(chain: &[SortKey<C>], a: &T, b: &T) -> Ordering {
+    chain
+        .iter()
+        .map(|(field, dir, nulls)| cmp_by(field, *dir, *nulls, a, b))
+        .find(|ord| ord.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+fn cmp_by<T, C: Compare<T>>(
+    sort_by: &C,
+    dir: Direction,
+    nulls: NullHandling,
+    a: &T,
+    b: &T,
+) -> Ordering {
+    let partial = sort_by.compare(a, b);
+    partial.map_or_else(
+        || {
+            let a_is_null = is_null(sort_by, a);
+            let b_is_null = is_null(sort_by, b);
+            null_ordering(a_is_null, b_is_null, nulls.nulls_first(dir))
+        },
+        // Reversal must be applied per item to avoid ordering NULLs
+        |o| match dir {
+            Direction::Ascending => o,
+            Direction::Descending => o.reverse(),
+        },
+    )
+}
+
+/// Returns true if the item's field is `NULL`.
+pub(crate) fn is_null<T, C: Compare<T>>(field: &C, item: &T) -> bool {
+    field.compare(item, item).is_none()
+}
+
+/// Orders two items where at least one is `NULL`.
+fn null_ordering(a_is_null: bool, b_is_null: bool, nulls_first: bool) -> Ordering {
+    let null = if nulls_first {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    };
+    match (a_is_null, b_is_null) {
+        (true, true) => Ordering::Equal,
+        (true, false) => null,
+        (false, true) => null.reverse(),
+        // The comparison should not have returned None. Ordering as equal keeps the sort going in release builds
+        (false, false) => {
+            debug_assert!(
+                false,
+                "partial_cmp_by returned None for two values that aren't NULL. An item is NULL only if comparing it with itself returns None. Use check_partial_ord_by to find them"
+            );
+            Ordering::Equal
+        }
+    }
+}
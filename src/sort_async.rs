@@ -0,0 +1,228 @@
+use crate::sort::{cmp_chain, is_null, partition_nulls, sort_chain};
+use crate::{NullHandling, PartialOrdBy, Sortable, UseSorter};
+use std::cmp::Ordering;
+
+/// Number of indices sorted or merged between yields by [`UseSorter::sort_async`]. Large enough that yielding doesn't dominate, small enough that each step takes a few milliseconds.
+const CHUNK_SIZE: usize = 16_384;
+
+impl<F> UseSorter<F> {
+    /// Returns the indices of items in sorted order like [`Self::sort_indices`] but yields between chunks of work so that sorting many items doesn't block the UI. Apply the order with e.g., `indices.iter().map(|&i| &items[i])`.
+    ///
+    /// Items are sorted in chunks which are then merged, so the sort is always stable whatever the [`crate::SortAlgorithm`]. With the `web` feature each yield waits for the browser's next task so it may render and handle input. Otherwise yields to the async executor.
+    ///
+    /// Call from a future e.g., in `use_resource` so the sort reruns when the sorter changes. See [`Self::sort_incremental`] to show progress.
+    pub async fn sort_async<T: 'static>(&self, items: &[T]) -> Vec<usize>
+    where
+        F: Clone + PartialOrdBy<T> + Sortable,
+    {
+        self.sort_incremental(items, |_| ()).await
+    }
+
+    /// Sorts like [`Self::sort_async`], reporting progress from 0.0 to 1.0 between chunks of work e.g., to show a progress bar or spinner while sorting hundreds of thousands of rows. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Row { value: f64 }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum RowField { #[default] Value }
+    /// # impl PartialOrdBy<Row> for RowField {
+    /// #     fn partial_cmp_by(&self, a: &Row, b: &Row) -> Option<std::cmp::Ordering> { a.value.partial_cmp(&b.value) }
+    /// # }
+    /// # impl Sortable for RowField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// #[component]
+    /// fn Rows(rows: ReadOnlySignal<Vec<Row>>) -> Element {
+    ///     let sorter = use_sorter::<RowField>();
+    ///     let mut progress = use_signal(|| 0.0);
+    ///     let sorted = use_resource(move || async move {
+    ///         let rows = rows.read();
+    ///         sorter.sort_incremental(&rows, |done| progress.set(done)).await
+    ///     });
+    ///     let sorted = sorted.cloned();
+    ///     match sorted {
+    ///         None => rsx! { progress { value: "{progress}" } },
+    ///         Some(indices) => rsx! {
+    ///             for index in indices {
+    ///                 p { "{rows.read()[index].value}" }
+    ///             }
+    ///         },
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Progress is the share of comparisons made so isn't reported while the sort state or `NULL` values are checked, which take a single pass.
+    pub async fn sort_incremental<T: 'static>(
+        &self,
+        items: &[T],
+        mut progress: impl FnMut(f32),
+    ) -> Vec<usize>
+    where
+        F: Clone + PartialOrdBy<T> + Sortable,
+    {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        if !self.sorts_locally() {
+            return indices;
+        }
+        // Copied out so no signal is borrowed across yields
+        let (field, direction) = self.get_state();
+        let then_by = self.then_by();
+        let tie_breakers = field.tie_breakers();
+        let chain = self.compare_chain(sort_chain(&field, direction, &then_by, &tie_breakers));
+        let item = |index: usize| &items[index];
+        self.check_chain(&chain, item, items.len());
+        if self.null_handling(&field) == NullHandling::Remove {
+            indices.retain(|&index| !is_null(&chain[0].0, item(index)));
+        }
+        let (mid, front_chain, back_chain) = partition_nulls(&chain, item, &mut indices);
+        let (front, back) = indices.split_at_mut(mid);
+        let front_work = work(front.len(), CHUNK_SIZE);
+        let total = (front_work + work(back.len(), CHUNK_SIZE)).max(1) as f32;
+        let cmp_front = |a: &usize, b: &usize| cmp_chain(front_chain, item(*a), item(*b));
+        sort_chunked(front, CHUNK_SIZE, cmp_front, |done| {
+            progress(done as f32 / total)
+        })
+        .await;
+        let cmp_back = |a: &usize, b: &usize| cmp_chain(back_chain, item(*a), item(*b));
+        sort_chunked(back, CHUNK_SIZE, cmp_back, |done| {
+            progress((front_work + done) as f32 / total)
+        })
+        .await;
+        progress(1.0);
+        self.notify_sorted(&indices);
+        indices
+    }
+}
+
+/// Number of indices [`sort_chunked`] sorts or merges in total: each once when sorting chunks then once per merge pass.
+fn work(len: usize, chunk: usize) -> usize {
+    let mut passes = 1;
+    let mut width = chunk;
+    while width < len {
+        width *= 2;
+        passes += 1;
+    }
+    len * passes
+}
+
+/// Stable merge sort that yields after every `chunk` indices sorted or merged. Reports the number sorted or merged so far at each yield. See [`work`].
+async fn sort_chunked(
+    indices: &mut [usize],
+    chunk: usize,
+    cmp: impl Fn(&usize, &usize) -> Ordering,
+    mut progress: impl FnMut(usize),
+) {
+    let mut done = 0;
+    for run in indices.chunks_mut(chunk) {
+        run.sort_by(&cmp);
+        done += run.len();
+        progress(done);
+        yield_now().await;
+    }
+    let len = indices.len();
+    let mut merged = Vec::with_capacity(len);
+    let mut width = chunk;
+    while width < len {
+        for start in (0..len).step_by(2 * width) {
+            let mid = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut left, mut right) = (start, mid);
+            while left < mid && right < end {
+                // Take from the left on ties to keep the sort stable
+                if cmp(&indices[right], &indices[left]).is_lt() {
+                    merged.push(indices[right]);
+                    right += 1;
+                } else {
+                    merged.push(indices[left]);
+                    left += 1;
+                }
+                if merged.len() % chunk == 0 {
+                    progress(done + merged.len());
+                    yield_now().await;
+                }
+            }
+            merged.extend_from_slice(&indices[left..mid]);
+            merged.extend_from_slice(&indices[right..end]);
+            indices[start..end].copy_from_slice(&merged);
+            done += merged.len();
+            merged.clear();
+        }
+        progress(done);
+        width *= 2;
+    }
+}
+
+/// Waits for the browser's next task. A timeout rather than a resolved promise as microtasks run before rendering.
+#[cfg(feature = "web")]
+async fn yield_now() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+/// Gives other futures on the executor a turn.
+#[cfg(not(feature = "web"))]
+async fn yield_now() {
+    struct YieldNow(bool);
+
+    impl std::future::Future for YieldNow {
+        type Output = ();
+
+        fn poll(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<()> {
+            if self.0 {
+                return std::task::Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        }
+    }
+
+    YieldNow(false).await
+}
+
+#[cfg(all(test, not(feature = "web")))]
+mod tests {
+    use super::*;
+    use std::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    fn block_on<T>(future: impl Future<Output = T>) -> T {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_chunked() {
+        // Keys with ties, identified by position
+        let keys = [5, 3, 9, 3, 1, 5, 0, 3, 8, 1, 2];
+        for chunk in [1, 2, 3, 4, 64] {
+            let mut indices = (0..keys.len()).collect::<Vec<_>>();
+            let mut done = 0;
+            let cmp = |a: &usize, b: &usize| keys[*a].cmp(&keys[*b]);
+            block_on(sort_chunked(&mut indices, chunk, cmp, |at| {
+                assert!(at >= done, "chunk {chunk}");
+                done = at;
+            }));
+            let mut expected = (0..keys.len()).collect::<Vec<_>>();
+            expected.sort_by_key(|&i| keys[i]);
+            assert_eq!(indices, expected, "chunk {chunk}");
+            assert_eq!(done, work(keys.len(), chunk), "chunk {chunk}");
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use crate::sort::{sort_by, Compare};
+#[cfg(feature = "core")]
+use crate::{
+    sort::{sort_indices_by, SortKey},
+    Sortable, UseSorter,
+};
+use crate::{Direction, NullHandling, SortAlgorithm};
+use std::{cmp::Ordering, fmt, rc::Rc};
+
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> Option<Ordering>>;
+
+/// How a [`SortFields`] field compares rows.
+struct FieldFn<T, F> {
+    field: F,
+    compare: Comparator<T>,
+    /// Overrides the sorter's [`UseSorter::null_handling`] if set.
+    nulls: Option<NullHandling>,
+}
+
+impl<T, F: Clone> Clone for FieldFn<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            field: self.field.clone(),
+            compare: self.compare.clone(),
+            nulls: self.nulls,
+        }
+    }
+}
+
+impl<T, F> Compare<T> for FieldFn<T, F> {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        (self.compare)(a, b)
+    }
+}
+
+/// Describes how rows of `T` compare by each field using closures, in place of implementing [`crate::PartialOrdBy`]. Quick to set up for prototypes and small tables. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// #[derive(Clone, PartialEq)]
+/// struct Person {
+///     name: String,
+///     age: Option<u8>,
+/// }
+///
+/// #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// enum PersonField {
+///     #[default]
+///     Name,
+///     Age,
+///     Initials,
+/// }
+///
+/// // Still needed to describe how each field may be sorted
+/// impl Sortable for PersonField {
+///     fn sort_by(&self) -> Option<SortBy> {
+///         SortBy::increasing_or_decreasing()
+///     }
+/// }
+///
+/// #[component]
+/// fn People(people: Vec<Person>) -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     let fields = SortFields::<Person, PersonField>::new()
+///         .field(PersonField::Name, |p| &p.name)
+///         .field_opt(PersonField::Age, |p| p.age.as_ref())
+///         .nulls_first()
+///         .field_key(PersonField::Initials, |p| p.name.chars().next());
+///     let mut people = people;
+///     fields.sort(&sorter, &mut people);
+///     rsx! {
+///         // Render with `Th` and `sorter` as usual
+///     }
+/// }
+/// ```
+///
+/// Fields without a closure compare as equal. [`Sortable::tie_breakers`] aren't used: add secondary sorts with [`UseSorter::add_sort`] instead. Build it each render or keep it in a hook; either is cheap.
+pub struct SortFields<T, F> {
+    fields: Vec<FieldFn<T, F>>,
+}
+
+impl<T, F: Clone> Clone for SortFields<T, F> {
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+        }
+    }
+}
+
+impl<T, F: fmt::Debug> fmt::Debug for SortFields<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fields = self.fields.iter().map(|field| &field.field);
+        f.debug_struct("SortFields")
+            .field("fields", &fields.collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F> Default for SortFields<T, F> {
+    fn default() -> Self {
+        Self { fields: Vec::new() }
+    }
+}
+
+impl<T: 'static, F: Copy + PartialEq> SortFields<T, F> {
+    /// Creates an empty set of fields. Add them with [`Self::field`] and friends.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates fields compared by one fn given the field and both rows, like a [`crate::PartialOrdBy`] impl written as a closure. Handy when moving over an existing `match` on the field. For example:
+    /// ```rust
+    /// # use dioxus_sortable::*;
+    /// # use std::cmp::Ordering;
+    /// # #[derive(Copy, Clone, Debug, PartialEq)]
+    /// # enum PersonField { Name, Age }
+    /// struct Person {
+    ///     name: String,
+    ///     age: u8,
+    /// }
+    ///
+    /// let fields = SortFields::from_fn([PersonField::Name, PersonField::Age], |field, a: &Person, b| {
+    ///     match field {
+    ///         PersonField::Name => a.name.partial_cmp(&b.name),
+    ///         PersonField::Age => a.age.partial_cmp(&b.age),
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Returning `None` marks a row as `NULL`. Add or replace fields afterwards with [`Self::field`] and friends.
+    pub fn from_fn(
+        fields: impl IntoIterator<Item = F>,
+        compare: impl Fn(&F, &T, &T) -> Option<Ordering> + 'static,
+    ) -> Self
+    where
+        F: 'static,
+    {
+        let compare = Rc::new(compare);
+        fields.into_iter().fold(Self::new(), |fields, field| {
+            let compare = Rc::clone(&compare);
+            fields.field_by(field, move |a, b| compare(&field, a, b))
+        })
+    }
+
+    /// Compares a field by a reference to its value e.g., `|p| &p.name`. Replaces any earlier closure for the field.
+    pub fn field<K: PartialOrd + ?Sized>(
+        self,
+        field: F,
+        value: impl for<'a> Fn(&'a T) -> &'a K + 'static,
+    ) -> Self {
+        self.field_by(field, move |a, b| value(a).partial_cmp(value(b)))
+    }
+
+    /// Compares a field by an optional value where `None` is `NULL` e.g., `|p| p.age.as_ref()`. Unlike comparing `Option`s directly, `None` is ordered by [`NullHandling`] rather than before `Some`.
+    pub fn field_opt<K: PartialOrd + ?Sized>(
+        self,
+        field: F,
+        value: impl for<'a> Fn(&'a T) -> Option<&'a K> + 'static,
+    ) -> Self {
+        self.field_by(field, move |a, b| value(a)?.partial_cmp(value(b)?))
+    }
+
+    /// Compares a field by a value computed from the row e.g., `|p| p.name.len()`. Computed on every comparison so keep it cheap or see [`crate::ComputedField`].
+    pub fn field_key<K: PartialOrd>(self, field: F, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.field_by(field, move |a, b| key(a).partial_cmp(&key(b)))
+    }
+
+    /// Compares a field with a total order returning a plain [`Ordering`] e.g., a closure from existing [`slice::sort_by`] code or [`f64::total_cmp`]. Nothing is `NULL`. See [`crate::comparators::total`].
+    pub fn field_cmp(self, field: F, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.field_by(field, move |a, b| Some(compare(a, b)))
+    }
+
+    /// Compares a field with a fn like [`crate::PartialOrdBy::partial_cmp_by`]. Returning `None` marks a row as `NULL`. See [`crate::comparators`].
+    pub fn field_by(
+        mut self,
+        field: F,
+        compare: impl Fn(&T, &T) -> Option<Ordering> + 'static,
+    ) -> Self {
+        self.fields.retain(|other| other.field != field);
+        self.fields.push(FieldFn {
+            field,
+            compare: Rc::new(compare),
+            nulls: None,
+        });
+        self
+    }
+
+    /// Orders the last added field's `NULL` values first, like SQL's `NULLS FIRST`. Overrides [`Sortable::null_handling`]. See [`Self::with_null_handling`].
+    pub fn nulls_first(self) -> Self {
+        self.with_null_handling(NullHandling::First)
+    }
+
+    /// Orders the last added field's `NULL` values last, like SQL's `NULLS LAST`. See [`Self::with_null_handling`].
+    pub fn nulls_last(self) -> Self {
+        self.with_null_handling(NullHandling::Last)
+    }
+
+    /// Sets how the last added field orders `NULL` values, overriding [`Sortable::null_handling`] and [`UseSorter::set_null_handling`]. Does nothing if no field has been added.
+    pub fn with_null_handling(mut self, nulls: NullHandling) -> Self {
+        if let Some(last) = self.fields.last_mut() {
+            last.nulls = Some(nulls);
+        }
+        self
+    }
+
+    /// Returns how a field compares rows, if set.
+    fn get(&self, field: &F) -> Option<&FieldFn<T, F>> {
+        self.fields.iter().find(|other| other.field == *field)
+    }
+
+    /// Sorts items by a single field outside a component e.g., on the server or in tests, like [`crate::sort_slice_by`]. Uses the field's null handling if set, otherwise the default.
+    pub fn sort_slice(&self, field: F, dir: Direction, items: &mut [T]) {
+        if let Some(cmp) = self.get(&field) {
+            let nulls = cmp.nulls.unwrap_or_default();
+            sort_by(&[(cmp.clone(), dir, nulls)], SortAlgorithm::Stable, items);
+        }
+    }
+}
+
+#[cfg(feature = "core")]
+impl<T: 'static, F: Copy + PartialEq> SortFields<T, F> {
+    /// Builds the comparison chain for the sorter's [`UseSorter::sort_keys`].
+    fn chain(&self, sorter: &UseSorter<F>) -> Vec<SortKey<FieldFn<T, F>>>
+    where
+        F: Sortable,
+    {
+        sorter
+            .sort_keys()
+            .into_iter()
+            .filter_map(|(field, dir)| {
+                let cmp = self.get(&field)?.clone();
+                let nulls = cmp.nulls.unwrap_or_else(|| sorter.null_handling(&field));
+                Some((cmp, dir, nulls))
+            })
+            .collect()
+    }
+
+    /// Sorts items by the sorter's state like [`UseSorter::sort`]. Does nothing if unsorted or in [`crate::SortMode::Remote`].
+    pub fn sort(&self, sorter: &UseSorter<F>, items: &mut [T])
+    where
+        F: Sortable,
+    {
+        if sorter.sorts_locally() {
+            sort_by(&self.chain(sorter), sorter.algorithm(), items);
+        }
+    }
+
+    /// Returns the indices of items in sorted order without modifying them like [`UseSorter::sort_indices`].
+    pub fn sort_indices(&self, sorter: &UseSorter<F>, items: &[T]) -> Vec<usize>
+    where
+        F: Sortable,
+    {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        if sorter.sorts_locally() {
+            let item = |index: usize| &items[index];
+            sort_indices_by(&self.chain(sorter), sorter.algorithm(), item, &mut indices);
+        }
+        sorter.notify_sorted(&indices);
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum Field {
+        Name,
+        Score,
+        Len,
+    }
+
+    #[test]
+    fn test_sort_fields() {
+        let rows = [("bb", Some(2)), ("a", None), ("ccc", Some(1))];
+        let fields = SortFields::<(&str, Option<u8>), Field>::new()
+            .field(Field::Name, |row| row.0)
+            .field_opt(Field::Score, |row| row.1.as_ref())
+            .nulls_first()
+            .field_key(Field::Len, |row| row.0.len());
+        let sorted = |field, dir| {
+            let mut rows = rows;
+            fields.sort_slice(field, dir, &mut rows);
+            rows.map(|row| row.0)
+        };
+        assert_eq!(
+            sorted(Field::Name, Direction::Descending),
+            ["ccc", "bb", "a"]
+        );
+        assert_eq!(
+            sorted(Field::Score, Direction::Ascending),
+            ["a", "ccc", "bb"]
+        );
+        assert_eq!(sorted(Field::Len, Direction::Ascending), ["a", "bb", "ccc"]);
+
+        // Replaced
+        let fields = fields.field_key(Field::Name, |row| row.0.len() % 2);
+        let mut rows = rows;
+        fields.sort_slice(Field::Name, Direction::Ascending, &mut rows);
+        assert_eq!(rows.map(|row| row.0), ["bb", "a", "ccc"]);
+    }
+
+    #[test]
+    fn test_sort_fields_from_fn() {
+        let fields = SortFields::<&str, Field>::from_fn(
+            [Field::Name, Field::Len],
+            |field, a, b| match field {
+                Field::Len => a.len().partial_cmp(&b.len()),
+                _ => a.partial_cmp(b),
+            },
+        )
+        .field_cmp(Field::Score, |a, b| b.cmp(a));
+        let sorted = |field| {
+            let mut rows = ["bb", "ccc", "a"];
+            fields.sort_slice(field, Direction::Ascending, &mut rows);
+            rows
+        };
+        assert_eq!(sorted(Field::Name), ["a", "bb", "ccc"]);
+        assert_eq!(sorted(Field::Len), ["a", "bb", "ccc"]);
+        assert_eq!(sorted(Field::Score), ["ccc", "bb", "a"]);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{PartialOrdBy, Sortable, UseSorter};
+use std::iter::FusedIterator;
+
+/// Adds [`Self::sorted_by_sorter`] to iterators over references. Sorts within an iterator pipeline without cloning items e.g., after filtering props. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Person { name: String, age: u8 }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name }
+/// # impl PartialOrdBy<Person> for PersonField {
+/// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { a.name.partial_cmp(&b.name) }
+/// # }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Adults(people: Vec<Person>) -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     let adults = people
+///         .iter()
+///         .filter(|person| person.age >= 18)
+///         .sorted_by_sorter(&sorter);
+///     rsx! {
+///         for person in adults {
+///             p { "{person.name}" }
+///         }
+///     }
+/// }
+/// ```
+pub trait SortedBySorter<'a, T: 'a>: Iterator<Item = &'a T> + Sized {
+    /// Collects references to the items and yields them in the sorter's order. See [`UseSorter::sort_collection`].
+    fn sorted_by_sorter<F>(self, sorter: &UseSorter<F>) -> SortedBy<'a, T>
+    where
+        T: 'static,
+        F: PartialOrdBy<T> + Sortable,
+    {
+        SortedBy {
+            items: sorter.sort_collection(self).into_iter(),
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a T>> SortedBySorter<'a, T> for I {}
+
+/// Iterator over references to items in sorted order. Created by [`SortedBySorter::sorted_by_sorter`].
+///
+/// Like [`UseSorter::sort_indices`], `NULL` items are left out for [`crate::NullHandling::Remove`]. Items keep their iteration order if unsorted or in [`crate::SortMode::Remote`].
+#[derive(Clone, Debug)]
+pub struct SortedBy<'a, T> {
+    items: std::vec::IntoIter<&'a T>,
+}
+
+impl<'a, T> Iterator for SortedBy<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for SortedBy<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for SortedBy<'_, T> {}
+
+impl<T> FusedIterator for SortedBy<'_, T> {}
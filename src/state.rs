@@ -0,0 +1,354 @@
+use crate::sort::{is_null, sort_by, sort_chain, sort_indices_by, sortable_by};
+#[cfg(feature = "core")]
+use crate::UseSorter;
+use crate::{Direction, NullHandling, PartialOrdBy, SortAlgorithm, SortState, Sortable};
+use std::fmt;
+
+/// A snapshot of sort state. Created by [`UseSorter::save`] and applied with [`UseSorter::restore`].
+///
+/// With the `serde` feature this can be serialised e.g., to persist each user's table state on a server.
+///
+/// A plain value rather than signals so it compares, hashes and copies cheaply. Use it as a `use_memo`, `use_effect` or `use_resource` dependency with `use_reactive!`, as a prop or as a cache key. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Caption(state: SorterState<PersonField>) -> Element {
+///     // Recomputed only when the sort changes
+///     let caption = use_memo(use_reactive!(|state| match state.sorted {
+///         true => format!("Sorted by {:?} {:?}", state.field, state.direction),
+///         false => "Unsorted".to_string(),
+///     }));
+///     rsx! { caption { "{caption}" } }
+/// }
+///
+/// #[component]
+/// fn People() -> Element {
+///     let sorter = use_sorter::<PersonField>();
+///     rsx! {
+///         table {
+///             Caption { state: sorter.save() }
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`UseSorter`] itself compares by identity so can't tell states apart: pass [`UseSorter::save`] where a dependency should change with the sort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SorterState<F> {
+    /// Field being sorted by.
+    pub field: F,
+    /// Direction of the sort.
+    pub direction: Direction,
+    /// False if toggled back to the unsorted (original) order. See [`UseSorter::is_sorted`].
+    pub sorted: bool,
+}
+
+/// Why sort state was rejected. Returned by [`SorterState::validate`], [`crate::UseSorterBuilder::validate`] and [`UseSorter::try_restore`] in place of silently correcting it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum InvalidSort<F> {
+    /// The field can't be sorted by. See [`crate::SortBy::unsortable`].
+    Unsortable(F),
+    /// The field can't be sorted in the direction e.g., a [`crate::SortBy::Fixed`] field in its other direction.
+    Direction(F, Direction),
+}
+
+impl<F: fmt::Debug> fmt::Display for InvalidSort<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsortable(field) => write!(f, "field {field:?} is not sortable"),
+            Self::Direction(field, direction) => {
+                write!(f, "field {field:?} can't be sorted {direction:?}")
+            }
+        }
+    }
+}
+
+impl<F: fmt::Debug> std::error::Error for InvalidSort<F> {}
+
+impl<F: Copy + Sortable> SorterState<F> {
+    /// Checks the field is sortable in the direction. Useful for state from untrusted sources such as URLs where [`UseSorter::restore`]'s silent corrections would hide mistakes.
+    ///
+    /// `sorted: false` is always valid: fields that can't be unsorted still start unsorted, like [`crate::UseSorterBuilder::initially_unsorted`].
+    pub fn validate(&self) -> Result<(), InvalidSort<F>> {
+        let Some(sort_by) = sortable_by(&self.field) else {
+            return Err(InvalidSort::Unsortable(self.field));
+        };
+        if sort_by.ensure_direction(self.direction) != self.direction {
+            return Err(InvalidSort::Direction(self.field, self.direction));
+        }
+        Ok(())
+    }
+
+    /// Changes the state as clicking the field's header would, like [`UseSorter::toggle_field`] but without a hook e.g., on a server following a "sort by" link. Ignores unsortable and [`crate::SortBy::GroupOnly`] fields.
+    pub fn toggle(&mut self, field: F) {
+        let Some(sort_by) = sortable_by(&field) else {
+            return;
+        };
+        let current = (self.field == field).then_some(match self.sorted {
+            true => SortState::Sorted(self.direction),
+            false => SortState::Unsorted,
+        });
+        match sort_by.next_state(current) {
+            Some(SortState::Sorted(direction)) => {
+                *self = Self {
+                    field,
+                    direction,
+                    sorted: true,
+                }
+            }
+            Some(SortState::Unsorted) => {
+                self.field = field;
+                self.sorted = false;
+            }
+            None => (),
+        }
+    }
+
+    /// Sorts items by the state without a hook e.g., on a server given state sent by the browser, so both order rows alike. Compares like [`UseSorter::sort`] with each field's [`Sortable::null_handling`] and [`Sortable::tie_breakers`]. Does nothing if unsorted.
+    ///
+    /// Only the sorter's state is shared so runtime settings such as [`UseSorter::with_comparator`] don't apply. For example:
+    /// ```rust
+    /// # use dioxus_sortable::{Direction, PartialOrdBy, SortBy, Sortable, SorterState};
+    /// # use std::cmp::Ordering;
+    /// #[derive(Copy, Clone, Debug, PartialEq)]
+    /// enum AgeField {
+    ///     Age,
+    /// }
+    ///
+    /// impl PartialOrdBy<u32> for AgeField {
+    ///     fn partial_cmp_by(&self, a: &u32, b: &u32) -> Option<Ordering> {
+    ///         a.partial_cmp(b)
+    ///     }
+    /// }
+    ///
+    /// impl Sortable for AgeField {
+    ///     fn sort_by(&self) -> Option<SortBy> {
+    ///         SortBy::increasing_or_decreasing()
+    ///     }
+    /// }
+    ///
+    /// // e.g., deserialised from a request
+    /// let state = SorterState { field: AgeField::Age, direction: Direction::Descending, sorted: true };
+    /// let mut ages = [28, 42, 32];
+    /// state.sort(&mut ages);
+    /// assert_eq!(ages, [42, 32, 28]);
+    /// ```
+    pub fn sort<T>(&self, items: &mut [T])
+    where
+        F: PartialOrdBy<T>,
+    {
+        if !self.sorted {
+            return;
+        }
+        let tie_breakers = self.field.tie_breakers();
+        let chain = sort_chain(&self.field, self.direction, &[], &tie_breakers);
+        sort_by(&chain, SortAlgorithm::Stable, items);
+    }
+
+    /// Returns the indices of items in sorted order like [`UseSorter::sort_indices`] but without a hook. `NULL` items are left out for [`NullHandling::Remove`]. See [`Self::sort`].
+    pub fn sort_indices<T>(&self, items: &[T]) -> Vec<usize>
+    where
+        F: PartialOrdBy<T>,
+    {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        if !self.sorted {
+            return indices;
+        }
+        let tie_breakers = self.field.tie_breakers();
+        let chain = sort_chain(&self.field, self.direction, &[], &tie_breakers);
+        let item = |index: usize| &items[index];
+        if chain[0].2 == NullHandling::Remove {
+            indices.retain(|&index| !is_null(&chain[0].0, item(index)));
+        }
+        sort_indices_by(&chain, SortAlgorithm::Stable, item, &mut indices);
+        indices
+    }
+}
+
+#[cfg(feature = "core")]
+impl<F> UseSorter<F> {
+    /// Returns a snapshot of the current state. Subscribes the current component to changes like [`Self::get_state`]. See [`SorterState`] for using it as a hook dependency.
+    pub fn save(&self) -> SorterState<F>
+    where
+        F: Clone,
+    {
+        let (field, direction) = self.get_state();
+        SorterState {
+            field,
+            direction,
+            sorted: self.is_sorted(),
+        }
+    }
+
+    /// Sets the state from a snapshot, firing any [`Self::on_change`] callback. Like [`Self::set_field`], ignores unsortable fields and invalid directions. Ignores `sorted: false` if the field can't be unsorted.
+    pub fn restore(&self, state: SorterState<F>)
+    where
+        F: Copy + Sortable,
+    {
+        let Some(sort_by) = sortable_by(&state.field) else {
+            return;
+        };
+        let direction = sort_by.ensure_direction(state.direction);
+        let sorted = state.sorted || !sort_by.can_reset();
+        self.update(state.field, direction, sorted);
+    }
+
+    /// Sets the state from a snapshot like [`Self::restore`] but returns an error rather than correcting invalid state. Leaves the state unchanged on error. See [`SorterState::validate`].
+    pub fn try_restore(&self, state: SorterState<F>) -> Result<(), InvalidSort<F>>
+    where
+        F: Copy + Sortable,
+    {
+        state.validate()?;
+        self.restore(state);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SortBy;
+
+    #[test]
+    fn test_validate() {
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        enum Field {
+            Name,
+            Newest,
+            Notes,
+        }
+
+        impl Sortable for Field {
+            fn sort_by(&self) -> Option<SortBy> {
+                match self {
+                    Self::Name => SortBy::increasing_or_decreasing(),
+                    Self::Newest => SortBy::decreasing(),
+                    Self::Notes => SortBy::unsortable(),
+                }
+            }
+        }
+
+        let state = |field, direction| SorterState {
+            field,
+            direction,
+            sorted: true,
+        };
+        assert_eq!(state(Field::Name, Direction::Descending).validate(), Ok(()));
+        assert_eq!(
+            state(Field::Newest, Direction::Descending).validate(),
+            Ok(())
+        );
+        assert_eq!(
+            state(Field::Newest, Direction::Ascending).validate(),
+            Err(InvalidSort::Direction(Field::Newest, Direction::Ascending))
+        );
+        let err = state(Field::Notes, Direction::Ascending).validate();
+        assert_eq!(err, Err(InvalidSort::Unsortable(Field::Notes)));
+        assert_eq!(err.unwrap_err().to_string(), "field Notes is not sortable");
+    }
+
+    #[test]
+    fn test_headless() {
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        enum Field {
+            Value,
+        }
+
+        impl PartialOrdBy<f64> for Field {
+            fn partial_cmp_by(&self, a: &f64, b: &f64) -> Option<std::cmp::Ordering> {
+                a.partial_cmp(b)
+            }
+        }
+
+        impl Sortable for Field {
+            fn sort_by(&self) -> Option<SortBy> {
+                Some(SortBy::ReversibleWithReset(Direction::Descending))
+            }
+
+            fn null_handling(&self) -> NullHandling {
+                NullHandling::Remove
+            }
+        }
+
+        let mut state = SorterState {
+            field: Field::Value,
+            direction: Direction::Ascending,
+            sorted: false,
+        };
+        state.toggle(Field::Value);
+        assert_eq!(
+            (state.direction, state.sorted),
+            (Direction::Descending, true)
+        );
+        let items = [1.0, f64::NAN, 3.0, 2.0];
+        assert_eq!(state.sort_indices(&items), [2, 3, 0]);
+
+        state.toggle(Field::Value);
+        state.toggle(Field::Value);
+        assert!(!state.sorted);
+        let mut unsorted = items;
+        state.sort(&mut unsorted);
+        assert_eq!(unsorted[..1], [1.0]);
+    }
+
+    #[test]
+    fn test_state_as_key() {
+        use std::collections::HashSet;
+
+        let state = |direction, sorted| SorterState {
+            field: "name",
+            direction,
+            sorted,
+        };
+        let seen = HashSet::from([
+            state(Direction::Ascending, true),
+            state(Direction::Descending, true),
+            state(Direction::Ascending, true),
+        ]);
+        assert_eq!(seen.len(), 2);
+        assert!(seen.contains(&state(Direction::Descending, true)));
+        assert!(!seen.contains(&state(Direction::Descending, false)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use crate::{NullHandling, SortState};
+
+        let state = SorterState {
+            field: "name".to_string(),
+            direction: Direction::Descending,
+            sorted: false,
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"field":"name","direction":"Descending","sorted":false}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<SorterState<String>>(&json).unwrap(),
+            state
+        );
+
+        let nulls = serde_json::to_string(&NullHandling::Remove).unwrap();
+        assert_eq!(
+            serde_json::from_str::<NullHandling>(&nulls).unwrap(),
+            NullHandling::Remove
+        );
+        let sort_by = SortBy::ReversibleWithReset(Direction::Ascending);
+        let json = serde_json::to_string(&sort_by).unwrap();
+        assert_eq!(serde_json::from_str::<SortBy>(&json).unwrap(), sort_by);
+        // Cycles are 'static so may only be serialized
+        let cycle = SortBy::Cycle(&[SortState::Unsorted]);
+        let json = serde_json::to_string(&cycle).unwrap();
+        assert!(serde_json::from_str::<SortBy>(&json).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+use crate::Direction;
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Saves sort state to the browser's `localStorage`. Created by [`crate::UseSorterBuilder::with_storage_key`].
+///
+/// Holds a save fn so that [`crate::UseSorter`] doesn't need `F: Display` everywhere.
+pub(crate) struct Storage<F> {
+    key: &'static str,
+    save: fn(&str, &F, Direction),
+}
+
+impl<F: Display> Storage<F> {
+    pub(crate) fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            save: save::<F>,
+        }
+    }
+}
+
+impl<F> Storage<F> {
+    pub(crate) fn save(&self, field: &F, direction: Direction) {
+        (self.save)(self.key, field, direction)
+    }
+}
+
+impl<F> Clone for Storage<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for Storage<F> {}
+
+impl<F> PartialEq for Storage<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<F> fmt::Debug for Storage<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Storage").field("key", &self.key).finish()
+    }
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Loads a previously saved field and direction. Returns `None` if missing or unparseable.
+pub(crate) fn load<F: FromStr>(key: &str) -> Option<(F, Direction)> {
+    let value = local_storage()?.get_item(key).ok()??;
+    // Field names may contain our separator, the direction never does
+    let (field, direction) = value.rsplit_once(':')?;
+    Some((field.parse().ok()?, Direction::from_param(direction)?))
+}
+
+fn save<F: Display>(key: &str, field: &F, direction: Direction) {
+    if let Some(storage) = local_storage() {
+        // Storage may be full or disabled. Not worth failing over.
+        let _ = storage.set_item(key, &format!("{field}:{}", direction.as_param()));
+    }
+}
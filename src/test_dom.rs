@@ -0,0 +1,21 @@
+//! Shared fixture for unit tests of hooks.
+
+use dioxus::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+type MountProps<H> = (Rc<dyn Fn() -> H>, Rc<RefCell<Option<H>>>);
+
+fn root<H: 'static>((hooks, seen): MountProps<H>) -> Element {
+    *seen.borrow_mut() = Some(hooks());
+    rsx! {}
+}
+
+/// Runs hooks in the root component of a virtual DOM, returning the DOM and what the hooks returned. Use them within [`VirtualDom::in_runtime`].
+pub(crate) fn mount<H: Clone + 'static>(hooks: impl Fn() -> H + 'static) -> (VirtualDom, H) {
+    let seen = Rc::new(RefCell::new(None));
+    let props: MountProps<H> = (Rc::new(hooks), Rc::clone(&seen));
+    let mut dom = VirtualDom::new_with_props(root::<H>, props);
+    dom.rebuild_in_place();
+    let hooks = seen.borrow().clone().expect("root rendered");
+    (dom, hooks)
+}
@@ -0,0 +1,330 @@
+//! Helpers for testing sortable tables without a browser. Requires the `test-utils` feature, usually as a dev-dependency:
+//! ```toml
+//! [dev-dependencies]
+//! dioxus-sortable = { version = "*", features = ["test-utils"] }
+//! ```
+//!
+//! [`ThHarness`] mounts a [`crate::Th`] per field in a virtual DOM, clicks them as a user would and reports the resulting [`SorterState`]. For example:
+//! ```rust
+//! # use dioxus::prelude::*;
+//! # use dioxus_sortable::{test_utils::ThHarness, *};
+//! #[derive(Copy, Clone, Debug, Default, PartialEq)]
+//! enum PersonField {
+//!     #[default]
+//!     Name,
+//!     Age,
+//! }
+//! # impl Sortable for PersonField {
+//! #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+//! # }
+//!
+//! let mut harness = ThHarness::new([PersonField::Name, PersonField::Age]);
+//! harness.click(PersonField::Age);
+//! harness.assert_sorted_by(PersonField::Age, Direction::Ascending);
+//! assert_eq!(harness.aria_sort(PersonField::Age), Some("ascending"));
+//!
+//! // Shift-click adds a secondary sort
+//! harness.click_with(PersonField::Name, Modifiers::SHIFT);
+//! assert_eq!(
+//!     harness.sort_keys(),
+//!     vec![(PersonField::Age, Direction::Ascending), (PersonField::Name, Direction::Ascending)]
+//! );
+//! ```
+//!
+//! The harness runs on any target so works with `#[wasm_bindgen_test]` as well as `#[test]`.
+
+use crate::{Direction, PartialOrdBy, Sortable, SorterState, Th, UseSorter, UseSorterBuilder};
+use dioxus::{
+    dioxus_core::{AttributeValue, ElementId, Mutation, Mutations},
+    prelude::*,
+};
+use dioxus_html::{
+    geometry::Coordinates, input_data::MouseButton, PlatformEventData,
+    SerializedHtmlEventConverter, SerializedMouseData,
+};
+use std::{any::Any, cell::Cell, collections::HashMap, fmt, rc::Rc};
+
+/// Mounts a row of [`crate::Th`] headers sharing a [`UseSorter`] in a virtual DOM and simulates clicking them. See the [module docs](self).
+///
+/// Sets Dioxus' global event converter so create harnesses in tests only, not alongside a renderer.
+pub struct ThHarness<F: 'static> {
+    dom: VirtualDom,
+    sorter: Rc<Cell<Option<UseSorter<F>>>>,
+    fields: Vec<F>,
+    /// Element of each field's header, in the order of `fields`.
+    headers: Vec<ElementId>,
+    /// Latest `aria-sort` attribute of each header.
+    aria_sort: HashMap<ElementId, String>,
+}
+
+impl<F: fmt::Debug> fmt::Debug for ThHarness<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThHarness")
+            .field("fields", &self.fields)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Root props of a [`ThHarness`].
+struct HarnessProps<F: 'static> {
+    builder: UseSorterBuilder<F>,
+    fields: Vec<F>,
+    sorter: Rc<Cell<Option<UseSorter<F>>>>,
+}
+
+impl<F: Clone> Clone for HarnessProps<F> {
+    fn clone(&self) -> Self {
+        Self {
+            builder: self.builder.clone(),
+            fields: self.fields.clone(),
+            sorter: self.sorter.clone(),
+        }
+    }
+}
+
+fn harness_root<F: Copy + Default + fmt::Debug + Sortable + 'static>(
+    props: HarnessProps<F>,
+) -> Element {
+    let sorter = props.builder.use_sorter();
+    props.sorter.set(Some(sorter));
+    rsx! {
+        table {
+            thead {
+                tr {
+                    for field in props.fields {
+                        Th { sorter: sorter, field: field, "{field:?}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<F: Copy + Default + fmt::Debug + Sortable + 'static> ThHarness<F> {
+    /// Mounts a header per field with a sorter from [`crate::use_sorter()`].
+    pub fn new(fields: impl IntoIterator<Item = F>) -> Self {
+        Self::with_builder(UseSorterBuilder::default(), fields)
+    }
+
+    /// Mounts a header per field with a sorter from the builder e.g., to start on a field or unsorted.
+    pub fn with_builder(builder: UseSorterBuilder<F>, fields: impl IntoIterator<Item = F>) -> Self {
+        dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+        let sorter = Rc::new(Cell::new(None));
+        let fields = fields.into_iter().collect::<Vec<_>>();
+        let props = HarnessProps {
+            builder,
+            fields: fields.clone(),
+            sorter: sorter.clone(),
+        };
+        let mut harness = Self {
+            dom: VirtualDom::new_with_props(harness_root::<F>, props),
+            sorter,
+            fields,
+            headers: Vec::new(),
+            aria_sort: HashMap::new(),
+        };
+        let mutations = harness.dom.rebuild_to_vec();
+        harness.apply(mutations);
+        harness
+    }
+
+    /// Clicks a field's header without modifier keys. Panics if the field wasn't mounted.
+    pub fn click(&mut self, field: F) {
+        self.click_with(field, Modifiers::empty());
+    }
+
+    /// Clicks a field's header holding modifier keys e.g., `Modifiers::SHIFT` to add a secondary sort. Panics if the field wasn't mounted.
+    pub fn click_with(&mut self, field: F, modifiers: Modifiers) {
+        let position = self.fields.iter().position(|f| *f == field);
+        let Some(&id) = position.and_then(|position| self.headers.get(position)) else {
+            panic!("no header for {field:?}");
+        };
+        let data = SerializedMouseData::new(
+            Some(MouseButton::Primary),
+            MouseButton::Primary.into(),
+            Coordinates::new(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+            modifiers,
+        );
+        let data: Rc<dyn Any> = Rc::new(PlatformEventData::new(Box::new(data)));
+        self.dom
+            .runtime()
+            .handle_event("click", Event::new(data, true), id);
+        self.render();
+    }
+
+    /// Returns the sorter shared by the headers. Read it with [`Self::in_runtime`].
+    pub fn sorter(&self) -> UseSorter<F> {
+        self.sorter.get().expect("rendered")
+    }
+
+    /// Runs a fn with the virtual DOM's runtime e.g., to read or change the sorter directly. Call [`Self::render`] after changing it.
+    pub fn in_runtime<O>(&self, f: impl FnOnce(UseSorter<F>) -> O) -> O {
+        let sorter = self.sorter();
+        self.dom.in_runtime(|| f(sorter))
+    }
+
+    /// Renders any pending changes e.g., made with [`Self::in_runtime`]. Clicks render for you.
+    pub fn render(&mut self) {
+        let mutations = self.dom.render_immediate_to_vec();
+        self.apply(mutations);
+    }
+
+    /// Returns the sorter's state. See [`UseSorter::save`].
+    pub fn state(&self) -> SorterState<F> {
+        self.in_runtime(|sorter| sorter.save())
+    }
+
+    /// Returns every field sorted by with its direction. See [`UseSorter::sort_keys`].
+    pub fn sort_keys(&self) -> Vec<(F, Direction)> {
+        self.in_runtime(|sorter| sorter.sort_keys())
+    }
+
+    /// Sorts items by the sorter's current state. See [`UseSorter::sort`].
+    pub fn sort<T: 'static>(&self, items: &mut [T])
+    where
+        F: PartialOrdBy<T>,
+    {
+        self.in_runtime(|sorter| sorter.sort(items));
+    }
+
+    /// Returns the rendered `aria-sort` attribute of a field's header: "ascending", "descending" or "none". `None` if the field wasn't mounted.
+    pub fn aria_sort(&self, field: F) -> Option<&str> {
+        let position = self.fields.iter().position(|f| *f == field)?;
+        let id = self.headers.get(position)?;
+        self.aria_sort.get(id).map(String::as_str)
+    }
+
+    /// Panics unless sorted by the field in the direction. Reports the actual state on failure.
+    #[track_caller]
+    pub fn assert_sorted_by(&self, field: F, direction: Direction) {
+        let state = self.state();
+        let expected = SorterState {
+            field,
+            direction,
+            sorted: true,
+        };
+        assert_eq!(state, expected, "sorter state");
+    }
+
+    /// Panics unless in the unsorted (original) order. See [`UseSorter::is_sorted`].
+    #[track_caller]
+    pub fn assert_unsorted(&self) {
+        let state = self.state();
+        assert!(!state.sorted, "expected unsorted, got {state:?}");
+    }
+
+    /// Records headers and their attributes from rendered mutations.
+    fn apply(&mut self, mutations: Mutations) {
+        for edit in mutations.edits {
+            match edit {
+                // Each header listens for clicks once, in field order
+                Mutation::NewEventListener { name, id }
+                    if name == "click" && !self.headers.contains(&id) =>
+                {
+                    self.headers.push(id);
+                }
+                Mutation::SetAttribute {
+                    name: "aria-sort",
+                    value: AttributeValue::Text(value),
+                    id,
+                    ..
+                } => {
+                    self.aria_sort.insert(id, value);
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SortBy, UseSorterBuilder};
+    use std::cmp::Ordering;
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum Field {
+        #[default]
+        Name,
+        Score,
+        Id,
+    }
+
+    impl PartialOrdBy<(&str, u8)> for Field {
+        fn partial_cmp_by(&self, a: &(&str, u8), b: &(&str, u8)) -> Option<Ordering> {
+            match self {
+                Field::Name => a.0.partial_cmp(b.0),
+                Field::Score => a.1.partial_cmp(&b.1),
+                Field::Id => None,
+            }
+        }
+    }
+
+    impl Sortable for Field {
+        fn sort_by(&self) -> Option<SortBy> {
+            match self {
+                Field::Name => SortBy::increasing_or_decreasing(),
+                Field::Score => SortBy::decreasing_increasing_or_unsorted(),
+                Field::Id => SortBy::unsortable(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_th_harness() {
+        let mut harness = ThHarness::new([Field::Name, Field::Score, Field::Id]);
+        harness.assert_sorted_by(Field::Name, Direction::Ascending);
+        assert_eq!(harness.aria_sort(Field::Name), Some("ascending"));
+        assert_eq!(harness.aria_sort(Field::Score), Some("none"));
+
+        // Cycles through directions then back to unsorted
+        harness.click(Field::Score);
+        harness.assert_sorted_by(Field::Score, Direction::Descending);
+        assert_eq!(harness.aria_sort(Field::Score), Some("descending"));
+        assert_eq!(harness.aria_sort(Field::Name), Some("none"));
+        let mut rows = [("b", 1), ("a", 3), ("c", 2)];
+        harness.sort(&mut rows);
+        assert_eq!(rows, [("a", 3), ("c", 2), ("b", 1)]);
+        harness.click(Field::Score);
+        harness.assert_sorted_by(Field::Score, Direction::Ascending);
+        harness.click(Field::Score);
+        harness.assert_unsorted();
+
+        // Unsortable fields are ignored
+        harness.click(Field::Id);
+        harness.assert_unsorted();
+
+        // Modifier keys add and remove secondary sorts
+        harness.click(Field::Name);
+        harness.click_with(Field::Score, Modifiers::SHIFT);
+        assert_eq!(
+            harness.sort_keys(),
+            vec![
+                (Field::Name, Direction::Ascending),
+                (Field::Score, Direction::Descending)
+            ]
+        );
+        harness.click_with(Field::Score, Modifiers::CONTROL);
+        assert_eq!(
+            harness.sort_keys(),
+            vec![(Field::Name, Direction::Ascending)]
+        );
+
+        // Changed directly
+        harness.in_runtime(|sorter| sorter.set_field(Field::Name, Direction::Descending));
+        harness.render();
+        assert_eq!(harness.aria_sort(Field::Name), Some("descending"));
+
+        let harness = ThHarness::with_builder(
+            UseSorterBuilder::default().with_field(Field::Score),
+            [Field::Name, Field::Score],
+        );
+        harness.assert_sorted_by(Field::Score, Direction::Descending);
+    }
+}
@@ -0,0 +1,132 @@
+use dioxus::prelude::*;
+
+/// Styling shared by [`crate::Th`], [`crate::ThStatus`] and [`crate::NullableCell`]. Provide one via Dioxus context to restyle every sortable table below it in one place:
+///
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::SorterTheme;
+/// #[component]
+/// fn App() -> Element {
+///     use_context_provider(|| SorterTheme {
+///         active_class: "sorted".to_string(),
+///         active_colour: String::new(),
+///         inactive_colour: String::new(),
+///         ..SorterTheme::default()
+///     });
+///     rsx! { /* Tables using Th */ }
+/// }
+/// ```
+///
+/// Components fall back to [`SorterTheme::default`] if there is no theme in context. Props such as [`crate::ThIcons`] take precedence over the theme.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SorterTheme {
+    /// Glyph for a field sorted (or only sortable) in ascending order. Defaults to "↓".
+    pub ascending: String,
+    /// Glyph for a field sorted (or only sortable) in descending order. Defaults to "↑".
+    pub descending: String,
+    /// Glyph for a field that can be sorted in either direction but is not active. Defaults to "↕".
+    pub neutral: String,
+    /// Glyph for a field only sortable in ascending order (see [`crate::SortBy::Fixed`]), so it can be told apart from a reversible field sorted ascending. Defaults to an outlined "⇩". Set to [`Self::ascending`] to show both alike.
+    pub fixed_ascending: String,
+    /// Glyph for a field only sortable in descending order. Defaults to an outlined "⇧". Set to [`Self::descending`] to show both alike.
+    pub fixed_descending: String,
+    /// Glyph for a field that can't be sorted, in the inactive colour e.g., "⊘". Empty by default to render nothing. See [`Self::reserve_unsortable`].
+    pub unsortable: String,
+    /// Reserves space for the glyph of fields that can't be sorted, with an invisible [`Self::neutral`] glyph, so mixed headers line up. Ignored if [`Self::unsortable`] is set. Defaults to false.
+    pub reserve_unsortable: bool,
+    /// Text colour of the active field's glyph. Defaults to "#555". Empty to leave unstyled.
+    pub active_colour: String,
+    /// Text colour of inactive fields' glyphs. Defaults to "#ccc". Empty to leave unstyled.
+    pub inactive_colour: String,
+    /// Class added to the active field's `<th>` and glyph. Empty by default.
+    pub active_class: String,
+    /// Class added to inactive fields' `<th>` and glyph. Empty by default.
+    pub inactive_class: String,
+    /// Class added to the priority badges [`crate::ThStatus`] shows when sorting by several fields. Badges always have a `th-priority` class. Empty by default.
+    pub priority_class: String,
+    /// Shows a priority badge (1, 2, 3…) after each sorted field's glyph when sorting by several fields. Defaults to true.
+    pub show_priority: bool,
+    /// Lays headers out right to left for Arabic, Hebrew and other RTL scripts. Set alongside `dir="rtl"` on the page or table. [`crate::Th`] puts the status glyph before its label, mirrors spacing and drags the resize grip leftwards to widen. The grip follows `dir` to the left edge. The default glyphs are vertical arrows so need no mirroring: keep custom glyphs direction-neutral too. Defaults to false. Override per header with the `rtl` prop of [`crate::Th`] and [`crate::ThStatus`].
+    pub rtl: bool,
+    /// Visually hidden text read by screen readers alongside the glyph. Set to localise.
+    pub labels: ThLabels,
+    /// Placeholder text for `NULL` cells rendered by [`crate::NullableCell`] and [`crate::render_nullable`], in an `<em>`. Defaults to "Unknown".
+    pub null_text: String,
+    /// Class added to the `<em>` placeholder of `NULL` cells. Empty by default.
+    pub null_class: String,
+}
+
+/// Visually hidden text rendered by [`crate::ThStatus`] for screen readers. Use to localise the sort state. Defaults to English. An empty string renders nothing for that state.
+///
+/// Set app-wide on the [`SorterTheme`] or per header with the `labels` prop of [`crate::Th`] and [`crate::ThStatus`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThLabels {
+    /// Field is the active field, sorted in ascending order. Defaults to "sorted ascending".
+    pub ascending: String,
+    /// Field is the active field, sorted in descending order. Defaults to "sorted descending".
+    pub descending: String,
+    /// Field is sortable but not the active field or the sorter is unsorted. Defaults to "not sorted".
+    pub unsorted: String,
+    /// Precedes a sorted field's priority when sorting by several fields e.g., "sorted ascending, priority 2". Defaults to "priority".
+    pub priority: String,
+}
+
+impl Default for ThLabels {
+    fn default() -> Self {
+        Self {
+            ascending: "sorted ascending".to_string(),
+            descending: "sorted descending".to_string(),
+            unsorted: "not sorted".to_string(),
+            priority: "priority".to_string(),
+        }
+    }
+}
+
+impl Default for SorterTheme {
+    fn default() -> Self {
+        Self {
+            ascending: "↓".to_string(),
+            descending: "↑".to_string(),
+            neutral: "↕".to_string(),
+            fixed_ascending: "⇩".to_string(),
+            fixed_descending: "⇧".to_string(),
+            unsortable: String::new(),
+            reserve_unsortable: false,
+            active_colour: "#555".to_string(),
+            inactive_colour: "#ccc".to_string(),
+            active_class: String::new(),
+            inactive_class: String::new(),
+            priority_class: String::new(),
+            show_priority: true,
+            rtl: false,
+            labels: ThLabels::default(),
+            null_text: "Unknown".to_string(),
+            null_class: String::new(),
+        }
+    }
+}
+
+impl SorterTheme {
+    /// Returns the colour for an active or inactive field.
+    pub(crate) fn colour(&self, active: bool) -> &str {
+        if active {
+            &self.active_colour
+        } else {
+            &self.inactive_colour
+        }
+    }
+
+    /// Returns the class for an active or inactive field.
+    pub(crate) fn class(&self, active: bool) -> &str {
+        if active {
+            &self.active_class
+        } else {
+            &self.inactive_class
+        }
+    }
+}
+
+/// Returns the theme from context or the default. A hook: must be called unconditionally.
+pub(crate) fn use_theme() -> SorterTheme {
+    try_use_context::<SorterTheme>().unwrap_or_default()
+}
@@ -0,0 +1,176 @@
+use crate::sort::apply_permutation;
+use crate::{PartialOrdBy, Sortable, UseSorter};
+
+/// A parent row and its child rows e.g., an order and its line items. Sorting by parent fields with [`UseSorter::sort_tree`] moves children with their parent. Sort the children within each parent with [`UseSorter::sort_children`].
+///
+/// Parents and children may be different types. Render children indented beneath their parent with [`crate::TreeCell`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeRow<P, C = P> {
+    /// The parent row.
+    pub parent: P,
+    /// Child rows, shown beneath the parent.
+    pub children: Vec<C>,
+}
+
+impl<P, C> TreeRow<P, C> {
+    /// Creates a parent row with its children.
+    pub fn new(parent: P, children: impl IntoIterator<Item = C>) -> Self {
+        Self {
+            parent,
+            children: children.into_iter().collect(),
+        }
+    }
+
+    /// Returns the number of rows including the parent.
+    pub fn row_count(&self) -> usize {
+        1 + self.children.len()
+    }
+
+    /// Returns true if the parent has no children.
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<F: Copy> UseSorter<F> {
+    /// Sorts parent rows by the sorter, keeping each parent's children with it and in their order. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Order { id: u32, total: f64 }
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Item { name: String, price: f64 }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum OrderField { #[default] Total }
+    /// # impl PartialOrdBy<Order> for OrderField {
+    /// #     fn partial_cmp_by(&self, a: &Order, b: &Order) -> Option<std::cmp::Ordering> { a.total.partial_cmp(&b.total) }
+    /// # }
+    /// # impl Sortable for OrderField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum ItemField { #[default] Price }
+    /// # impl PartialOrdBy<Item> for ItemField {
+    /// #     fn partial_cmp_by(&self, a: &Item, b: &Item) -> Option<std::cmp::Ordering> { a.price.partial_cmp(&b.price) }
+    /// # }
+    /// # impl Sortable for ItemField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// #[component]
+    /// fn Orders(orders: Vec<TreeRow<Order, Item>>) -> Element {
+    ///     let sorter = use_sorter::<OrderField>();
+    ///     let items = use_sorter::<ItemField>();
+    ///     let mut orders = orders;
+    ///     sorter.sort_tree(&mut orders);
+    ///     items.sort_children(&mut orders);
+    ///     rsx! {
+    ///         for order in orders {
+    ///             tr { key: "{order.parent.id}",
+    ///                 TreeCell { depth: 0, "Order {order.parent.id}" }
+    ///                 td { "{order.parent.total}" }
+    ///             }
+    ///             for item in order.children {
+    ///                 tr { class: "child-row",
+    ///                     TreeCell { depth: 1, "{item.name}" }
+    ///                     td { "{item.price}" }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Otherwise behaves like [`Self::sort`]. Parents are kept for [`crate::NullHandling::Remove`] so their children aren't lost.
+    pub fn sort_tree<P: 'static, C>(&self, rows: &mut [TreeRow<P, C>])
+    where
+        F: PartialOrdBy<P> + Sortable,
+    {
+        let mut indices = (0..rows.len()).collect::<Vec<_>>();
+        self.sort_indices_with(|index| &rows[index].parent, &mut indices, false);
+        apply_permutation(rows, indices);
+    }
+
+    /// Sorts each parent's children by the sorter, leaving parents in place. Use a second sorter over the children's fields alongside [`Self::sort_tree`] to sort both levels. Otherwise behaves like [`Self::sort`].
+    ///
+    /// Sorting every parent's children counts as one sort: [`Self::last_sort_stats`] and debug timings cover all children together.
+    pub fn sort_children<P, C: 'static>(&self, rows: &mut [TreeRow<P, C>])
+    where
+        F: PartialOrdBy<C> + Sortable,
+    {
+        let total = rows.iter().map(|row| row.children.len()).sum();
+        if !self.sorts_locally() {
+            self.record_sort(total, total, 0);
+            return;
+        }
+        let nulls = self.timed(total, || {
+            rows.iter_mut()
+                .map(|row| {
+                    let mut indices = (0..row.children.len()).collect::<Vec<_>>();
+                    let nulls = self.sort_indices_unrecorded(
+                        |index| &row.children[index],
+                        &mut indices,
+                        false,
+                    );
+                    apply_permutation(&mut row.children, indices);
+                    nulls
+                })
+                .sum()
+        });
+        self.record_sort(total, total, nulls);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_dom::mount, use_sorter, Direction, SortBy};
+    use std::cmp::Ordering;
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum Field {
+        #[default]
+        Value,
+    }
+
+    impl PartialOrdBy<(char, f64)> for Field {
+        fn partial_cmp_by(&self, a: &(char, f64), b: &(char, f64)) -> Option<Ordering> {
+            a.1.partial_cmp(&b.1)
+        }
+    }
+
+    impl Sortable for Field {
+        fn sort_by(&self) -> Option<SortBy> {
+            SortBy::increasing_or_decreasing()
+        }
+    }
+
+    #[test]
+    fn test_sort_tree() {
+        let (dom, (parents, children)) = mount(|| (use_sorter::<Field>(), use_sorter::<Field>()));
+        let mut rows = vec![
+            TreeRow::new(('a', 2.0), [('x', 3.0), ('y', 1.0)]),
+            TreeRow::new(('b', f64::NAN), [('z', 0.0)]),
+            TreeRow::new(('c', 1.0), [('w', f64::NAN)]),
+        ];
+        dom.in_runtime(|| {
+            parents.set_field(Field::Value, Direction::Descending);
+            parents.sort_tree(&mut rows);
+            children.sort_children(&mut rows);
+        });
+        let order = |rows: &[TreeRow<(char, f64)>]| {
+            rows.iter()
+                .flat_map(|row| {
+                    [row.parent.0]
+                        .into_iter()
+                        .chain(row.children.iter().map(|c| c.0))
+                })
+                .collect::<String>()
+        };
+        // Children travel with their parent, sorted by their own sorter
+        assert_eq!(order(&rows), "ayxcwbz");
+        // One sort across all parents' children
+        let stats = children.last_sort_stats().unwrap();
+        assert_eq!((stats.total, stats.visible, stats.nulls), (4, 4, 1));
+    }
+}
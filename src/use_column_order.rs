@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+
+/// Stores Dioxus hooks and state of the order columns are shown in. Columns are identified by the same field enum `F` used by [`crate::UseSorter`].
+///
+/// Pass to [`crate::Th`] in its [`crate::ThHooks`] to add a drag handle to each header. Dropping a header on another moves it to that column's place. Render headers and cells in [`Self::fields`] order so they stay in step.
+#[derive(Debug)]
+pub struct UseColumnOrder<F: 'static> {
+    fields: Signal<Vec<F>>,
+    initial: CopyValue<Vec<F>>,
+    drag: Signal<Option<F>>,
+}
+
+impl<F> Clone for UseColumnOrder<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for UseColumnOrder<F> {}
+
+impl<F> PartialEq for UseColumnOrder<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields && self.initial == other.initial && self.drag == other.drag
+    }
+}
+
+/// Creates Dioxus hooks to manage column order. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Columns start in the order of `fields`. Only the first value given is used.
+pub fn use_column_order<F: Copy + PartialEq + 'static>(fields: &[F]) -> UseColumnOrder<F> {
+    UseColumnOrder {
+        fields: use_signal(|| fields.to_vec()),
+        initial: use_hook(|| CopyValue::new(fields.to_vec())),
+        drag: use_signal(|| None),
+    }
+}
+
+impl<F: Copy + PartialEq> UseColumnOrder<F> {
+    /// Returns fields in their current order.
+    pub fn fields(&self) -> Vec<F> {
+        self.fields.cloned()
+    }
+
+    /// Returns the current position of a field's column.
+    pub fn position(&self, field: &F) -> Option<usize> {
+        self.fields.read().iter().position(|f| f == field)
+    }
+
+    /// Returns `fields` in the current order e.g., to order a subset of columns. Fields not given to [`use_column_order()`] keep their given order at the end.
+    pub fn order(&self, fields: &[F]) -> Vec<F> {
+        let known = self.fields.read();
+        let ordered = known.iter().filter(|field| fields.contains(field));
+        let unknown = fields.iter().filter(|field| !known.contains(field));
+        ordered.chain(unknown).copied().collect()
+    }
+
+    /// Moves a field's column to a position. Positions past the end move it last. Does nothing for unknown fields.
+    pub fn move_to(&self, field: F, position: usize) {
+        let Some(from) = self.position(&field) else {
+            return;
+        };
+        let mut fields = self.fields;
+        let mut fields = fields.write();
+        fields.remove(from);
+        let position = position.min(fields.len());
+        fields.insert(position, field);
+    }
+
+    /// Returns columns to their initial order.
+    pub fn reset(&self) {
+        let mut fields = self.fields;
+        fields.set(self.initial.peek().clone());
+    }
+
+    /// Returns the field being dragged, if any.
+    pub fn dragging(&self) -> Option<F> {
+        *self.drag.read()
+    }
+
+    /// Starts dragging a field's column. Used by [`crate::Th`]; call yourself to build your own handle.
+    pub fn start_drag(&self, field: F) {
+        let mut drag = self.drag;
+        drag.set(Some(field));
+    }
+
+    /// Drops the dragged column on `target`'s, moving it to that position. Does nothing if not dragging.
+    pub fn drop_on(&self, target: F) {
+        let Some(field) = *self.drag.peek() else {
+            return;
+        };
+        if let Some(position) = self.position(&target) {
+            self.move_to(field, position);
+        }
+        self.end_drag();
+    }
+
+    /// Stops dragging without moving.
+    pub fn end_drag(&self) {
+        let mut drag = self.drag;
+        if drag.peek().is_some() {
+            drag.set(None);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use dioxus::prelude::*;
+
+/// Narrowest width in pixels a column can be resized to.
+pub const MIN_COLUMN_WIDTH: f64 = 24.0;
+
+/// Stores Dioxus hooks and state of user-resized column widths. Columns are identified by the same field enum `F` used by [`crate::UseSorter`].
+///
+/// Pass to [`crate::Th`] in its [`crate::ThHooks`] to add a resize grip to each header. Widths are applied to the `<th>` so work best with `table-layout: fixed`. Columns without a width are left to the browser.
+#[derive(Debug)]
+pub struct UseColumnWidths<F: 'static> {
+    widths: Signal<Vec<(F, f64)>>,
+    drag: Signal<Option<Drag<F>>>,
+}
+
+/// An in-progress resize.
+#[derive(Debug)]
+struct Drag<F> {
+    field: F,
+    start_x: f64,
+    start_width: f64,
+}
+
+impl<F> Clone for UseColumnWidths<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for UseColumnWidths<F> {}
+
+impl<F> PartialEq for UseColumnWidths<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.widths == other.widths && self.drag == other.drag
+    }
+}
+
+/// Creates Dioxus hooks to manage column widths. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// All columns start at their natural width.
+pub fn use_column_widths<F: PartialEq + 'static>() -> UseColumnWidths<F> {
+    UseColumnWidths {
+        widths: use_signal(Vec::new),
+        drag: use_signal(|| None),
+    }
+}
+
+impl<F: Copy + PartialEq> UseColumnWidths<F> {
+    /// Returns the field's width in pixels. Returns `None` if the column hasn't been resized.
+    pub fn width(&self, field: &F) -> Option<f64> {
+        self.widths
+            .read()
+            .iter()
+            .find(|(f, _)| f == field)
+            .map(|(_, width)| *width)
+    }
+
+    /// Sets the field's width in pixels. Widths below [`MIN_COLUMN_WIDTH`] are raised to it.
+    pub fn set_width(&self, field: F, width: f64) {
+        let width = width.max(MIN_COLUMN_WIDTH);
+        let mut widths = self.widths;
+        let mut widths = widths.write();
+        match widths.iter_mut().find(|(f, _)| *f == field) {
+            Some((_, w)) => *w = width,
+            None => widths.push((field, width)),
+        }
+    }
+
+    /// Returns the field's column to its natural width.
+    pub fn reset(&self, field: &F) {
+        let mut widths = self.widths;
+        widths.write().retain(|(f, _)| f != field);
+    }
+
+    /// Returns all columns to their natural width.
+    pub fn reset_all(&self) {
+        let mut widths = self.widths;
+        widths.write().clear();
+    }
+
+    /// Returns the field being resized, if any.
+    pub fn resizing(&self) -> Option<F> {
+        self.drag.read().as_ref().map(|drag| drag.field)
+    }
+
+    /// Starts resizing a field's column from a pointer's horizontal position and the column's current width. Used by [`crate::Th`]; call yourself to build your own grip.
+    pub fn start_resize(&self, field: F, client_x: f64, width: f64) {
+        let mut drag = self.drag;
+        drag.set(Some(Drag {
+            field,
+            start_x: client_x,
+            start_width: width,
+        }));
+    }
+
+    /// Updates the column being resized to follow the pointer. Does nothing if not resizing.
+    pub fn resize_to(&self, client_x: f64) {
+        let Some((field, width)) = self
+            .drag
+            .peek()
+            .as_ref()
+            .map(|drag| (drag.field, drag.start_width + client_x - drag.start_x))
+        else {
+            return;
+        };
+        self.set_width(field, width);
+    }
+
+    /// Finishes resizing, keeping the current width.
+    pub fn end_resize(&self) {
+        let mut drag = self.drag;
+        drag.set(None);
+    }
+
+    /// Returns a `style` declaration for the field's width or an empty string if it has no width.
+    pub fn style(&self, field: &F) -> String {
+        self.width(field)
+            .map(|width| format!("width: {width}px;"))
+            .unwrap_or_default()
+    }
+}
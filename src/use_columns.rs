@@ -0,0 +1,75 @@
+use dioxus::prelude::*;
+
+/// Stores Dioxus hooks and state of which columns are shown. Columns are identified by the same field enum `F` used by [`crate::UseSorter`].
+///
+/// Pass to [`crate::Th`] in its [`crate::ThHooks`] to skip hidden headers. Use [`Self::is_visible`] when rendering cells or [`Self::visible`] to iterate over shown fields in column order.
+#[derive(Debug)]
+pub struct UseColumns<F: 'static> {
+    hidden: Signal<Vec<F>>,
+}
+
+impl<F> Clone for UseColumns<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for UseColumns<F> {}
+
+impl<F> PartialEq for UseColumns<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hidden == other.hidden
+    }
+}
+
+/// Creates Dioxus hooks to manage column visibility. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// All columns start visible.
+pub fn use_columns<F: PartialEq + 'static>() -> UseColumns<F> {
+    UseColumns {
+        hidden: use_signal(Vec::new),
+    }
+}
+
+impl<F: Copy + PartialEq> UseColumns<F> {
+    /// Returns true if the field's column is shown.
+    pub fn is_visible(&self, field: &F) -> bool {
+        !self.hidden.read().contains(field)
+    }
+
+    /// Shows or hides a field's column.
+    pub fn set_visible(&self, field: F, visible: bool) {
+        if self.is_visible(&field) == visible {
+            return;
+        }
+        let mut hidden = self.hidden;
+        if visible {
+            hidden.write().retain(|f| *f != field);
+        } else {
+            hidden.push(field);
+        }
+    }
+
+    /// Shows a field's column.
+    pub fn show(&self, field: F) {
+        self.set_visible(field, true);
+    }
+
+    /// Hides a field's column.
+    pub fn hide(&self, field: F) {
+        self.set_visible(field, false);
+    }
+
+    /// Toggles a field's column between shown and hidden.
+    pub fn toggle(&self, field: F) {
+        self.set_visible(field, !self.is_visible(&field));
+    }
+
+    /// Iterates over visible fields, keeping the order given. Useful for rendering cells in the same order as headers.
+    pub fn visible<'b>(&'b self, fields: &'b [F]) -> impl Iterator<Item = F> + 'b {
+        fields
+            .iter()
+            .copied()
+            .filter(|field| self.is_visible(field))
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{Sortable, SorterMiddleware, SorterState, UseSorter, UseSorterBuilder};
+use dioxus::prelude::*;
+
+/// Creates Dioxus hooks for a sorter whose state is owned by the parent e.g., kept in a global store. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// The sorter follows `state` and never changes it: clicking a [`crate::Th`] (or calling [`UseSorter::toggle_field`] and friends) sends the requested state to `on_change` instead. It's up to the parent to store it and pass it back. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PersonField { #[default] Name }
+/// # impl Sortable for PersonField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// static SORT: GlobalSignal<SorterState<PersonField>> = Signal::global(|| SorterState {
+///     field: PersonField::Name,
+///     direction: Direction::Ascending,
+///     sorted: true,
+/// });
+///
+/// #[component]
+/// fn People() -> Element {
+///     let sorter = use_controlled_sorter(SORT(), EventHandler::new(|state| *SORT.write() = state));
+///     rsx! {
+///         table {
+///             thead {
+///                 tr { Th { sorter: sorter, field: PersonField::Name, "Name" } }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// New state from the parent is applied after render, without firing [`UseSorter::on_change`] or [`SorterMiddleware`]s. Secondary sorts from [`UseSorter::add_sort`] aren't part of [`SorterState`] so stay local. Relies on `F::default()` for any settings not in `state`; see [`UseSorterBuilder::use_controlled_sorter`] to choose them.
+pub fn use_controlled_sorter<F: Copy + Default + Sortable + 'static>(
+    state: SorterState<F>,
+    on_change: EventHandler<SorterState<F>>,
+) -> UseSorter<F> {
+    UseSorterBuilder::default().use_controlled_sorter(state, on_change)
+}
+
+impl<F: Copy + Default + Sortable + 'static> UseSorterBuilder<F> {
+    /// Creates Dioxus hooks for a sorter following the parent's `state` and requesting changes through `on_change`. Uses this builder's algorithm and mode. Its initial field and direction are replaced by `state`. See [`use_controlled_sorter()`].
+    pub fn use_controlled_sorter(
+        self,
+        state: SorterState<F>,
+        on_change: EventHandler<SorterState<F>>,
+    ) -> UseSorter<F> {
+        let sorter = self
+            .with_state(state)
+            .use_sorter()
+            .with_middleware(Controlled { on_change });
+        use_effect(use_reactive!(|state| sorter.sync(state)));
+        sorter
+    }
+}
+
+/// Sends a [`use_controlled_sorter()`]'s changes to its parent instead of applying them.
+struct Controlled<F: 'static> {
+    on_change: EventHandler<SorterState<F>>,
+}
+
+impl<F> SorterMiddleware<F> for Controlled<F> {
+    fn before_change(&self, _: &SorterState<F>, to: SorterState<F>) -> Option<SorterState<F>> {
+        self.on_change.call(to);
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_dom::mount, Direction, SortBy};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum Field {
+        #[default]
+        Name,
+        Age,
+    }
+
+    impl Sortable for Field {
+        fn sort_by(&self) -> Option<SortBy> {
+            SortBy::increasing_or_decreasing()
+        }
+    }
+
+    #[test]
+    fn test_controlled_sorter() {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&requests);
+        let (dom, sorter) = mount(move || {
+            let state = SorterState {
+                field: Field::Age,
+                direction: Direction::Descending,
+                sorted: true,
+            };
+            let log = Rc::clone(&log);
+            let on_change = EventHandler::new(move |state| log.borrow_mut().push(state));
+            use_controlled_sorter(state, on_change)
+        });
+        dom.in_runtime(|| {
+            assert_eq!(sorter.get_state(), (Field::Age, Direction::Descending));
+            sorter.toggle_field(Field::Name);
+            // Requested but left to the parent
+            assert_eq!(sorter.get_state(), (Field::Age, Direction::Descending));
+        });
+        let requested = SorterState {
+            field: Field::Name,
+            direction: Direction::Ascending,
+            sorted: true,
+        };
+        assert_eq!(*requests.borrow(), vec![requested]);
+    }
+}
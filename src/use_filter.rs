@@ -0,0 +1,245 @@
+use crate::aggregates::Aggregate;
+use crate::{KeyedRows, PartialOrdBy, Sortable, UseSorter};
+use dioxus::prelude::*;
+use std::{fmt, ops::Range, rc::Rc};
+
+/// Trait used by [`UseFilter`] to filter a struct by a specific field. This must be implemented on the field enum, alongside [`PartialOrdBy`]. Type `T` represents the struct (table row) that is being filtered.
+///
+/// The `value` is the text entered by the user. Decide what a match means for each field e.g., a case-insensitive substring or an exact match. For example:
+/// ```rust
+/// # use dioxus_sortable::FilterBy;
+/// struct Person {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// # #[derive(PartialEq)]
+/// enum PersonField {
+///     Name,
+///     Age,
+/// }
+///
+/// impl FilterBy<Person> for PersonField {
+///     fn filter_by(&self, item: &Person, value: &str) -> bool {
+///         match self {
+///             PersonField::Name => item.name.to_lowercase().contains(&value.to_lowercase()),
+///             PersonField::Age => item.age.to_string() == value,
+///         }
+///     }
+/// }
+/// ```
+pub trait FilterBy<T>: PartialEq {
+    /// Returns true if the item matches the user's text for this field. Never called with an empty value.
+    fn filter_by(&self, item: &T, value: &str) -> bool;
+}
+
+type Predicate<T> = (&'static str, Rc<dyn Fn(&T) -> bool>);
+
+/// Stores Dioxus hooks and state of filters applied to rows of `T`. Field filters use the field enum `F` via [`FilterBy`].
+///
+/// A row is shown if it matches all of:
+/// - The search text on any of the search fields. See [`Self::set_search`].
+/// - Each field filter. See [`Self::set_field_filter`].
+/// - Each registered predicate. See [`Self::set_predicate`].
+///
+/// Use with [`FilteredSorted`] to filter and sort in one pass.
+pub struct UseFilter<T: 'static, F: 'static> {
+    search: Signal<String>,
+    search_fields: CopyValue<Vec<F>>,
+    fields: Signal<Vec<(F, String)>>,
+    predicates: Signal<Vec<Predicate<T>>>,
+}
+
+impl<T, F> Clone for UseFilter<T, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for UseFilter<T, F> {}
+
+impl<T, F: fmt::Debug> fmt::Debug for UseFilter<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseFilter")
+            .field("search", &self.search)
+            .field("search_fields", &self.search_fields)
+            .field("fields", &self.fields)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F> PartialEq for UseFilter<T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        // Signals compare by identity: equal filters share state
+        self.search == other.search
+            && self.search_fields == other.search_fields
+            && self.fields == other.fields
+            && self.predicates == other.predicates
+    }
+}
+
+/// Creates Dioxus hooks to manage filters. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// The `search_fields` are checked by [`UseFilter::set_search`]. Only the first value given is used. Starts with no filters.
+pub fn use_filter<T: 'static, F: Copy + 'static>(search_fields: &[F]) -> UseFilter<T, F> {
+    UseFilter {
+        search: use_signal(String::new),
+        search_fields: use_hook(|| CopyValue::new(search_fields.to_vec())),
+        fields: use_signal(Vec::new),
+        predicates: use_signal(Vec::new),
+    }
+}
+
+impl<T, F: Copy + FilterBy<T>> UseFilter<T, F> {
+    /// Returns the current search text.
+    pub fn search(&self) -> String {
+        self.search.cloned()
+    }
+
+    /// Sets the search text. Rows match if any search field matches. An empty string clears the search.
+    pub fn set_search(&self, text: impl Into<String>) {
+        let mut search = self.search;
+        search.set(text.into());
+    }
+
+    /// Returns the current filter text for a field. Empty if there is none.
+    pub fn field_filter(&self, field: &F) -> String {
+        self.fields
+            .read()
+            .iter()
+            .find(|(f, _)| f == field)
+            .map_or_else(String::new, |(_, value)| value.clone())
+    }
+
+    /// Sets the filter text for a field. An empty string removes the field's filter.
+    pub fn set_field_filter(&self, field: F, value: impl Into<String>) {
+        let value = value.into();
+        let mut fields = self.fields;
+        let mut fields = fields.write();
+        fields.retain(|(f, _)| *f != field);
+        if !value.is_empty() {
+            fields.push((field, value));
+        }
+    }
+
+    /// Registers a custom predicate under a name, replacing any with the same name. Rows are shown only if the predicate returns true.
+    pub fn set_predicate(&self, name: &'static str, predicate: impl Fn(&T) -> bool + 'static) {
+        let predicate: Rc<dyn Fn(&T) -> bool> = Rc::new(predicate);
+        let mut predicates = self.predicates;
+        let mut predicates = predicates.write();
+        predicates.retain(|(n, _)| *n != name);
+        predicates.push((name, predicate));
+    }
+
+    /// Removes a named predicate.
+    pub fn remove_predicate(&self, name: &'static str) {
+        let mut predicates = self.predicates;
+        predicates.write().retain(|(n, _)| *n != name);
+    }
+
+    /// Removes the search text, all field filters and all predicates.
+    pub fn clear(&self) {
+        let Self {
+            mut search,
+            mut fields,
+            mut predicates,
+            ..
+        } = *self;
+        search.set(String::new());
+        fields.set(Vec::new());
+        predicates.set(Vec::new());
+    }
+
+    /// Returns true if the item passes every filter.
+    pub fn matches(&self, item: &T) -> bool {
+        let search = self.search.read();
+        (search.is_empty()
+            || self
+                .search_fields
+                .read()
+                .iter()
+                .any(|field| field.filter_by(item, &search)))
+            && self
+                .fields
+                .read()
+                .iter()
+                .all(|(field, value)| field.filter_by(item, value))
+            && self
+                .predicates
+                .read()
+                .iter()
+                .all(|(_, predicate)| predicate(item))
+    }
+}
+
+/// A filtered and sorted view over items. Holds indices rather than cloning the items.
+///
+/// Created by [`FilteredSorted::new`] which applies a [`UseFilter`] then sorts what remains with a [`UseSorter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilteredSorted<'b, T> {
+    items: &'b [T],
+    indices: Vec<usize>,
+}
+
+impl<'b, T: 'static> FilteredSorted<'b, T> {
+    /// Filters then sorts items in one pass.
+    pub fn new<F>(items: &'b [T], filter: &UseFilter<T, F>, sorter: &UseSorter<F>) -> Self
+    where
+        F: Copy + FilterBy<T> + PartialOrdBy<T> + Sortable,
+    {
+        let mut indices = (0..items.len())
+            .filter(|&index| filter.matches(&items[index]))
+            .collect::<Vec<_>>();
+        sorter.sort_index_slice(items, &mut indices);
+        sorter.record_total(items.len());
+        Self { items, indices }
+    }
+}
+
+impl<'b, T> FilteredSorted<'b, T> {
+    /// Returns the number of items in the view.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if no items are in the view.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns the item at a position in the view.
+    pub fn get(&self, position: usize) -> Option<&'b T> {
+        let items = self.items;
+        self.indices.get(position).map(|&index| &items[index])
+    }
+
+    /// Returns the indices into the original items in view order.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Iterates over items in view order.
+    pub fn iter(&self) -> impl Iterator<Item = &'b T> + '_ {
+        let items = self.items;
+        self.indices.iter().map(move |&index| &items[index])
+    }
+
+    /// Computes an aggregate over items in view order e.g., for a footer. See [`crate::aggregates`].
+    pub fn aggregate<A: Aggregate<T>>(&self, aggregate: &A) -> A::Output {
+        aggregate.aggregate(&mut self.iter())
+    }
+
+    /// Iterates over `(key, &T)` pairs in view order. See [`KeyedRows`].
+    pub fn keyed<K, G: Fn(&T) -> K>(&self, key: G) -> KeyedRows<'b, T, G> {
+        self.keyed_range(0..self.len(), key)
+    }
+
+    /// Iterates over `(key, &T)` pairs at a range of positions in the view.
+    pub(crate) fn keyed_range<K, G: Fn(&T) -> K>(
+        &self,
+        range: Range<usize>,
+        key: G,
+    ) -> KeyedRows<'b, T, G> {
+        KeyedRows::new(self.items, self.indices[range].to_vec(), key)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::sort::{sort_by, sort_chain};
+use crate::{Direction, PartialOrdBy, Sortable, UseSorter};
+use dioxus::prelude::*;
+
+/// Stores Dioxus hooks and state of the field used to group rows. Rows are sorted by the group field first and then by the [`UseSorter`] field within each group.
+///
+/// For example group by country and sort by name within each country. Use [`GroupedRows`] to iterate over each group.
+#[derive(Debug)]
+pub struct UseGrouper<F: 'static> {
+    field: Signal<Option<F>>,
+    direction: Signal<Direction>,
+}
+
+impl<F> Clone for UseGrouper<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for UseGrouper<F> {}
+
+impl<F> PartialEq for UseGrouper<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field == other.field && self.direction == other.direction
+    }
+}
+
+/// Creates Dioxus hooks to manage grouping. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Starts ungrouped with groups in ascending order.
+pub fn use_grouper<F: 'static>() -> UseGrouper<F> {
+    UseGrouper {
+        field: use_signal(|| None),
+        direction: use_signal(|| Direction::Ascending),
+    }
+}
+
+impl<F: Copy + Sortable> UseGrouper<F> {
+    /// Returns the group field, if grouping.
+    pub fn field(&self) -> Option<F> {
+        *self.field.read()
+    }
+
+    /// Returns the order of groups.
+    pub fn direction(&self) -> Direction {
+        *self.direction.read()
+    }
+
+    /// Groups by a field or stops grouping with `None`.
+    pub fn group_by(&self, field: Option<F>) {
+        let mut state = self.field;
+        state.set(field);
+    }
+
+    /// Groups by a field or stops grouping if already grouped by it. Used by [`crate::Th`] for [`crate::SortBy::GroupOnly`] fields.
+    pub fn toggle_field(&self, field: F) {
+        let mut state = self.field;
+        let current = *state.peek();
+        state.set((current != Some(field)).then_some(field));
+    }
+
+    /// Sets the order of groups.
+    pub fn set_direction(&self, direction: Direction) {
+        let mut state = self.direction;
+        state.set(direction);
+    }
+
+    /// Sorts items by the group field and then by the sorter's field within each group. Keeps groups contiguous for [`GroupedRows`]. Behaves like [`UseSorter::sort`] if not grouping.
+    pub fn sort<T: 'static>(&self, sorter: &UseSorter<F>, items: &mut [T])
+    where
+        F: PartialOrdBy<T>,
+    {
+        let Some(group) = self.field() else {
+            sorter.sort(items);
+            return;
+        };
+        let (field, dir) = sorter.get_state();
+        let then_by = sorter.then_by();
+        let tie_breakers = field.tie_breakers();
+        let mut chain = vec![(&group, self.direction(), group.null_handling())];
+        if sorter.sorts_locally() {
+            chain.extend(sort_chain(&field, dir, &then_by, &tie_breakers));
+        }
+        let chain = sorter.compare_chain(chain);
+        sort_by(&chain, sorter.algorithm(), items);
+    }
+}
+
+/// Iterates over runs of consecutive rows sharing a group value, yielding `(group_value, &[T])`. Sort with [`UseGrouper::sort`] first so each group is contiguous.
+///
+/// The group value is returned by a closure e.g., `|row| row.country.clone()`.
+pub struct GroupedRows<'b, T, G> {
+    rows: &'b [T],
+    group_value: G,
+}
+
+impl<'b, T, K: PartialEq, G: Fn(&T) -> K> GroupedRows<'b, T, G> {
+    /// Creates an iterator over groups of rows.
+    pub fn new(rows: &'b [T], group_value: G) -> Self {
+        Self { rows, group_value }
+    }
+}
+
+impl<'b, T, K: PartialEq, G: Fn(&T) -> K> Iterator for GroupedRows<'b, T, G> {
+    type Item = (K, &'b [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = (self.group_value)(self.rows.first()?);
+        let len = self
+            .rows
+            .iter()
+            .position(|row| (self.group_value)(row) != value)
+            .unwrap_or(self.rows.len());
+        let (group, rest) = self.rows.split_at(len);
+        self.rows = rest;
+        Some((value, group))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grouped_rows() {
+        let rows = [("a", 1), ("a", 2), ("b", 3), ("a", 4)];
+        let groups = GroupedRows::new(&rows, |row| row.0).collect::<Vec<_>>();
+        assert_eq!(
+            groups,
+            vec![
+                ("a", &rows[0..2]),
+                ("b", &rows[2..3]),
+                // Not contiguous so not merged
+                ("a", &rows[3..4]),
+            ]
+        );
+        assert_eq!(GroupedRows::new(&rows[..0], |row| row.0).count(), 0);
+    }
+}
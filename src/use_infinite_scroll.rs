@@ -0,0 +1,112 @@
+use crate::UseSorter;
+use dioxus::prelude::*;
+use std::ops::Range;
+
+/// Stores Dioxus hooks and state for rendering a growing prefix of sorted items. See [`use_infinite_scroll()`].
+///
+/// Like [`crate::UsePaginator`], it doesn't know how many items there are. Methods that need it take a `total`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UseInfiniteScroll {
+    loaded: Signal<usize>,
+    chunk_size: usize,
+}
+
+/// Creates Dioxus hooks to render sorted items a chunk at a time, loading more as the user scrolls. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Starts with one chunk loaded and returns to it whenever the sort changes, including secondary sorts, so the user sees the top of the new order. Render [`crate::InfiniteScrollTrigger`] after the items to load more. A `chunk_size` of zero is treated as one. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # use std::cmp::Ordering;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Post { score: u32 }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PostField { #[default] Score }
+/// # impl PartialOrdBy<Post> for PostField {
+/// #     fn partial_cmp_by(&self, a: &Post, b: &Post) -> Option<Ordering> { a.score.partial_cmp(&b.score) }
+/// # }
+/// # impl Sortable for PostField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::decreasing_or_increasing() }
+/// # }
+/// #[component]
+/// fn Posts(posts: Vec<Post>) -> Element {
+///     let sorter = use_sorter::<PostField>();
+///     let scroll = use_infinite_scroll(sorter, 50);
+///     let mut posts = posts;
+///     sorter.sort(&mut posts);
+///     rsx! {
+///         table {
+///             thead {
+///                 tr { Th { sorter: sorter, field: PostField::Score, "Score" } }
+///             }
+///             tbody {
+///                 for post in scroll.visible(&posts) {
+///                     tr { td { "{post.score}" } }
+///                 }
+///             }
+///         }
+///         InfiniteScrollTrigger { scroll: scroll, total: posts.len() }
+///     }
+/// }
+/// ```
+pub fn use_infinite_scroll<F: Clone + PartialEq + 'static>(
+    sorter: UseSorter<F>,
+    chunk_size: usize,
+) -> UseInfiniteScroll {
+    let chunk_size = chunk_size.max(1);
+    let scroll = UseInfiniteScroll {
+        loaded: use_signal(|| chunk_size),
+        chunk_size,
+    };
+    // Subscribes to the sort so runs on every change
+    use_effect(move || {
+        let _ = sorter.sort_keys();
+        scroll.reset();
+    });
+    scroll
+}
+
+impl UseInfiniteScroll {
+    /// Returns the number of items loaded per chunk.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the number of items loaded, clamped to `total`.
+    pub fn loaded(&self, total: usize) -> usize {
+        (*self.loaded.read()).min(total)
+    }
+
+    /// Returns the range of item indices loaded. Always starts at zero.
+    pub fn range(&self, total: usize) -> Range<usize> {
+        0..self.loaded(total)
+    }
+
+    /// Returns the loaded items: a prefix of the sorted items.
+    pub fn visible<'b, T>(&self, items: &'b [T]) -> &'b [T] {
+        &items[self.range(items.len())]
+    }
+
+    /// Returns true if there are more items to load.
+    pub fn has_more(&self, total: usize) -> bool {
+        self.loaded(total) < total
+    }
+
+    /// Loads the next chunk if there are more items. Called by [`crate::InfiniteScrollTrigger`], otherwise call it from your own scroll handler.
+    pub fn load_more(&self, total: usize) {
+        if !self.has_more(total) {
+            return;
+        }
+        let mut state = self.loaded;
+        let loaded = self.loaded(total) + self.chunk_size;
+        state.set(loaded.min(total));
+    }
+
+    /// Returns to the first chunk. Called when the sort changes. Call it when filtering too.
+    pub fn reset(&self) {
+        let mut state = self.loaded;
+        if *state.peek() != self.chunk_size {
+            state.set(self.chunk_size);
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    SortAlgorithm, SortMode, Sortable, SorterMiddleware, SorterState, UseSorter, UseSorterBuilder,
+};
+use dioxus::prelude::*;
+use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender};
+use std::{any::Any, fmt, rc::Rc};
+
+type OnChange<K> = Option<Rc<dyn Fn(K)>>;
+
+/// A sorter registered with [`UseMultiSorter::use_sorter`]. Type-erased as each table may have its own field enum.
+struct Table<K> {
+    id: K,
+    /// Holds a [`UseSorter`] of the table's field enum.
+    sorter: Rc<dyn Any>,
+    clear: Rc<dyn Fn()>,
+}
+
+/// Stores Dioxus hooks and state of several tables showing the same data, each with its own [`UseSorter`] and possibly its own field enum e.g., a summary and a detail table. Tables are identified by `K`, usually an enum. See [`use_multi_sorter()`].
+///
+/// Sorters share the defaults set on [`UseMultiSorterBuilder`] and report every change through one [`Self::on_change`] callback or [`Self::changes`] stream, saving a callback per table. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum SummaryField { #[default] Country }
+/// # impl Sortable for SummaryField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum DetailField { #[default] Name, Population }
+/// # impl Sortable for DetailField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// enum Table {
+///     Summary,
+///     Detail,
+/// }
+///
+/// #[component]
+/// fn Report() -> Element {
+///     let tables = UseMultiSorterBuilder::default()
+///         .initially_unsorted()
+///         .use_multi_sorter::<Table>()
+///         .on_change(|table| log::info!("{table:?} sort changed"));
+///     let summary = tables.use_sorter::<SummaryField>(Table::Summary);
+///     let detail = tables.use_sorter_with(
+///         Table::Detail,
+///         tables.builder().with_field(DetailField::Population),
+///     );
+///     rsx! {
+///         button { onclick: move |_| tables.clear(), "Reset sorting" }
+///         // Render a table with `summary` and another with `detail`
+///     }
+/// }
+/// ```
+pub struct UseMultiSorter<K: 'static> {
+    algorithm: SortAlgorithm,
+    mode: SortMode,
+    sorted: bool,
+    tables: CopyValue<Vec<Table<K>>>,
+    on_change: CopyValue<OnChange<K>>,
+    /// Senders of streams handed out by [`Self::changes`]. Closed ones are dropped on the next change.
+    changes: CopyValue<Vec<UnboundedSender<K>>>,
+}
+
+impl<K> Clone for UseMultiSorter<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for UseMultiSorter<K> {}
+
+impl<K> fmt::Debug for UseMultiSorter<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseMultiSorter")
+            .field("algorithm", &self.algorithm)
+            .field("mode", &self.mode)
+            .field("sorted", &self.sorted)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K> PartialEq for UseMultiSorter<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.algorithm == other.algorithm
+            && self.mode == other.mode
+            && self.sorted == other.sorted
+            && self.tables == other.tables
+            && self.on_change == other.on_change
+            && self.changes == other.changes
+    }
+}
+
+/// Builder for [`UseMultiSorter`]. Sets defaults shared by every table's sorter.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseMultiSorterBuilder {
+    algorithm: SortAlgorithm,
+    mode: SortMode,
+    sorted: bool,
+}
+
+impl Default for UseMultiSorterBuilder {
+    fn default() -> Self {
+        Self {
+            algorithm: SortAlgorithm::default(),
+            mode: SortMode::default(),
+            sorted: true,
+        }
+    }
+}
+
+impl UseMultiSorterBuilder {
+    /// Optionally sets the sort algorithm of every table. See [`UseSorterBuilder::with_algorithm`].
+    pub fn with_algorithm(self, algorithm: SortAlgorithm) -> Self {
+        Self { algorithm, ..self }
+    }
+
+    /// Optionally sets where every table is sorted. See [`UseSorterBuilder::with_mode`].
+    pub fn with_mode(self, mode: SortMode) -> Self {
+        Self { mode, ..self }
+    }
+
+    /// Optionally starts every table unsorted. See [`UseSorterBuilder::initially_unsorted`].
+    pub fn initially_unsorted(self) -> Self {
+        Self {
+            sorted: false,
+            ..self
+        }
+    }
+
+    /// Creates Dioxus hooks to manage several sorters. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+    pub fn use_multi_sorter<K: 'static>(self) -> UseMultiSorter<K> {
+        UseMultiSorter {
+            algorithm: self.algorithm,
+            mode: self.mode,
+            sorted: self.sorted,
+            tables: use_hook(|| CopyValue::new(Vec::new())),
+            on_change: use_hook(|| CopyValue::new(None)),
+            changes: use_hook(|| CopyValue::new(Vec::new())),
+        }
+    }
+}
+
+/// Creates Dioxus hooks to manage the sorters of several tables. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks. See [`UseMultiSorterBuilder`] to set shared defaults.
+pub fn use_multi_sorter<K: 'static>() -> UseMultiSorter<K> {
+    UseMultiSorterBuilder::default().use_multi_sorter()
+}
+
+impl<K: Clone + PartialEq + 'static> UseMultiSorter<K> {
+    /// Returns a [`UseSorterBuilder`] with the shared defaults. Customise it and pass it to [`Self::use_sorter_with`].
+    pub fn builder<F: Copy + Default + Sortable + 'static>(&self) -> UseSorterBuilder<F> {
+        let builder = UseSorterBuilder::default()
+            .with_algorithm(self.algorithm)
+            .with_mode(self.mode);
+        match self.sorted {
+            true => builder,
+            false => builder.initially_unsorted(),
+        }
+    }
+
+    /// Creates Dioxus hooks to manage a table's sorter with the shared defaults. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+    pub fn use_sorter<F: Copy + Default + Sortable + 'static>(&self, table: K) -> UseSorter<F> {
+        self.use_sorter_with(table, self.builder())
+    }
+
+    /// Creates Dioxus hooks to manage a table's sorter from a builder e.g., one from [`Self::builder`] starting on another field. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+    ///
+    /// Registers [`SorterMiddleware`] on the sorter to report its changes. Other middleware and the sorter's own [`UseSorter::on_change`] are left alone.
+    pub fn use_sorter_with<F: Copy + Default + Sortable + 'static>(
+        &self,
+        table: K,
+        builder: UseSorterBuilder<F>,
+    ) -> UseSorter<F> {
+        let sorter = builder.use_sorter().with_middleware(Notify {
+            table: table.clone(),
+            multi: *self,
+        });
+        let entry = Table {
+            id: table,
+            sorter: Rc::new(sorter),
+            clear: Rc::new(move || sorter.clear()),
+        };
+        let mut tables = self.tables;
+        let mut tables = tables.write();
+        match tables.iter_mut().find(|other| other.id == entry.id) {
+            Some(existing) => *existing = entry,
+            None => tables.push(entry),
+        }
+        sorter
+    }
+
+    /// Returns a table's sorter e.g., in a child component given only the [`UseMultiSorter`]. `None` if no sorter was created for the table with field enum `F`.
+    pub fn get<F: 'static>(&self, table: &K) -> Option<UseSorter<F>> {
+        let tables = self.tables.peek();
+        let entry = tables.iter().find(|entry| entry.id == *table)?;
+        entry.sorter.downcast_ref::<UseSorter<F>>().copied()
+    }
+
+    /// Returns the ids of tables with a sorter, in creation order.
+    pub fn tables(&self) -> Vec<K> {
+        self.tables
+            .peek()
+            .iter()
+            .map(|entry| entry.id.clone())
+            .collect()
+    }
+
+    /// Resets every table's sorter. See [`UseSorter::clear`].
+    pub fn clear(&self) {
+        // Clone out so changes may be reported back to us
+        let clears = self
+            .tables
+            .peek()
+            .iter()
+            .map(|entry| entry.clear.clone())
+            .collect::<Vec<_>>();
+        for clear in clears {
+            clear();
+        }
+    }
+
+    /// Registers a callback fired with the table id whenever any table's sort state changes. Read the new state from the table's sorter.
+    ///
+    /// Call this every render, straight after creating the sorters. Replaces any earlier callback.
+    pub fn on_change(self, on_change: impl Fn(K) + 'static) -> Self {
+        let mut slot = self.on_change;
+        slot.set(Some(Rc::new(on_change)));
+        self
+    }
+
+    /// Returns a stream of table ids, receiving the id of each table after its sort state changes. Like [`UseSorter::changes`] for every table at once.
+    pub fn changes(&self) -> UnboundedReceiver<K> {
+        let (sender, receiver) = futures_channel::mpsc::unbounded();
+        let mut changes = self.changes;
+        changes.write().push(sender);
+        receiver
+    }
+
+    /// Reports a table's change to the callback and streams.
+    fn changed(&self, table: &K) {
+        // Clone out so the callback may use the sorters
+        let on_change = self.on_change.peek().clone();
+        if let Some(on_change) = on_change {
+            on_change(table.clone());
+        }
+        let mut changes = self.changes;
+        changes
+            .write()
+            .retain(|sender| sender.unbounded_send(table.clone()).is_ok());
+    }
+}
+
+/// Reports a sorter's changes to its [`UseMultiSorter`].
+struct Notify<K: 'static> {
+    table: K,
+    multi: UseMultiSorter<K>,
+}
+
+impl<F, K: Clone + PartialEq + 'static> SorterMiddleware<F> for Notify<K> {
+    fn after_change(&self, _: &SorterState<F>, _: &SorterState<F>) {
+        self.multi.changed(&self.table);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_dom::mount, Direction, SortBy};
+    use std::cell::RefCell;
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum Summary {
+        #[default]
+        Country,
+    }
+
+    impl Sortable for Summary {
+        fn sort_by(&self) -> Option<SortBy> {
+            SortBy::increasing_or_decreasing()
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum Detail {
+        #[default]
+        Name,
+        Population,
+    }
+
+    impl Sortable for Detail {
+        fn sort_by(&self) -> Option<SortBy> {
+            SortBy::increasing_or_decreasing()
+        }
+    }
+
+    #[test]
+    fn test_multi_sorter() {
+        let changed = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&changed);
+        let (dom, multi) = mount(move || {
+            let log = Rc::clone(&log);
+            let multi = UseMultiSorterBuilder::default()
+                .initially_unsorted()
+                .use_multi_sorter()
+                .on_change(move |table| log.borrow_mut().push(table));
+            multi.use_sorter::<Summary>("summary");
+            multi.use_sorter_with("detail", multi.builder().with_field(Detail::Population));
+            multi
+        });
+        dom.in_runtime(|| {
+            assert_eq!(multi.tables(), vec!["summary", "detail"]);
+            assert!(multi.get::<Detail>(&"summary").is_none());
+            let summary = multi.get::<Summary>(&"summary").unwrap();
+            let detail = multi.get::<Detail>(&"detail").unwrap();
+            // Shared defaults
+            assert!(!summary.is_sorted() && !detail.is_sorted());
+            assert_eq!(detail.get_state().0, Detail::Population);
+
+            detail.toggle_field(Detail::Name);
+            summary.set_field(Summary::Country, Direction::Descending);
+            assert_eq!(*changed.borrow(), vec!["detail", "summary"]);
+            multi.clear();
+            assert_eq!(detail.get_state().0, Detail::Population);
+            assert!(!summary.is_sorted());
+        });
+        // Cleared in creation order
+        assert_eq!(
+            *changed.borrow(),
+            vec!["detail", "summary", "summary", "detail"]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{PartialOrdBy, Sortable, UseSorter};
+use dioxus::prelude::*;
+use std::ops::Range;
+
+/// Stores Dioxus hooks and state for slicing items into pages. Use alongside [`UseSorter`] via [`UseSorter::sort_and_page`].
+///
+/// The paginator does not know how many items there are. Methods that need it take a `total` and clamp the current page so that filtering data never leaves us on a page that no longer exists.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UsePaginator {
+    page: Signal<usize>,
+    page_size: Signal<usize>,
+}
+
+/// Creates Dioxus hooks to manage pagination state. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Starts on the first page. A `page_size` of zero is treated as one.
+pub fn use_paginator(page_size: usize) -> UsePaginator {
+    UsePaginator {
+        page: use_signal(|| 0),
+        page_size: use_signal(|| page_size.max(1)),
+    }
+}
+
+impl UsePaginator {
+    /// Returns the current (zero-based) page, clamped to the last page of `total` items.
+    pub fn page(&self, total: usize) -> usize {
+        (*self.page.read()).min(self.last_page(total))
+    }
+
+    /// Returns the number of items per page.
+    pub fn page_size(&self) -> usize {
+        *self.page_size.read()
+    }
+
+    /// Returns the number of pages needed for `total` items. Always at least one.
+    pub fn page_count(&self, total: usize) -> usize {
+        self.last_page(total) + 1
+    }
+
+    fn last_page(&self, total: usize) -> usize {
+        total.saturating_sub(1) / self.page_size()
+    }
+
+    /// Returns the range of item indices on the current page.
+    pub fn range(&self, total: usize) -> Range<usize> {
+        page_range(self.page(total), self.page_size(), total)
+    }
+
+    /// Returns the current page of items.
+    pub fn page_of<'b, T>(&self, items: &'b [T]) -> &'b [T] {
+        &items[self.range(items.len())]
+    }
+
+    /// Jumps to a (zero-based) page. Clamped to the last page.
+    pub fn set_page(&self, page: usize, total: usize) {
+        let mut state = self.page;
+        state.set(page.min(self.last_page(total)));
+    }
+
+    /// Sets the number of items per page. Returns to the first page.
+    pub fn set_page_size(&self, page_size: usize) {
+        let (mut size_state, mut page_state) = (self.page_size, self.page);
+        size_state.set(page_size.max(1));
+        page_state.set(0);
+    }
+
+    /// Returns to the first page.
+    pub fn first(&self) {
+        let mut state = self.page;
+        state.set(0);
+    }
+
+    /// Moves to the next page if there is one.
+    pub fn next(&self, total: usize) {
+        self.set_page(self.page(total) + 1, total);
+    }
+
+    /// Moves to the previous page if there is one.
+    pub fn previous(&self, total: usize) {
+        self.set_page(self.page(total).saturating_sub(1), total);
+    }
+}
+
+fn page_range(page: usize, page_size: usize, total: usize) -> Range<usize> {
+    let start = (page * page_size).min(total);
+    let end = (start + page_size).min(total);
+    start..end
+}
+
+impl<F> UseSorter<F> {
+    /// Sorts items (see [`Self::sort`]) and returns the current page of them. Sorting is applied to all items before paging.
+    pub fn sort_and_page<'b, T: 'static>(
+        &self,
+        items: &'b mut [T],
+        paginator: &UsePaginator,
+    ) -> &'b [T]
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        self.sort(items);
+        paginator.page_of(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_range() {
+        assert_eq!(page_range(0, 10, 25), 0..10);
+        assert_eq!(page_range(1, 10, 25), 10..20);
+        assert_eq!(page_range(2, 10, 25), 20..25);
+        // Out of bounds is empty rather than panicking
+        assert_eq!(page_range(3, 10, 25), 25..25);
+        assert_eq!(page_range(0, 10, 0), 0..0);
+    }
+}
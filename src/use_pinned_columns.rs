@@ -0,0 +1,111 @@
+use crate::UseColumnWidths;
+use dioxus::prelude::*;
+
+/// Which edge of the table a pinned column sticks to. See [`UsePinnedColumns`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PinSide {
+    /// Frozen on the left, before unpinned columns.
+    Left,
+    /// Frozen on the right, after unpinned columns.
+    Right,
+}
+
+/// Stores Dioxus hooks and state of which columns are pinned (frozen) while the table scrolls sideways. Columns are identified by the same field enum `F` used by [`crate::UseSorter`].
+///
+/// Render columns in [`Self::order`] and give each pinned `<th>` and `<td>` the [`Self::style`]. Headers stay sortable: pass the style to [`crate::Th`] as usual. [`crate::SortableTable`] does this for you.
+///
+/// Pinned columns after the first on each side are offset by the widths of those before them. Set their widths with [`UseColumnWidths`] otherwise they will overlap.
+#[derive(Debug)]
+pub struct UsePinnedColumns<F: 'static> {
+    pinned: Signal<Vec<(F, PinSide)>>,
+}
+
+impl<F> Clone for UsePinnedColumns<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for UsePinnedColumns<F> {}
+
+impl<F> PartialEq for UsePinnedColumns<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pinned == other.pinned
+    }
+}
+
+/// Creates Dioxus hooks to manage pinned columns. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// No columns start pinned.
+pub fn use_pinned_columns<F: PartialEq + 'static>() -> UsePinnedColumns<F> {
+    UsePinnedColumns {
+        pinned: use_signal(Vec::new),
+    }
+}
+
+impl<F: Copy + PartialEq> UsePinnedColumns<F> {
+    /// Returns the side a field's column is pinned to, if any.
+    pub fn side(&self, field: &F) -> Option<PinSide> {
+        self.pinned
+            .read()
+            .iter()
+            .find(|(f, _)| f == field)
+            .map(|(_, side)| *side)
+    }
+
+    /// Pins a field's column to a side. Replaces any earlier side.
+    pub fn pin(&self, field: F, side: PinSide) {
+        if self.side(&field) == Some(side) {
+            return;
+        }
+        let mut pinned = self.pinned;
+        let mut pinned = pinned.write();
+        pinned.retain(|(f, _)| *f != field);
+        pinned.push((field, side));
+    }
+
+    /// Unpins a field's column.
+    pub fn unpin(&self, field: &F) {
+        if self.side(field).is_none() {
+            return;
+        }
+        let mut pinned = self.pinned;
+        pinned.write().retain(|(f, _)| f != field);
+    }
+
+    /// Returns fields in render order: left-pinned, unpinned then right-pinned. Keeps the order given within each group.
+    pub fn order(&self, fields: &[F]) -> Vec<F> {
+        let on = |side| {
+            fields
+                .iter()
+                .copied()
+                .filter(move |field| self.side(field) == side)
+        };
+        on(Some(PinSide::Left))
+            .chain(on(None))
+            .chain(on(Some(PinSide::Right)))
+            .collect()
+    }
+
+    /// Returns a `style` declaration making a pinned field's cells sticky or an empty string if unpinned. `fields` must be in render order (see [`Self::order`]).
+    ///
+    /// The offset is the total width of pinned columns between this one and its edge, taken from `widths`. Columns without a width count as zero.
+    pub fn style(&self, field: &F, fields: &[F], widths: Option<UseColumnWidths<F>>) -> String {
+        let Some(side) = self.side(field) else {
+            return String::new();
+        };
+        let Some(position) = fields.iter().position(|f| f == field) else {
+            return String::new();
+        };
+        let (between, edge) = match side {
+            PinSide::Left => (&fields[..position], "left"),
+            PinSide::Right => (&fields[position + 1..], "right"),
+        };
+        let offset = between
+            .iter()
+            .filter(|f| self.side(f) == Some(side))
+            .filter_map(|f| widths.and_then(|widths| widths.width(f)))
+            .sum::<f64>();
+        format!("position: sticky; {edge}: {offset}px; z-index: 1; background: Canvas;")
+    }
+}
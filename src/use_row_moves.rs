@@ -0,0 +1,257 @@
+use dioxus::prelude::*;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+};
+
+/// How rows moved between two orders. Returned by [`UseRowMoves::track`] or [`RowMoves::between`].
+///
+/// A row has moved if its position relative to the other rows changed, not just its index. Inserting a row at the top shifts every row down but moves none of them. Moved rows are the fewest that explain the change: if one row jumps from last to first, only it has moved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowMoves<K> {
+    moved: Vec<K>,
+    added: Vec<K>,
+    removed: Vec<K>,
+}
+
+impl<K> Default for RowMoves<K> {
+    fn default() -> Self {
+        Self {
+            moved: Vec::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> RowMoves<K> {
+    /// Compares two orders of row keys. Keys must be unique within each order.
+    pub fn between(previous: &[K], current: &[K]) -> Self {
+        let positions = previous
+            .iter()
+            .enumerate()
+            .map(|(position, key)| (key, position))
+            .collect::<HashMap<_, _>>();
+        let current_keys = current.iter().collect::<HashSet<_>>();
+        let removed = previous
+            .iter()
+            .filter(|key| !current_keys.contains(key))
+            .cloned()
+            .collect();
+        // Rows in both orders, by current position, with their previous position
+        let mut added = Vec::new();
+        let mut kept = Vec::new();
+        for key in current {
+            match positions.get(key) {
+                Some(&position) => kept.push((key, position)),
+                None => added.push(key.clone()),
+            }
+        }
+        // Rows in the longest run keeping their previous order stayed put
+        let mut stayed = vec![false; kept.len()];
+        for index in longest_increasing(&kept.iter().map(|(_, p)| *p).collect::<Vec<_>>()) {
+            stayed[index] = true;
+        }
+        let moved = kept
+            .iter()
+            .zip(stayed)
+            .filter(|(_, stayed)| !stayed)
+            .map(|((key, _), _)| (*key).clone())
+            .collect();
+        Self {
+            moved,
+            added,
+            removed,
+        }
+    }
+
+    /// Returns true if the row changed position relative to other rows.
+    pub fn is_moved(&self, key: &K) -> bool {
+        self.moved.contains(key)
+    }
+
+    /// Returns true if the row wasn't in the previous order.
+    pub fn is_added(&self, key: &K) -> bool {
+        self.added.contains(key)
+    }
+}
+
+impl<K> RowMoves<K> {
+    /// Returns the keys of rows that changed position, in current order.
+    pub fn moved(&self) -> &[K] {
+        &self.moved
+    }
+
+    /// Returns the keys of rows that weren't in the previous order, in current order.
+    pub fn added(&self) -> &[K] {
+        &self.added
+    }
+
+    /// Returns the keys of rows that are no longer present, in previous order.
+    pub fn removed(&self) -> &[K] {
+        &self.removed
+    }
+
+    /// Returns true if nothing moved, was added or removed.
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty() && self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Returns the indices of a longest strictly increasing subsequence. Patience sorting in O(n log n).
+fn longest_increasing(values: &[usize]) -> Vec<usize> {
+    // Index of the smallest tail of each run length and each index's predecessor in its run
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; values.len()];
+    for (index, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|&tail| values[tail] < *value);
+        previous[index] = length.checked_sub(1).map(|length| tails[length]);
+        match tails.get_mut(length) {
+            Some(tail) => *tail = index,
+            None => tails.push(index),
+        }
+    }
+    let mut run = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+    while let Some(index) = next {
+        run.push(index);
+        next = previous[index];
+    }
+    run.reverse();
+    run
+}
+
+/// Stores Dioxus hooks and state to report how rows moved each time their order changes. Helps users keep their place when live data refreshes and re-sorts e.g., show "12 rows changed position" or flash moved rows. See [`use_row_moves()`].
+pub struct UseRowMoves<K: 'static> {
+    /// Order seen by the last [`Self::track`].
+    keys: CopyValue<Vec<K>>,
+    /// Moves from the last change of order.
+    moves: CopyValue<RowMoves<K>>,
+    /// Number of times the order has changed.
+    changes: CopyValue<usize>,
+}
+
+impl<K> Clone for UseRowMoves<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for UseRowMoves<K> {}
+
+impl<K: fmt::Debug> fmt::Debug for UseRowMoves<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseRowMoves")
+            .field("moves", &self.moves)
+            .field("changes", &self.changes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K> PartialEq for UseRowMoves<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.moves == other.moves && self.changes == other.changes
+    }
+}
+
+/// Creates Dioxus hooks to track how sorted rows move as data changes. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Call [`UseRowMoves::track`] with the keys of the rows in display order after sorting each render. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Trade { id: u32, price: f64 }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum TradeField { #[default] Price }
+/// # impl PartialOrdBy<Trade> for TradeField {
+/// #     fn partial_cmp_by(&self, a: &Trade, b: &Trade) -> Option<std::cmp::Ordering> { a.price.partial_cmp(&b.price) }
+/// # }
+/// # impl Sortable for TradeField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Trades(trades: Vec<Trade>) -> Element {
+///     let sorter = use_sorter::<TradeField>();
+///     let moves = use_row_moves::<u32>();
+///     let rows = sorter.keyed_rows(&trades, |trade| trade.id).collect::<Vec<_>>();
+///     let moved = moves.track(rows.iter().map(|(id, _)| *id));
+///     rsx! {
+///         p { "{moved.moved().len()} rows changed position" }
+///         for (id, trade) in rows {
+///             tr { key: "{id}", class: if moved.is_moved(&id) { "moved" }, td { "{trade.price}" } }
+///         }
+///     }
+/// }
+/// ```
+///
+/// The first order tracked has nothing to compare with so reports no moves.
+pub fn use_row_moves<K: 'static>() -> UseRowMoves<K> {
+    UseRowMoves {
+        keys: use_hook(|| CopyValue::new(Vec::new())),
+        moves: use_hook(|| CopyValue::new(RowMoves::default())),
+        changes: use_hook(|| CopyValue::new(0)),
+    }
+}
+
+impl<K: Clone + Eq + Hash> UseRowMoves<K> {
+    /// Records the keys of rows in their current order. Returns how rows moved since the order last changed: the same result is returned each render until it changes again. Keys must be unique.
+    ///
+    /// Not reactive so may be called during render. Components re-render when their data or sorter changes anyway.
+    pub fn track(&self, keys: impl IntoIterator<Item = K>) -> RowMoves<K> {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        let mut previous = self.keys;
+        if *previous.peek() == keys {
+            return self.moves();
+        }
+        // Nothing to compare the first order with
+        let moves = match *self.changes.peek() {
+            0 if previous.peek().is_empty() => RowMoves::default(),
+            _ => RowMoves::between(&previous.peek(), &keys),
+        };
+        previous.set(keys);
+        let (mut slot, mut changes) = (self.moves, self.changes);
+        slot.set(moves.clone());
+        *changes.write() += 1;
+        moves
+    }
+
+    /// Returns how rows moved the last time the order changed.
+    pub fn moves(&self) -> RowMoves<K> {
+        self.moves.peek().clone()
+    }
+
+    /// Returns the number of times the order has changed. Useful as a `key` to restart a CSS animation on each change.
+    pub fn changes(&self) -> usize {
+        *self.changes.peek()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_moves() {
+        // Inserting shifts rows but moves none
+        let moves = RowMoves::between(&[1, 2, 3], &[0, 1, 2, 3]);
+        assert!(moves.moved().is_empty());
+        assert_eq!(moves.added(), &[0]);
+
+        // Only the row jumping to the top moved
+        let moves = RowMoves::between(&[1, 2, 3, 4], &[4, 1, 2, 3]);
+        assert_eq!(moves.moved(), &[4]);
+        assert!(moves.is_moved(&4) && !moves.is_moved(&1));
+
+        // Reversed: all but one must move
+        let moves = RowMoves::between(&[1, 2, 3], &[3, 2, 1]);
+        assert_eq!(moves.moved().len(), 2);
+
+        let moves = RowMoves::between(&[1, 2, 3, 4], &[3, 1, 5]);
+        assert_eq!(moves.moved(), &[3]);
+        assert_eq!(moves.added(), &[5]);
+        assert_eq!(moves.removed(), &[2, 4]);
+        assert!(RowMoves::between(&[1, 2], &[1, 2]).is_empty());
+    }
+}
@@ -0,0 +1,210 @@
+use dioxus::prelude::*;
+use std::fmt;
+
+/// How many rows may be selected at once. See [`use_selection()`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum SelectionMode {
+    /// At most one row. Selecting a row deselects any other.
+    Single,
+    /// Any number of rows. The default.
+    #[default]
+    Multiple,
+}
+
+/// Stores Dioxus hooks and state of selected rows. Type `T` is the row and `K` is the key identifying it.
+///
+/// Rows are identified by a key rather than their index so selections survive re-sorting.
+pub struct UseSelection<T, K: 'static> {
+    selected: Signal<Vec<K>>,
+    mode: SelectionMode,
+    key: fn(&T) -> K,
+}
+
+impl<T, K> Clone for UseSelection<T, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, K> Copy for UseSelection<T, K> {}
+
+impl<T, K: fmt::Debug> fmt::Debug for UseSelection<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseSelection")
+            .field("selected", &self.selected)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, K> PartialEq for UseSelection<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.selected == other.selected && self.mode == other.mode
+    }
+}
+
+/// Creates Dioxus hooks to manage row selection. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// The `key` fn must return a value that uniquely identifies each row e.g., an ID or name. Starts with nothing selected.
+pub fn use_selection<T, K: 'static>(mode: SelectionMode, key: fn(&T) -> K) -> UseSelection<T, K> {
+    UseSelection {
+        selected: use_signal(Vec::new),
+        mode,
+        key,
+    }
+}
+
+impl<T, K: Clone + PartialEq> UseSelection<T, K> {
+    /// Returns the selection mode.
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Returns the keys of selected rows in the order they were selected.
+    pub fn selected(&self) -> Vec<K> {
+        self.selected.cloned()
+    }
+
+    /// Returns the key of a row.
+    pub fn key(&self, row: &T) -> K {
+        (self.key)(row)
+    }
+
+    /// Returns true if the row is selected.
+    pub fn is_selected(&self, row: &T) -> bool {
+        self.is_key_selected(&self.key(row))
+    }
+
+    /// Returns true if the row identified by `key` is selected.
+    pub fn is_key_selected(&self, key: &K) -> bool {
+        self.selected.read().contains(key)
+    }
+
+    /// Selects or deselects a row.
+    pub fn set_selected(&self, row: &T, selected: bool) {
+        self.set_key_selected(self.key(row), selected);
+    }
+
+    /// Selects or deselects the row identified by `key`.
+    pub fn set_key_selected(&self, key: K, selected: bool) {
+        if self.is_key_selected(&key) == selected {
+            return;
+        }
+        let mut keys = self.selected;
+        match (selected, self.mode) {
+            (true, SelectionMode::Single) => keys.set(vec![key]),
+            (true, SelectionMode::Multiple) => keys.push(key),
+            (false, _) => keys.write().retain(|k| *k != key),
+        }
+    }
+
+    /// Toggles a row between selected and deselected.
+    pub fn toggle(&self, row: &T) {
+        let key = self.key(row);
+        let selected = !self.is_key_selected(&key);
+        self.set_key_selected(key, selected);
+    }
+
+    /// Selects all given rows. Only the first row is selected in [`SelectionMode::Single`].
+    pub fn select_all<'b>(&self, rows: impl IntoIterator<Item = &'b T>)
+    where
+        T: 'b,
+    {
+        let keys = rows.into_iter().map(|row| self.key(row));
+        let mut selected = self.selected;
+        selected.set(match self.mode {
+            SelectionMode::Single => keys.take(1).collect(),
+            SelectionMode::Multiple => keys.collect(),
+        });
+    }
+
+    /// Deselects all rows.
+    pub fn clear(&self) {
+        let mut selected = self.selected;
+        if !selected.peek().is_empty() {
+            selected.set(Vec::new());
+        }
+    }
+}
+
+/// Stores Dioxus hooks and state of sorting selected rows to the top. A virtual field applied after the [`crate::UseSorter`] so rows keep its order within the selected and unselected groups. See [`use_sort_by_selection()`].
+///
+/// Useful for review workflows: pick rows then group them together. Toggle from a [`crate::SelectionTh`].
+pub struct SortBySelection<T, K: 'static> {
+    selection: UseSelection<T, K>,
+    enabled: Signal<bool>,
+}
+
+impl<T, K> Clone for SortBySelection<T, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, K> Copy for SortBySelection<T, K> {}
+
+impl<T, K: fmt::Debug> fmt::Debug for SortBySelection<T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortBySelection")
+            .field("selection", &self.selection)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl<T, K> PartialEq for SortBySelection<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.selection == other.selection && self.enabled == other.enabled
+    }
+}
+
+/// Creates Dioxus hooks to sort selected rows to the top. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Starts disabled.
+pub fn use_sort_by_selection<T, K: 'static>(
+    selection: UseSelection<T, K>,
+) -> SortBySelection<T, K> {
+    SortBySelection {
+        selection,
+        enabled: use_signal(|| false),
+    }
+}
+
+impl<T, K: Clone + PartialEq> SortBySelection<T, K> {
+    /// Returns the selection rows are grouped by.
+    pub fn selection(&self) -> UseSelection<T, K> {
+        self.selection
+    }
+
+    /// Returns true if selected rows are sorted to the top.
+    pub fn is_enabled(&self) -> bool {
+        *self.enabled.read()
+    }
+
+    /// Turns sorting selected rows to the top on or off.
+    pub fn set_enabled(&self, enabled: bool) {
+        let mut state = self.enabled;
+        if *state.peek() != enabled {
+            state.set(enabled);
+        }
+    }
+
+    /// Toggles sorting selected rows to the top.
+    pub fn toggle(&self) {
+        self.set_enabled(!*self.enabled.peek());
+    }
+
+    /// Moves selected items to the top if enabled. Call after [`crate::UseSorter::sort`]: the sort is stable so each group keeps its order.
+    pub fn sort(&self, items: &mut [T]) {
+        if self.is_enabled() {
+            items.sort_by_cached_key(|item| !self.selection.is_selected(item));
+        }
+    }
+
+    /// Moves indices of selected items to the top if enabled e.g., after [`crate::UseSorter::sort_indices`]. Otherwise behaves like [`Self::sort`].
+    pub fn sort_indices(&self, items: &[T], indices: &mut [usize]) {
+        if self.is_enabled() {
+            indices.sort_by_cached_key(|&index| !self.selection.is_selected(&items[index]));
+        }
+    }
+}
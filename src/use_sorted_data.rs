@@ -0,0 +1,58 @@
+use crate::{PartialOrdBy, Sortable, UseSorter};
+use dioxus::prelude::*;
+
+/// Creates Dioxus hooks to keep a sorted copy of data. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Re-sorts only when the source or the sorter's state changes rather than every render. Useful for data that changes often e.g., a live feed, or components that render often. For example:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::{use_sorted_data, use_sorter, PartialOrdBy, SortBy, Sortable, Th};
+/// # use std::cmp::Ordering;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Trade { price: f64 }
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum TradeField { #[default] Price }
+/// # impl PartialOrdBy<Trade> for TradeField {
+/// #     fn partial_cmp_by(&self, a: &Trade, b: &Trade) -> Option<Ordering> { a.price.partial_cmp(&b.price) }
+/// # }
+/// # impl Sortable for TradeField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+/// # }
+/// #[component]
+/// fn Trades(trades: ReadOnlySignal<Vec<Trade>>) -> Element {
+///     let sorter = use_sorter::<TradeField>();
+///     // Props are reactive so new trades re-sort
+///     let sorted = use_sorted_data(trades, sorter);
+///     rsx! {
+///         table {
+///             thead {
+///                 tr { Th { sorter: sorter, field: TradeField::Price, "Price" } }
+///             }
+///             tbody {
+///                 for trade in sorted.read().iter() {
+///                     tr { td { "{trade.price}" } }
+///                 }
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// Pass the source as a signal e.g., from `use_signal` or a `ReadOnlySignal` prop. The first source given is the one tracked. Comparators from [`UseSorter::with_comparator`] aren't tracked: changing one doesn't re-sort.
+///
+/// Sorts a copy with [`UseSorter::sort`] so the source keeps its order. Use [`UseSorter::sort_indices`] in a `use_memo` to avoid the copy.
+pub fn use_sorted_data<T, F>(
+    source: impl Into<ReadOnlySignal<Vec<T>>>,
+    sorter: UseSorter<F>,
+) -> Memo<Vec<T>>
+where
+    T: Clone + PartialEq + 'static,
+    F: PartialOrdBy<T> + Sortable + 'static,
+{
+    let source = source.into();
+    use_memo(move || {
+        let mut data = source.cloned();
+        sorter.sort(&mut data);
+        data
+    })
+}
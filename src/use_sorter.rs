@@ -1,195 +1,331 @@
+use crate::computed::ComputeKeys;
+#[cfg(feature = "debug")]
+use crate::debug::{SortStats, SortTimer};
+use crate::sort::{
+    apply_permutation, check_compare, cmp_chain, describe_sort, is_null, sort_by,
+    sort_by_cached_key, sort_chain, sort_indices_by, sort_indices_by_index, sort_ord_by,
+    sort_top_n_by, sortable_by, Compare, KeyCmp, SortKey,
+};
+#[cfg(feature = "web")]
+use crate::storage::{self, Storage};
+use crate::{
+    ComputedField, Direction, InvalidSort, NullHandling, OrdBy, PartialOrdBy, SortAlgorithm,
+    SortBy, SortKeyBy, SortState, Sortable, SorterMiddleware, SorterState,
+};
 use dioxus::prelude::*;
-use std::cmp::Ordering;
+use std::{
+    any::{Any, TypeId},
+    cmp::Ordering,
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    ops::Deref,
+    rc::Rc,
+};
 
-/// Stores Dioxus hooks and state of our sortable items.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct UseSorter<'a, F: 'static> {
-    field: &'a UseState<F>,
-    direction: &'a UseState<Direction>,
-}
+type OnChange<F> = Option<Rc<dyn Fn(F, Direction)>>;
+type OnSorted = Option<Rc<dyn Fn(&[usize], &[usize])>>;
+type Middleware<F> = Vec<(TypeId, Rc<dyn SorterMiddleware<F>>)>;
+type Changes<F> = Vec<UnboundedSender<SorterState<F>>>;
+/// Comparator registered with [`UseSorter::with_comparator`].
+type Comparator<T> = Rc<dyn Fn(&T, &T) -> Option<Ordering>>;
+/// Computed field registered with [`UseSorter::with_computed`] or a field's [`PartialOrdBy::projection`].
+type Computed<T> = Rc<dyn ComputeKeys<T>>;
+/// Field, direction, sorted state and secondary sorts of a [`UseSorter`].
+pub(crate) type StateSignals<F> = (
+    Signal<F>,
+    Signal<Direction>,
+    Signal<bool>,
+    Signal<Vec<(F, Direction)>>,
+);
+/// Looks up an item's load order captured by [`UseSorter::capture_baseline`].
+type Baseline<T> = Rc<dyn Fn(&T) -> Option<usize>>;
 
-/// Trait used by [UseSorter](UseSorter) to sort a struct by a specific field. This must be implemented on the field enum. Type `T` represents the struct (table row) that is being sorted.
-///
-/// The implementation should use the [`PartialOrd::partial_cmp`] trait to compare the field values and return the result. For example:
-/// ```rust
-/// # use dioxus_sortable::PartialOrdBy;
-/// # #[derive(PartialEq)]
-/// struct MyStruct {
-///     first: String,
-///     second: f64, // <- Note: can return None if f64::NAN
-/// }
-///
-/// # #[derive(Copy, Clone, Debug, PartialEq)]
-/// enum MyStructField {
-///     First,
-///     Second,
-/// }
-///
-/// impl PartialOrdBy<MyStruct> for MyStructField {
-///     fn partial_cmp_by(&self, a: &MyStruct, b: &MyStruct) -> Option<std::cmp::Ordering> {
-///         match self {
-///             MyStructField::First => a.first.partial_cmp(&b.first),
-///             MyStructField::Second => a.second.partial_cmp(&b.second),
-///         }
-///     }
-/// }
-/// ```
-///
-/// Be careful when using [`Option::None`] or a custom enum to represent missing data (`NULL` values). As `partial_cmp` as `None` is less than `Some`:
-///
-/// ```rust
-/// # use std::cmp::Ordering;
-/// assert_eq!(Ordering::Less, None.cmp(&Some(0)));
-/// ```
+/// Stores Dioxus hooks and state of our sortable items.
 ///
-pub trait PartialOrdBy<T>: PartialEq {
-    /// Compare two values of type `T` by the field's enum. Return values of `None` are treated as `NULL` values. See [`Sortable`] for more information.
-    ///
-    /// Be careful when comparing types like `Option` which implement `Ord`. This means that `None` and `Some` have an order where we might use them as unknown / `NULL` values. This can be a surprise.
-    ///
-    /// Another issue is `f64` only implements `PartialOrd` and not `Ord` because a value can hold `f64::NAN`. In this situation `partial_cmp` will return `None` and we'll treat these values as `NULL` as expected.
-    fn partial_cmp_by(&self, a: &T, b: &T) -> Option<Ordering>;
+/// Cheap to copy into child components and event handlers. State is held in signals so reading it (e.g., with [`Self::get_state`] or [`Self::sort`]) subscribes the current component to changes. Children that only need to react to the current state can take [`Self::field`] or [`Self::direction`] instead of the whole sorter.
+pub struct UseSorter<F: 'static> {
+    field: Signal<F>,
+    direction: Signal<Direction>,
+    sorted: Signal<bool>,
+    /// Secondary sorts added with [`Self::add_sort`] in priority order.
+    then_by: Signal<Vec<(F, Direction)>>,
+    algorithm: SortAlgorithm,
+    mode: SortMode,
+    /// Set by [`UseSorterBuilder::with_checked_comparisons`].
+    checked: bool,
+    on_change: CopyValue<OnChange<F>>,
+    on_sorted: CopyValue<OnSorted>,
+    /// Registered by [`Self::with_middleware`] in order. Keyed by type so re-registering replaces.
+    middleware: CopyValue<Middleware<F>>,
+    /// Receivers handed out by [`Self::changes`]. Closed ones are dropped on the next change.
+    changes: CopyValue<Changes<F>>,
+    /// Initial directions set by [`UseSorterBuilder::with_field_direction`].
+    field_directions: CopyValue<Vec<(F, Direction)>>,
+    /// Per-field comparators. Each holds a [`Comparator`] for some `T`.
+    comparators: CopyValue<Vec<(F, Rc<dyn Any>)>>,
+    /// Per-field computed fields. Each holds a [`Computed`] for some `T`.
+    computed: CopyValue<Vec<(F, Rc<dyn Any>)>>,
+    /// Runtime overrides of [`Sortable::null_handling`] set by [`Self::set_null_handling`].
+    null_handling: Signal<Vec<(F, NullHandling)>>,
+    /// Fields turned off at runtime by [`Self::disable_field`].
+    disabled: Signal<Vec<F>>,
+    /// Last order seen by [`Self::sort_indices`]. Only tracked with an [`Self::on_sorted`] callback.
+    last_indices: CopyValue<Vec<usize>>,
+    /// Field, direction and sorted state the sorter was created with. Restored by [`Self::clear`].
+    initial: CopyValue<(F, Direction, bool)>,
+    /// Load orders captured by [`Self::capture_baseline`]. Each holds a [`Baseline`] for some `T`.
+    baselines: CopyValue<Vec<Rc<dyn Any>>>,
+    /// Rows given to, shown by and `NULL` in the last sort as `(total, visible, nulls)`. See [`Self::last_sort_stats`].
+    last_sort: CopyValue<Option<(usize, usize, usize)>>,
+    /// True once rows have been ordered by hand with [`Self::move_row`]. The unsorted order is then the custom order.
+    custom: Signal<bool>,
+    /// Not reactive so sorting while rendering may record them. See [`Self::stats`].
+    #[cfg(feature = "debug")]
+    stats: CopyValue<SortStats<F>>,
+    #[cfg(feature = "web")]
+    storage: Option<Storage<F>>,
 }
 
-/// Trait used to describe how a field can be sorted. This must be implemented on the field enum.
-///
-/// Our [`PartialOrdBy`] fn may result in `None` values which we refer to as `NULL`. We borrow from SQL here to handle these values in a similar way to the [SQL ORDER BY clause](https://www.postgresql.org/docs/current/sql-select.html#SQL-ORDERBY). The PostgreSQL general form is `ORDER BY expression [ ASC | DESC | USING operator ] [ NULLS { FIRST | LAST } ] [, ...]` where:
-/// - `expression` is the field being sorted.
-/// - `ASC` and `DESC` are the sort [`Direction`].
-/// - `USING operator` is implied by [`PartialOrdBy`].
-/// - `NULLS { FIRST | LAST }` corresponds to [`NullHandling`].
-/// Meaning you can sort by ascending or descending and optionally specify `NULL` ordering.
-pub trait Sortable: PartialEq {
-    /// Describes how this field can be sorted.
-    fn sort_by(&self) -> Option<SortBy>;
-
-    /// Describes how `NULL` values (when [`PartialOrdBy`] returns `None`) should be ordered when sorting. Either all at the start or the end.
-    ///
-    /// Provided implementation relies on the default (all at the end) and should be overridden if you want to change this generally or on a per-field basis.
-    fn null_handling(&self) -> NullHandling {
-        NullHandling::default()
+impl<F> Clone for UseSorter<F> {
+    fn clone(&self) -> Self {
+        *self
     }
 }
 
-/// Describes how a field should be sorted. Returned by [`Sortable::sort_by`].
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum SortBy {
-    /// This field is limited to being sorted in the one direction specified.
-    Fixed(Direction),
-    /// This field can be sorted in either direction. The direction specifies the initial direction. Fields of this sort can be toggled between directions.
-    Reversible(Direction),
-}
-
-/// Sort direction. Does not have a default -- implied by the field via [`SortBy`].
-///
-/// Actual sorting is done by [`PartialOrdBy`].
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum Direction {
-    /// Ascending sort. A-Z, 0-9, little to big, etc.
-    Ascending,
-    /// Descending sort. Z-A, opposite of ascending.
-    Descending,
-}
+impl<F> Copy for UseSorter<F> {}
 
-impl Direction {
-    /// Inverts the direction.
-    pub fn invert(&self) -> Self {
-        match self {
-            Self::Ascending => Self::Descending,
-            Self::Descending => Self::Ascending,
-        }
+impl<F: fmt::Debug> fmt::Debug for UseSorter<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseSorter")
+            .field("field", &self.field)
+            .field("direction", &self.direction)
+            .field("sorted", &self.sorted)
+            .field("then_by", &self.then_by)
+            .field("algorithm", &self.algorithm)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
     }
+}
 
-    fn from_field<F: Sortable>(field: &F) -> Direction {
-        field.sort_by().unwrap_or_default().direction()
+impl<F> PartialEq for UseSorter<F> {
+    fn eq(&self, other: &Self) -> bool {
+        // Signals compare by identity: equal sorters share state
+        self.field == other.field
+            && self.direction == other.direction
+            && self.sorted == other.sorted
+            && self.then_by == other.then_by
+            && self.algorithm == other.algorithm
+            && self.mode == other.mode
+            && self.checked == other.checked
+            && self.on_change == other.on_change
+            && self.on_sorted == other.on_sorted
+            && self.middleware == other.middleware
+            && self.changes == other.changes
+            && self.comparators == other.comparators
+            && self.computed == other.computed
+            && self.null_handling == other.null_handling
+            && self.disabled == other.disabled
+            && self.field_directions == other.field_directions
+            && self.baselines == other.baselines
+            && self.last_sort == other.last_sort
+            && self.custom == other.custom
     }
 }
 
-/// Describes how `NULL` values should be ordered when sorting. We refer to `None` values returned from [`PartialOrdBy::partial_cmp_by`] as `NULL`. Warning: Rust's `Option::None` is not strictly equivalent to SQL's `NULL` but we borrow from SQL terminology to handle them.
+/// Where sorting happens. Set with [`UseSorterBuilder::with_mode`].
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
-pub enum NullHandling {
-    /// Places all `NULL` values first.
-    First,
-    /// Places all `NULL` values last. The default.
+pub enum SortMode {
+    /// Items are sorted in the browser by [`UseSorter::sort`] and friends. The default.
     #[default]
-    Last,
+    Local,
+    /// Items arrive already sorted e.g., from a server or database. [`UseSorter::sort`] and friends leave items untouched and the sorter only tracks header state. Use [`UseSorter::sort_request`] to build the query.
+    Remote,
+}
+
+/// Returns how a field may be sorted, including any override from [`UseSorterBuilder::with_field_direction`].
+fn field_sort_by<F: Sortable>(field: &F, field_directions: &[(F, Direction)]) -> Option<SortBy> {
+    let sort_by = sortable_by(field)?;
+    let dir = field_directions.iter().find(|(other, _)| other == field);
+    Some(dir.map_or(sort_by, |(_, dir)| sort_by.with_initial_direction(*dir)))
+}
+
+/// Builder for [UseSorter](UseSorter). Use this to specify the field and direction of the sorter. For example by passing sort state from URL parameters.
+///
+/// The builder will ignore invalid combinations specified by the field's [`Sortable`] when the hook is created. This is to prevent the user from specifying a direction that is not allowed by the field. Use [`Self::try_with_state`] or [`Self::validate`] to reject them instead e.g., when syncing state from URLs:
+/// ```rust
+/// # use dioxus::prelude::*;
+/// # use dioxus_sortable::*;
+/// # use std::cmp::Ordering;
+/// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+/// # enum PostField { #[default] Newest }
+/// # impl PartialOrdBy<()> for PostField {
+/// #     fn partial_cmp_by(&self, _: &(), _: &()) -> Option<Ordering> { None }
+/// # }
+/// # impl Sortable for PostField {
+/// #     fn sort_by(&self) -> Option<SortBy> { SortBy::decreasing() }
+/// # }
+/// #[component]
+/// fn Posts(state: SorterState<PostField>) -> Element {
+///     let builder = UseSorterBuilder::default();
+///     // Fall back to the defaults and say why rather than quietly fixing the URL's state
+///     let (builder, error) = match builder.try_with_state(state) {
+///         Ok(builder) => (builder, None),
+///         Err(err) => (builder, Some(err.to_string())),
+///     };
+///     let sorter = builder.use_sorter();
+///     rsx! {
+///         if let Some(error) = error {
+///             p { class: "error", "Ignored sort: {error}" }
+///         }
+///         table {
+///             thead {
+///                 tr { Th { sorter: sorter, field: PostField::Newest, "Posted" } }
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseSorterBuilder<F> {
+    field: F,
+    /// Initial direction. Defaults to the field's initial direction.
+    direction: Option<Direction>,
+    sorted: bool,
+    field_directions: Vec<(F, Direction)>,
+    algorithm: SortAlgorithm,
+    mode: SortMode,
+    checked: bool,
+    #[cfg(feature = "web")]
+    storage: Option<Storage<F>>,
 }
 
-impl Default for SortBy {
-    fn default() -> SortBy {
-        Self::increasing_or_decreasing().unwrap()
+impl<F: Default + Sortable> Default for UseSorterBuilder<F> {
+    fn default() -> Self {
+        Self {
+            field: F::default(),
+            direction: None,
+            sorted: true,
+            field_directions: Vec::new(),
+            algorithm: SortAlgorithm::default(),
+            mode: SortMode::default(),
+            checked: false,
+            #[cfg(feature = "web")]
+            storage: None,
+        }
     }
 }
 
-impl SortBy {
-    /// Field may not be sorted. Convenience fn for specifying how a field may be sorted.
-    pub fn unsortable() -> Option<Self> {
-        None
+impl<F: Copy + Default + Sortable + 'static> UseSorterBuilder<F> {
+    /// Optionally sets the initial field to sort by. Starts in the field's initial direction unless [`Self::with_direction`] is given.
+    pub fn with_field(&self, field: F) -> Self {
+        Self {
+            field,
+            ..self.clone()
+        }
     }
-    /// Field may only be sorted in ascending order.
-    pub fn increasing() -> Option<Self> {
-        Some(Self::Fixed(Direction::Ascending))
+
+    /// Optionally sets the initial direction to sort by.[`Direction::Ascending`] can be set.
+    pub fn with_direction(&self, direction: Direction) -> Self {
+        Self {
+            direction: Some(direction),
+            ..self.clone()
+        }
     }
-    /// Field may only be sorted in descending order.
-    pub fn decreasing() -> Option<Self> {
-        Some(Self::Fixed(Direction::Descending))
+
+    /// Optionally overrides the direction a field starts in when first sorted by, in place of its [`Sortable::sort_by`]. Useful when the field enum comes from another crate e.g., to sort names Z-A first. Applies to the initial field unless [`Self::with_direction`] is given.
+    ///
+    /// Ignored for fields with a fixed direction or a [`SortBy::Cycle`]. Call again to override more fields. A later call for the same field replaces the earlier one.
+    pub fn with_field_direction(&self, field: F, direction: Direction) -> Self {
+        let mut field_directions = self.field_directions.clone();
+        field_directions.retain(|(other, _)| *other != field);
+        field_directions.push((field, direction));
+        Self {
+            field_directions,
+            ..self.clone()
+        }
     }
-    /// Field may be sorted in either direction. The initial direction is ascending. This is the default.
-    pub fn increasing_or_decreasing() -> Option<Self> {
-        Some(Self::Reversible(Direction::Ascending))
+
+    /// Optionally sets the initial field, direction and sorted state from a snapshot e.g., one saved with [`UseSorter::save`] or sent by a client for server-side rendering. Like [`UseSorter::restore`] but without firing callbacks.
+    pub fn with_state(&self, state: SorterState<F>) -> Self {
+        Self {
+            field: state.field,
+            direction: Some(state.direction),
+            sorted: state.sorted,
+            ..self.clone()
+        }
     }
-    /// Field may be sorted in either direction. The initial direction is descending.
-    pub fn decreasing_or_increasing() -> Option<Self> {
-        Some(Self::Reversible(Direction::Descending))
+
+    /// Optionally sets the initial state from a snapshot like [`Self::with_state`] but returns an error rather than correcting invalid state. See [`SorterState::validate`].
+    pub fn try_with_state(&self, state: SorterState<F>) -> Result<Self, InvalidSort<F>> {
+        state.validate()?;
+        Ok(self.with_state(state))
     }
 
-    /// Returns the initial / implied direction of the sort.
-    pub fn direction(&self) -> Direction {
-        match self {
-            Self::Fixed(dir) => *dir,
-            Self::Reversible(dir) => *dir,
+    /// Checks the initial field is sortable and any direction set with [`Self::with_direction`] is allowed for it. [`Self::use_sorter`] would otherwise silently fall back to the default field or the field's initial direction.
+    pub fn validate(&self) -> Result<(), InvalidSort<F>> {
+        let sort_by = field_sort_by(&self.field, &self.field_directions);
+        let sort_by = sort_by.ok_or(InvalidSort::Unsortable(self.field))?;
+        match self.direction {
+            Some(direction) if sort_by.ensure_direction(direction) != direction => {
+                Err(InvalidSort::Direction(self.field, direction))
+            }
+            _ => Ok(()),
         }
     }
 
-    fn ensure_direction(&self, dir: Direction) -> Direction {
-        use SortBy::*;
-        match self {
-            // Must match allowed
-            Fixed(allowed) if *allowed == dir => dir,
-            // Did not match allowed
-            Fixed(allowed) => *allowed,
-            // Any allowed
-            Reversible(_) => dir,
+    /// Optionally starts unsorted so data keeps its natural order until the user picks a field. The field and direction are still used for the first toggle of that field. See [`UseSorter::is_sorted`].
+    pub fn initially_unsorted(&self) -> Self {
+        Self {
+            sorted: false,
+            ..self.clone()
         }
     }
-}
 
-/// Builder for [UseSorter](UseSorter). Use this to specify the field and direction of the sorter. For example by passing sort state from URL parameters.
-///
-/// Ordering of [`Self::with_field`] and [`Self::with_direction`] matters as the builder will ignore invalid combinations specified by the field's [`Sortable`]. This is to prevent the user from specifying a direction that is not allowed by the field.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct UseSorterBuilder<F> {
-    field: F,
-    direction: Direction,
-}
+    /// Optionally sets the sort algorithm. Defaults to [`SortAlgorithm::Stable`].
+    pub fn with_algorithm(&self, algorithm: SortAlgorithm) -> Self {
+        Self {
+            algorithm,
+            ..self.clone()
+        }
+    }
 
-impl<F: Default + Sortable> Default for UseSorterBuilder<F> {
-    fn default() -> Self {
-        let field = F::default();
-        let direction = Direction::from_field(&field);
-        Self { field, direction }
+    /// Optionally sets where sorting happens. Defaults to [`SortMode::Local`]. Use [`SortMode::Remote`] when data arrives pre-sorted.
+    pub fn with_mode(&self, mode: SortMode) -> Self {
+        Self {
+            mode,
+            ..self.clone()
+        }
     }
-}
 
-impl<F: Copy + Default + Sortable> UseSorterBuilder<F> {
-    /// Optionally sets the initial field to sort by.
-    pub fn with_field(&self, field: F) -> Self {
-        Self { field, ..*self }
+    /// Optionally checks comparisons are consistent before each sort in debug builds, panicking with which rule was broken and by which items. See [`crate::check_partial_ord_by`] for the rules. Catches hand-written [`PartialOrdBy`] impls or [`UseSorter::with_comparator`] closures that would otherwise silently sort into a wrong, algorithm-dependent order.
+    ///
+    /// Checks a sample of up to 32 items per sort key so adds roughly a thousand comparisons per sort. Does nothing in release builds. Fields with a [`ComputedField`] aren't checked.
+    pub fn with_checked_comparisons(&self) -> Self {
+        Self {
+            checked: true,
+            ..self.clone()
+        }
     }
 
-    /// Optionally sets the initial direction to sort by.[`Direction::Ascending`] can be set.
-    pub fn with_direction(&self, direction: Direction) -> Self {
-        Self { direction, ..*self }
+    /// Optionally persists the sort field and direction to the browser's `localStorage` under `key`. Any previously saved state is restored on mount and every change is saved. Requires the `web` feature.
+    ///
+    /// Fields are stored as text so `F` must implement [`std::fmt::Display`] and [`std::str::FromStr`]. Saved state takes precedence over earlier calls to [`Self::with_field`] and [`Self::with_direction`] so call this last.
+    #[cfg(feature = "web")]
+    pub fn with_storage_key(&self, key: &'static str) -> Self
+    where
+        F: std::fmt::Display + std::str::FromStr,
+    {
+        let (field, direction) = match storage::load(key) {
+            Some((field, direction)) => (field, Some(direction)),
+            None => (self.field, self.direction),
+        };
+        Self {
+            field,
+            direction,
+            storage: Some(Storage::new(key)),
+            ..self.clone()
+        }
     }
 
     /// Creates Dioxus hooks to manage state. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks. See [use_sorter()] for simple usage.
@@ -197,10 +333,46 @@ impl<F: Copy + Default + Sortable> UseSorterBuilder<F> {
     /// This fn (or [`Self::use_sorter`]) *must* be called or never used. See the docs on [`UseSorter::sort`] on using conditions.
     ///
     /// If the field or direction has not been set then the default values will be used.
-    pub fn use_sorter(self, cx: &ScopeState) -> UseSorter<F> {
-        let sorter = use_sorter(cx);
-        sorter.set_field(self.field, self.direction);
-        sorter
+    pub fn use_sorter(self) -> UseSorter<F> {
+        let sort_by = |field: &F| field_sort_by(field, &self.field_directions);
+        // Ignore unsortable fields and invalid directions
+        let (field, direction) = match sort_by(&self.field) {
+            None => {
+                let field = F::default();
+                (field, sort_by(&field).unwrap_or_default().direction())
+            }
+            Some(sort_by) => match self.direction {
+                Some(direction) => (self.field, sort_by.ensure_direction(direction)),
+                None => (self.field, sort_by.direction()),
+            },
+        };
+        UseSorter {
+            field: use_signal(|| field),
+            direction: use_signal(|| direction),
+            sorted: use_signal(|| self.sorted),
+            then_by: use_signal(Vec::new),
+            algorithm: self.algorithm,
+            mode: self.mode,
+            checked: self.checked,
+            on_change: use_hook(|| CopyValue::new(None)),
+            on_sorted: use_hook(|| CopyValue::new(None)),
+            middleware: use_hook(|| CopyValue::new(Vec::new())),
+            changes: use_hook(|| CopyValue::new(Vec::new())),
+            comparators: use_hook(|| CopyValue::new(Vec::new())),
+            computed: use_hook(|| CopyValue::new(Vec::new())),
+            null_handling: use_signal(Vec::new),
+            disabled: use_signal(Vec::new),
+            field_directions: use_hook(|| CopyValue::new(self.field_directions.clone())),
+            last_indices: use_hook(|| CopyValue::new(Vec::new())),
+            initial: use_hook(|| CopyValue::new((field, direction, self.sorted))),
+            baselines: use_hook(|| CopyValue::new(Vec::new())),
+            last_sort: use_hook(|| CopyValue::new(None)),
+            custom: use_signal(|| false),
+            #[cfg(feature = "debug")]
+            stats: use_hook(|| CopyValue::new(SortStats::default())),
+            #[cfg(feature = "web")]
+            storage: self.storage,
+        }
     }
 }
 
@@ -209,157 +381,1601 @@ impl<F: Copy + Default + Sortable> UseSorterBuilder<F> {
 /// This fn (or [`UseSorterBuilder::use_sorter`]) *must* be called or never used. See the docs on [`UseSorter::sort`] on using conditions.
 ///
 /// Relies on `F::default()` for the initial value.
-pub fn use_sorter<F: Copy + Default + Sortable>(cx: &ScopeState) -> UseSorter<'_, F> {
-    let field = F::default();
-    UseSorter {
-        field: use_state(cx, || field),
-        direction: use_state(cx, || Direction::from_field(&field)),
+pub fn use_sorter<F: Copy + Default + Sortable + 'static>() -> UseSorter<F> {
+    UseSorterBuilder::default().use_sorter()
+}
+
+/// How a column relates to the current sort. Lets cells adapt when their column is sorted by e.g., bold the values or show rank changes. Get one with [`UseSorter::cell_sort`] or from a [`crate::CellFormat`] in a [`crate::SortableTable`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CellSort<F> {
+    /// The active (primary) field and direction, or `None` if unsorted.
+    pub active: Option<(F, Direction)>,
+    /// The column's direction if sorted by it, either as the active field or a secondary sort.
+    pub direction: Option<Direction>,
+    /// The column's position in [`UseSorter::sort_keys`]. See [`UseSorter::priority`].
+    pub priority: Option<usize>,
+}
+
+impl<F> CellSort<F> {
+    /// Returns true if the column is the active (primary) sort.
+    pub fn is_active(&self) -> bool {
+        self.priority == Some(0)
+    }
+
+    /// Returns true if the column is sorted by at all, including as a secondary sort.
+    pub fn is_sorted(&self) -> bool {
+        self.priority.is_some()
+    }
+}
+
+/// Row counts and sort of the last sort, for status bars e.g., "58 rows · sorted by Name, A to Z · 3 unknown". Returned by [`UseSorter::last_sort_stats`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LastSortStats<F> {
+    /// Rows before filtering with [`crate::FilteredSorted`] or removing `NULL`s (see [`NullHandling::Remove`]).
+    pub total: usize,
+    /// Rows left to show.
+    pub visible: usize,
+    /// Rows where the active field is `NULL`, including any removed. Counted while sorting so 0 if unsorted or in [`SortMode::Remote`].
+    pub nulls: usize,
+    /// Fields sorted by with their directions in priority order. Empty if unsorted. See [`UseSorter::sort_keys`].
+    pub sort_keys: Vec<(F, Direction)>,
+}
+
+impl<F: Sortable> LastSortStats<F> {
+    /// Describes the sort in plain language from [`Sortable::label`] and [`Sortable::direction_label`] e.g., "Name, A to Z, then Age, ascending". Empty if unsorted. See [`crate::SortSummary`] for a ready-made bar.
+    pub fn description(&self) -> String {
+        describe_sort(&self.sort_keys, "then")
     }
 }
 
-impl<'a, F> UseSorter<'a, F> {
+impl<F> UseSorter<F> {
     /// Returns the current field and direction. Can be used to recreate state with [UseSorterBuilder](UseSorterBuilder).
-    pub fn get_state(&self) -> (&F, &Direction) {
-        (self.field.get(), self.direction.get())
+    pub fn get_state(&self) -> (F, Direction)
+    where
+        F: Clone,
+    {
+        (self.field.cloned(), *self.direction.read())
     }
 
-    /// Sets the sort field and toggles the direction (if applicable). Ignores unsortable fields.
-    pub fn toggle_field(&self, field: F)
+    /// Returns the current field as a read-only signal. Pass it to child components that only display state.
+    pub fn field(&self) -> ReadOnlySignal<F> {
+        self.field.into()
+    }
+
+    /// Returns the current direction as a read-only signal. See [`Self::field`].
+    pub fn direction(&self) -> ReadOnlySignal<Direction> {
+        self.direction.into()
+    }
+
+    /// Returns whether the sorter is sorted as a read-only signal. See [`Self::is_sorted`].
+    pub fn sorted(&self) -> ReadOnlySignal<bool> {
+        self.sorted.into()
+    }
+
+    /// Registers a callback fired with the new field and direction whenever [`Self::toggle_field`] or [`Self::set_field`] changes the sort state. Useful for server-side sorting e.g., restarting a `use_resource` that fetches sorted data.
+    ///
+    /// Also fires when toggled back to unsorted (see [`SortBy::ReversibleWithReset`]). Check [`Self::is_sorted`] on the next render to tell the difference.
+    ///
+    /// Call this every render, straight after creating the sorter. Replaces any earlier callback.
+    pub fn on_change(self, on_change: impl Fn(F, Direction) + 'static) -> Self {
+        let mut slot = self.on_change;
+        slot.set(Some(Rc::new(on_change)));
+        self
+    }
+
+    /// Returns a stream of sort state, receiving the new state after every change. Use it from async tasks e.g., a `use_future` that refetches from a server, rather than comparing [`Self::get_state`] each render. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::{use_sorter, Sortable, SortBy};
+    /// # use futures_util::StreamExt;
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum MyField { #[default] Name }
+    /// # impl Sortable for MyField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// fn MyTable() -> Element {
+    ///     let sorter = use_sorter::<MyField>();
+    ///     use_future(move || async move {
+    ///         let mut changes = sorter.changes();
+    ///         while let Some(state) = changes.next().await {
+    ///             log::info!("sort by {:?} {:?}", state.field, state.direction);
+    ///         }
+    ///     });
+    ///     // ...
+    ///     # rsx! {}
+    /// }
+    /// ```
+    ///
+    /// Changes are seen in the same cases as [`Self::on_change`], after any [`Self::with_middleware`] has had its say. The stream starts empty: read [`Self::save`] for the current state. Each call returns a new stream and every stream receives every change. Dropping it unsubscribes.
+    pub fn changes(&self) -> UnboundedReceiver<SorterState<F>> {
+        let (sender, receiver) = futures_channel::mpsc::unbounded();
+        let mut changes = self.changes;
+        changes.write().push(sender);
+        receiver
+    }
+
+    /// Registers a callback fired by [`Self::sort_indices`] when the sorted order differs from the last call. Receives the previous and new orders as indices into items. Useful for animating rows to their new positions e.g., with the FLIP technique.
+    ///
+    /// Not fired on the first call as there is no previous order. If the items themselves changed, the previous order refers to the old items.
+    ///
+    /// Call this every render, straight after creating the sorter. Replaces any earlier callback.
+    pub fn on_sorted(self, on_sorted: impl Fn(&[usize], &[usize]) + 'static) -> Self {
+        let mut slot = self.on_sorted;
+        slot.set(Some(Rc::new(on_sorted)));
+        self
+    }
+
+    /// Registers middleware that intercepts every change of state. See [`SorterMiddleware`]. Middleware runs in the order first registered.
+    ///
+    /// Call this every render, straight after creating the sorter. Replaces any earlier middleware of the same type, keeping its place in the order.
+    pub fn with_middleware<M: SorterMiddleware<F> + 'static>(self, middleware: M) -> Self {
+        let middleware: Rc<dyn SorterMiddleware<F>> = Rc::new(middleware);
+        let mut slot = self.middleware;
+        let mut registered = slot.write();
+        let id = TypeId::of::<M>();
+        match registered
+            .iter_mut()
+            .find(|(registered, _)| *registered == id)
+        {
+            Some((_, existing)) => *existing = middleware,
+            None => registered.push((id, middleware)),
+        }
+        self
+    }
+
+    /// Overrides how a field compares items of type `T`, in place of its [`PartialOrdBy`]. Useful when sorting depends on app settings e.g., a user-selected locale or units. Returning `None` marks an item as `NULL` as usual.
+    ///
+    /// Used by [`Self::sort`] and friends, including tie-breakers and [`crate::UseGrouper::sort`]. Not used by [`Self::sort_by_cached_key`] or [`Self::sort_ord`].
+    ///
+    /// Call this every render, straight after creating the sorter, so the comparator sees current settings. Replaces any earlier comparator or computed field for the field and `T`.
+    pub fn with_comparator<T: 'static>(
+        self,
+        field: F,
+        compare: impl Fn(&T, &T) -> Option<Ordering> + 'static,
+    ) -> Self
+    where
+        F: PartialEq,
+    {
+        let compare: Comparator<T> = Rc::new(compare);
+        let mut slot = self.comparators;
+        let mut comparators = slot.write();
+        comparators.retain(|(f, c)| *f != field || !c.is::<Comparator<T>>());
+        let mut computed = self.computed;
+        computed
+            .write()
+            .retain(|(f, c)| *f != field || !c.is::<Computed<T>>());
+        comparators.push((field, Rc::new(compare)));
+        self
+    }
+
+    /// Compares a field by a [`ComputedField`]'s values, in place of its [`PartialOrdBy`]. [`Self::sort`], [`Self::sort_indices`] and friends compute each item's value once per sort rather than on every comparison. Elsewhere, such as [`crate::UseGrouper::sort`], values are computed as needed like a comparator from [`Self::with_comparator`].
+    ///
+    /// Call this every render, straight after creating the sorter. Replaces any earlier comparator or computed field for the field and `T`.
+    pub fn with_computed<T: 'static, K: PartialOrd + 'static>(
+        self,
+        field: F,
+        computed: ComputedField<T, K>,
+    ) -> Self
+    where
+        F: Clone + PartialEq,
+    {
+        let compute = computed.clone();
+        let this = self.with_comparator(field.clone(), move |a: &T, b: &T| {
+            compute.compute(a)?.partial_cmp(&compute.compute(b)?)
+        });
+        let computed: Computed<T> = Rc::new(computed);
+        let mut slot = self.computed;
+        slot.write().push((field, Rc::new(computed)));
+        this
+    }
+
+    /// Returns a field's computed field for `T`, if any. See [`Self::with_computed`].
+    fn computed<T: 'static>(&self, field: &F) -> Option<Computed<T>>
+    where
+        F: PartialEq,
+    {
+        self.computed
+            .read()
+            .iter()
+            .filter(|(f, _)| f == field)
+            .find_map(|(_, c)| c.downcast_ref::<Computed<T>>().cloned())
+    }
+
+    /// Returns true if any computed field for `T` has been registered.
+    fn has_computed<T: 'static>(&self) -> bool {
+        self.computed
+            .read()
+            .iter()
+            .any(|(_, c)| c.is::<Computed<T>>())
+    }
+
+    /// Returns a field's keys for `T`: any computed field from [`Self::with_computed`], otherwise its [`PartialOrdBy::projection`] unless replaced with [`Self::with_comparator`].
+    fn projected<T: 'static>(&self, field: &F) -> Option<Computed<T>>
+    where
+        F: PartialOrdBy<T>,
+    {
+        if let Some(computed) = self.computed(field) {
+            return Some(computed);
+        }
+        if self.field_cmp::<T>(field).custom.is_some() {
+            return None;
+        }
+        let projection = field.projection()?;
+        Some(Rc::new(projection))
+    }
+
+    /// Returns true if sorting `T` compares keys computed once per item for any field in the sort. See [`Self::projected`].
+    fn sorts_by_keys<T: 'static>(&self) -> bool
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        if self.has_computed::<T>() {
+            return true;
+        }
+        let field = self.field.read();
+        let then_by = self.then_by.read();
+        let tie_breakers = field.tie_breakers();
+        sort_chain(&*field, *self.direction.read(), &then_by, &tie_breakers)
+            .into_iter()
+            .any(|(field, _, _)| self.projected::<T>(field).is_some())
+    }
+
+    /// Returns a field's comparer for `T`: any comparator from [`Self::with_comparator`], otherwise its [`PartialOrdBy`].
+    pub(crate) fn field_cmp<'f, T: 'static>(&self, field: &'f F) -> FieldCmp<'f, T, F>
+    where
+        F: PartialEq,
+    {
+        let custom = self
+            .comparators
+            .read()
+            .iter()
+            .filter(|(f, _)| f == field)
+            .find_map(|(_, c)| c.downcast_ref::<Comparator<T>>().cloned());
+        FieldCmp { field, custom }
+    }
+
+    /// Swaps each field in a comparison chain for its comparer (see [`Self::field_cmp`]) and applies any [`Self::set_null_handling`] overrides.
+    pub(crate) fn compare_chain<'f, T: 'static>(
+        &self,
+        chain: Vec<SortKey<&'f F>>,
+    ) -> Vec<SortKey<FieldCmp<'f, T, F>>>
+    where
+        F: PartialEq,
+    {
+        let overrides = self.null_handling.read();
+        chain
+            .into_iter()
+            .map(|(field, dir, nulls)| {
+                let nulls = overrides
+                    .iter()
+                    .find(|(f, _)| f == field)
+                    .map_or(nulls, |(_, nulls)| *nulls);
+                (self.field_cmp(field), dir, nulls)
+            })
+            .collect()
+    }
+
+    /// Returns how a field orders `NULL` values: any override from [`Self::set_null_handling`], otherwise [`Sortable::null_handling`].
+    pub fn null_handling(&self, field: &F) -> NullHandling
     where
         F: Sortable,
     {
-        match field.sort_by() {
-            None => (), // Do nothing, don't switch to unsortable
-            Some(sort_by) => {
-                use SortBy::*;
-                match sort_by {
-                    Fixed(dir) => self.direction.set(dir),
-                    Reversible(dir) => {
-                        // Invert direction if the same field
-                        let dir = if *self.field.get() == field {
-                            self.direction.get().invert()
-                        } else {
-                            // Reset state to new field
-                            dir
-                        };
-                        self.direction.set(dir);
-                    }
-                }
-                self.field.set(field);
+        self.null_handling
+            .read()
+            .iter()
+            .find(|(f, _)| f == field)
+            .map_or_else(|| field.null_handling(), |(_, nulls)| *nulls)
+    }
+
+    /// Overrides how a field orders `NULL` values at runtime, in place of [`Sortable::null_handling`]. Useful for letting users choose whether unknown values come first or last. See [`crate::NullsToggle`].
+    ///
+    /// Applies wherever the field is sorted by, including secondary sorts, tie-breakers and [`crate::UseGrouper::sort`]. Isn't part of [`SorterState`] so doesn't fire [`Self::on_change`] or pass through middleware. Kept by [`Self::clear`].
+    pub fn set_null_handling(&self, field: F, nulls: NullHandling)
+    where
+        F: Sortable,
+    {
+        let mut overrides = self.null_handling;
+        let position = overrides.peek().iter().position(|(f, _)| *f == field);
+        match position {
+            Some(i) if overrides.peek()[i].1 == nulls => (),
+            Some(i) => overrides.write()[i].1 = nulls,
+            None => overrides.write().push((field, nulls)),
+        }
+    }
+
+    /// Removes any override from [`Self::set_null_handling`], returning the field to [`Sortable::null_handling`].
+    pub fn reset_null_handling(&self, field: &F)
+    where
+        F: PartialEq,
+    {
+        let mut overrides = self.null_handling;
+        let position = overrides.peek().iter().position(|(f, _)| f == field);
+        if let Some(i) = position {
+            overrides.write().remove(i);
+        }
+    }
+
+    /// Stops users sorting by a field at runtime e.g., while its data is still loading or if they lack permission. [`Self::toggle_field`], [`Self::set_field`] and [`Self::add_sort`] ignore it and [`crate::Th`] renders it as a plain header. Undo with [`Self::enable_field`].
+    ///
+    /// Doesn't change the current sort: if the field is already sorted by then it stays so until replaced or removed with [`Self::remove_sort`].
+    pub fn disable_field(&self, field: F)
+    where
+        F: PartialEq,
+    {
+        let mut disabled = self.disabled;
+        if !disabled.peek().contains(&field) {
+            disabled.write().push(field);
+        }
+    }
+
+    /// Lets users sort by a field turned off with [`Self::disable_field`] again.
+    pub fn enable_field(&self, field: &F)
+    where
+        F: PartialEq,
+    {
+        let mut disabled = self.disabled;
+        let position = disabled.peek().iter().position(|f| f == field);
+        if let Some(i) = position {
+            disabled.write().remove(i);
+        }
+    }
+
+    /// Returns false if the field was turned off with [`Self::disable_field`]. Says nothing of whether the field is sortable, see [`Sortable::sort_by`].
+    pub fn is_enabled(&self, field: &F) -> bool
+    where
+        F: PartialEq,
+    {
+        !self.disabled.read().contains(field)
+    }
+
+    /// Returns true if the field was turned off with [`Self::disable_field`]. Doesn't subscribe so may be used in setters.
+    fn is_disabled(&self, field: &F) -> bool
+    where
+        F: PartialEq,
+    {
+        self.disabled.peek().contains(field)
+    }
+
+    /// Returns the sort algorithm used by [`Self::sort`].
+    pub fn algorithm(&self) -> SortAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns where sorting happens.
+    pub fn mode(&self) -> SortMode {
+        self.mode
+    }
+
+    /// Returns true if [`Self::sort`] and friends should reorder items: sorting locally and not toggled back to unsorted.
+    pub(crate) fn sorts_locally(&self) -> bool {
+        self.mode == SortMode::Local && self.is_sorted()
+    }
+
+    /// Returns false if the sorter is in the unsorted (original) order: either toggled back to it (see [`SortBy::FixedWithReset`], [`SortBy::ReversibleWithReset`] and [`SortBy::Cycle`]) or started there with [`UseSorterBuilder::initially_unsorted`].
+    pub fn is_sorted(&self) -> bool {
+        *self.sorted.read()
+    }
+
+    /// Sets the sort field and toggles the direction (if applicable). Ignores unsortable, disabled (see [`Self::disable_field`]) and [`SortBy::GroupOnly`] fields. Replaces any secondary sorts from [`Self::add_sort`].
+    pub fn toggle_field(&self, field: F)
+    where
+        F: Copy + Sortable,
+    {
+        if self.is_disabled(&field) {
+            return;
+        }
+        if sortable_by(&field).is_some() {
+            self.clear_then_by();
+        }
+        self.toggle_primary(field);
+    }
+
+    /// Toggles the active field like [`Self::toggle_field`] but keeps secondary sorts unless toggled back to unsorted.
+    fn toggle_primary(&self, field: F)
+    where
+        F: Copy + Sortable,
+    {
+        // Do nothing, don't switch to unsortable
+        let Some(sort_by) = field_sort_by(&field, &self.field_directions.peek()) else {
+            return;
+        };
+        let current = *self.direction.peek();
+        let state = (*self.field.peek() == field).then(|| match *self.sorted.peek() {
+            true => SortState::Sorted(current),
+            false => SortState::Unsorted,
+        });
+        match sort_by.next_state(state) {
+            Some(SortState::Sorted(dir)) => self.update(field, dir, true),
+            Some(SortState::Unsorted) => {
+                self.clear_then_by();
+                self.update(field, current, false);
             }
+            None => (),
         }
     }
 
-    /// Sets the sort field and direction state directly. Ignores unsortable fields. Ignores the direction if not valid for a field.
+    /// Sets the sort field and direction state directly. Ignores unsortable and disabled fields. Ignores the direction if not valid for a field. Replaces any secondary sorts from [`Self::add_sort`].
     pub fn set_field(&self, field: F, dir: Direction)
     where
-        F: Sortable,
+        F: Copy + Sortable,
     {
-        match field.sort_by() {
+        match sortable_by(&field).filter(|_| !self.is_disabled(&field)) {
             None => (), // Do nothing, ignore unsortable
             Some(sort_by) => {
                 // Set state but ensure direction is valid
                 let dir = sort_by.ensure_direction(dir);
-                self.field.set(field);
-                self.direction.set(dir);
+                self.clear_then_by();
+                self.update(field, dir, true);
             }
         }
     }
 
-    /// Sorts items according to the current field and direction.
+    /// Sets the direction of the current field. Ignores the direction if not valid for the field or the field is disabled. Resumes sorting if toggled back to unsorted. Keeps any secondary sorts.
     ///
-    /// This is not a hook and may be called conditionally. For example:
-    /// - If data is coming from a `use_future` then you can call this fn once it has completed.
-    /// - If you need to apply a filter, do so before calling this fn.
-    pub fn sort<T>(&self, items: &mut [T])
+    /// Like the other state setters, useful for driving the sorter from toolbar buttons or keyboard shortcuts.
+    pub fn set_direction(&self, dir: Direction)
     where
-        F: PartialOrdBy<T> + Sortable,
+        F: Copy + Sortable,
     {
-        let (field, dir) = self.get_state();
-        sort_by(field, *dir, field.null_handling(), items);
+        let field = *self.field.peek();
+        if let Some(sort_by) = sortable_by(&field).filter(|_| !self.is_disabled(&field)) {
+            self.update(field, sort_by.ensure_direction(dir), true);
+        }
     }
-}
 
-fn sort_by<T, F: PartialOrdBy<T>>(
-    sort_by: &F,
-    dir: Direction,
-    nulls: NullHandling,
-    items: &mut [T],
-) {
-    items.sort_by(|a, b| {
-        let partial = sort_by.partial_cmp_by(a, b);
-        partial.map_or_else(
-            || {
-                let a_is_null = sort_by.partial_cmp_by(a, a).is_none();
-                let b_is_null = sort_by.partial_cmp_by(b, b).is_none();
-                match (a_is_null, b_is_null) {
-                    (true, true) => Ordering::Equal,
-                    (true, false) => match nulls {
-                        NullHandling::First => Ordering::Less,
-                        NullHandling::Last => Ordering::Greater,
-                    },
-                    (false, true) => match nulls {
-                        NullHandling::First => Ordering::Greater,
-                        NullHandling::Last => Ordering::Less,
-                    },
-                    // Uh-oh, first partial_cmp_by should not have returned None
-                    (false, false) => unreachable!(),
+    /// Adds a field as a secondary sort, applied after the active field and any earlier secondary sorts to order items that compare as equal. Like shift-clicking a header in a spreadsheet. Ignores unsortable and disabled fields.
+    ///
+    /// If the field is already sorted by, toggles its direction instead. A secondary sort toggled to unsorted (see [`SortBy::ReversibleWithReset`]) is removed. Toggles the active field if unsorted.
+    ///
+    /// Secondary sorts don't fire [`Self::on_change`] or pass through middleware and aren't sent with [`Self::sort_request`]. Changing the active field with [`Self::toggle_field`] or [`Self::set_field`] replaces them.
+    pub fn add_sort(&self, field: F)
+    where
+        F: Copy + Sortable,
+    {
+        let Some(sort_by) = field_sort_by(&field, &self.field_directions.peek()) else {
+            return;
+        };
+        if self.is_disabled(&field) {
+            return;
+        }
+        if !*self.sorted.peek() || *self.field.peek() == field {
+            self.toggle_primary(field);
+            return;
+        }
+        let mut then_by = self.then_by;
+        let position = then_by.peek().iter().position(|(f, _)| *f == field);
+        match position {
+            Some(i) => {
+                let current = then_by.peek()[i].1;
+                match sort_by.next_state(Some(SortState::Sorted(current))) {
+                    Some(SortState::Sorted(dir)) => then_by.write()[i].1 = dir,
+                    Some(SortState::Unsorted) => {
+                        then_by.write().remove(i);
+                    }
+                    None => (),
                 }
-            },
-            // Reversal must be applied per item to avoid ordering NULLs
-            |o| match dir {
-                Direction::Ascending => o,
-                Direction::Descending => o.reverse(),
-            },
-        )
-    });
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[derive(Clone, Debug, Default, PartialEq)]
-    struct Row(f64);
-
-    #[derive(Copy, Clone, Debug, Default, PartialEq)]
-    enum RowField {
-        #[default]
-        Value,
+            }
+            None => then_by.write().push((field, sort_by.direction())),
+        }
     }
 
-    impl PartialOrdBy<Row> for RowField {
-        fn partial_cmp_by(&self, a: &Row, b: &Row) -> Option<Ordering> {
-            match self {
-                Self::Value => a.0.partial_cmp(&b.0),
+    /// Removes a field from the sort. Like ctrl-clicking a header in a spreadsheet. A secondary sort is dropped. The active field is replaced by the first secondary sort or, with none, returns to the unsorted order if the field allows it (see [`SortBy::ReversibleWithReset`]).
+    pub fn remove_sort(&self, field: F)
+    where
+        F: Copy + Sortable,
+    {
+        let mut then_by = self.then_by;
+        let position = then_by.peek().iter().position(|(f, _)| *f == field);
+        if let Some(i) = position {
+            then_by.write().remove(i);
+            return;
+        }
+        if !*self.sorted.peek() || *self.field.peek() != field {
+            return;
+        }
+        if then_by.peek().is_empty() {
+            let can_reset = field_sort_by(&field, &self.field_directions.peek())
+                .is_some_and(|sort_by| sort_by.can_reset());
+            if can_reset {
+                self.update(field, *self.direction.peek(), false);
             }
+        } else {
+            let (next, dir) = then_by.write().remove(0);
+            self.update(next, dir, true);
         }
     }
 
-    #[test]
-    fn test_sort_by() {
-        use Direction::*;
-        use NullHandling::*;
+    /// Returns the secondary sorts added with [`Self::add_sort`] in priority order.
+    pub fn then_by(&self) -> Vec<(F, Direction)>
+    where
+        F: Clone,
+    {
+        self.then_by.cloned()
+    }
+
+    /// Returns every field being sorted by with its direction in priority order: the active field followed by any secondary sorts. Empty if unsorted.
+    pub fn sort_keys(&self) -> Vec<(F, Direction)>
+    where
+        F: Clone,
+    {
+        if !self.is_sorted() {
+            return Vec::new();
+        }
+        let mut keys = vec![self.get_state()];
+        keys.extend(self.then_by());
+        keys
+    }
+
+    /// Returns a field's position in [`Self::sort_keys`]: 0 for the active field, 1 for the first secondary sort and so on. `None` if not sorted by.
+    pub fn priority(&self, field: &F) -> Option<usize>
+    where
+        F: Clone + PartialEq,
+    {
+        self.sort_keys().iter().position(|(f, _)| f == field)
+    }
+
+    /// Returns true if the field is the active (primary) sort. False if unsorted. Use to style a manual table's sorted column. See [`Self::cell_sort`].
+    pub fn is_active(&self, field: &F) -> bool
+    where
+        F: PartialEq,
+    {
+        self.is_sorted() && *self.field.read() == *field
+    }
+
+    /// Returns how a field's column relates to the current sort: the active field and direction along with the column's own direction and priority. Call once per column, not per cell.
+    pub fn cell_sort(&self, field: &F) -> CellSort<F>
+    where
+        F: Clone + PartialEq,
+    {
+        let keys = self.sort_keys();
+        let priority = keys.iter().position(|(f, _)| f == field);
+        CellSort {
+            active: keys.first().cloned(),
+            direction: priority.map(|priority| keys[priority].1),
+            priority,
+        }
+    }
+
+    /// Returns true if an item's field is `NULL` as sorting sees it: by any comparator from [`Self::with_comparator`], otherwise its [`PartialOrdBy`]. Pass to [`crate::NullableCell`] so cells shown as unknown are the ones sorted as `NULL`.
+    pub fn is_null<T: 'static>(&self, field: &F, item: &T) -> bool
+    where
+        F: PartialOrdBy<T> + PartialEq,
+    {
+        is_null(&self.field_cmp(field), item)
+    }
+
+    fn clear_then_by(&self) {
+        let mut then_by = self.then_by;
+        if !then_by.peek().is_empty() {
+            then_by.write().clear();
+        }
+    }
+
+    /// Steps the current field to its next state as if its header were clicked. For example, reverses a [`SortBy::Reversible`] field. See [`Self::toggle_field`].
+    pub fn cycle_direction(&self)
+    where
+        F: Copy + Sortable,
+    {
+        let field = *self.field.peek();
+        self.toggle_field(field);
+    }
+
+    /// Resets to the field and direction the sorter was created with, including [`UseSorterBuilder::initially_unsorted`]. Removes any secondary sorts and forgets any custom order (see [`Self::move_row`]). See [`UseSorterBuilder`].
+    ///
+    /// With [`UseSorterBuilder::with_storage_key`], this is the restored state rather than the builder's.
+    pub fn clear(&self)
+    where
+        F: Copy + PartialEq,
+    {
+        let (field, direction, sorted) = *self.initial.peek();
+        self.clear_then_by();
+        self.clear_custom_order();
+        self.update(field, direction, sorted);
+    }
+
+    /// Remembers the order of items as loaded, by a unique key per item e.g., `|row| row.id`. [`Self::sort`] reorders items in place so once data kept in a signal has been sorted, its load order is lost. Capture it first and [`Self::restore_baseline`] can put it back. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Person { id: u32, name: String }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum PersonField { #[default] Name }
+    /// # impl PartialOrdBy<Person> for PersonField {
+    /// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { a.name.partial_cmp(&b.name) }
+    /// # }
+    /// # impl Sortable for PersonField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// #[component]
+    /// fn People(loaded: Vec<Person>) -> Element {
+    ///     let sorter = use_sorter::<PersonField>();
+    ///     let mut people = use_signal(|| {
+    ///         sorter.capture_baseline(&loaded, |person: &Person| person.id);
+    ///         loaded
+    ///     });
+    ///     rsx! {
+    ///         button { onclick: move |_| sorter.sort(&mut people.write()), "Sort" }
+    ///         button { onclick: move |_| { sorter.restore_baseline(&mut people.write()); }, "Load order" }
+    ///         for person in people.iter() {
+    ///             p { "{person.name}" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Call again when new data is loaded. Replaces any earlier baseline for `T`. Not needed if you pass a fresh copy of your data to [`Self::sort`] each render.
+    pub fn capture_baseline<T: 'static, K: Eq + Hash + 'static>(
+        &self,
+        items: &[T],
+        key: impl Fn(&T) -> K + 'static,
+    ) {
+        let positions = items
+            .iter()
+            .enumerate()
+            .map(|(position, item)| (key(item), position))
+            .collect::<HashMap<_, _>>();
+        let baseline: Baseline<T> = Rc::new(move |item| positions.get(&key(item)).copied());
+        let mut slot = self.baselines;
+        let mut baselines = slot.write();
+        baselines.retain(|b| !b.is::<Baseline<T>>());
+        baselines.push(Rc::new(baseline));
+    }
+
+    /// Returns true if a load order for `T` has been captured with [`Self::capture_baseline`].
+    pub fn has_baseline<T: 'static>(&self) -> bool {
+        self.baselines.read().iter().any(|b| b.is::<Baseline<T>>())
+    }
+
+    /// Puts items back in the order captured by [`Self::capture_baseline`] and marks the sorter as unsorted, removing any secondary sorts and forgetting any custom order, so headers show no sort. Items added since the capture go last in their current order. Returns false, changing nothing, if no baseline for `T` was captured.
+    pub fn restore_baseline<T: 'static>(&self, items: &mut [T]) -> bool
+    where
+        F: Copy + PartialEq,
+    {
+        let baseline = self
+            .baselines
+            .peek()
+            .iter()
+            .find_map(|b| b.downcast_ref::<Baseline<T>>().cloned());
+        let Some(baseline) = baseline else {
+            return false;
+        };
+        let positions = items.iter().map(|item| baseline(item)).collect::<Vec<_>>();
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        indices.sort_by_key(|&index| positions[index].unwrap_or(usize::MAX));
+        apply_permutation(items, indices);
+        let (field, direction) = (*self.field.peek(), *self.direction.peek());
+        self.clear_then_by();
+        self.clear_custom_order();
+        self.update(field, direction, false);
+        true
+    }
+
+    /// Moves a row dragged by hand from one position to another and switches to the resulting custom order. Positions are those shown i.e., after [`Self::sort`]. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Task { title: String }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum TaskField { #[default] Title }
+    /// # impl PartialOrdBy<Task> for TaskField {
+    /// #     fn partial_cmp_by(&self, a: &Task, b: &Task) -> Option<std::cmp::Ordering> { a.title.partial_cmp(&b.title) }
+    /// # }
+    /// # impl Sortable for TaskField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// #[component]
+    /// fn Tasks(loaded: Vec<Task>) -> Element {
+    ///     let sorter = use_sorter::<TaskField>();
+    ///     let mut tasks = use_signal(|| loaded);
+    ///     let mut dragging = use_signal(|| None);
+    ///     let mut shown = tasks();
+    ///     sorter.sort(&mut shown);
+    ///     rsx! {
+    ///         if sorter.is_custom_order() { p { "Custom order" } }
+    ///         button { onclick: move |_| sorter.restore_custom_order(), "Back to my order" }
+    ///         for (position, task) in shown.into_iter().enumerate() {
+    ///             p {
+    ///                 draggable: "true",
+    ///                 ondragstart: move |_| dragging.set(Some(position)),
+    ///                 ondragover: move |evt| evt.prevent_default(),
+    ///                 ondrop: move |_| {
+    ///                     if let Some(from) = dragging.take() {
+    ///                         sorter.move_row(&mut tasks.write(), from, position);
+    ///                     }
+    ///                 },
+    ///                 "{task.title}"
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Dragging a row while sorted by a column conflicts with that sort. So items are first sorted in place, keeping the order the user sees, and then the row is moved. The sorter becomes unsorted, removing any secondary sorts, so headers show no sort: [`Self::is_custom_order`] is then true. Clicking a header sorts by that column as usual. Toggling back to unsorted (see [`SortBy::ReversibleWithReset`]) or calling [`Self::restore_custom_order`] returns to the custom order as long as items were sorted as a copy rather than in place.
+    ///
+    /// Positions past the end move the row last. Does nothing if `from` is out of bounds.
+    pub fn move_row<T: 'static>(&self, items: &mut [T], from: usize, to: usize)
+    where
+        F: Copy + PartialOrdBy<T> + Sortable,
+    {
+        if from >= items.len() {
+            return;
+        }
+        self.sort(items);
+        let to = to.min(items.len() - 1);
+        match from < to {
+            true => items[from..=to].rotate_left(1),
+            false => items[to..=from].rotate_right(1),
+        }
+        let (field, direction) = (*self.field.peek(), *self.direction.peek());
+        self.clear_then_by();
+        self.update(field, direction, false);
+        let mut custom = self.custom;
+        if !*custom.peek() {
+            custom.set(true);
+        }
+    }
+
+    /// Returns true if rows are shown in the custom order set by [`Self::move_row`]: rows have been moved by hand and no column is sorted by since.
+    pub fn is_custom_order(&self) -> bool {
+        *self.custom.read() && !self.is_sorted()
+    }
+
+    /// Returns true if rows have been moved by hand with [`Self::move_row`], even if since sorted by a column.
+    pub fn has_custom_order(&self) -> bool {
+        *self.custom.read()
+    }
+
+    /// Returns to the custom order set by [`Self::move_row`] by marking the sorter as unsorted and removing any secondary sorts. Does nothing without a custom order.
+    pub fn restore_custom_order(&self)
+    where
+        F: Copy + PartialEq,
+    {
+        if !*self.custom.peek() {
+            return;
+        }
+        let (field, direction) = (*self.field.peek(), *self.direction.peek());
+        self.clear_then_by();
+        self.update(field, direction, false);
+    }
+
+    /// Forgets the custom order set by [`Self::move_row`]. Items aren't reordered: the unsorted order is whatever order they're in.
+    pub fn clear_custom_order(&self) {
+        let mut custom = self.custom;
+        if *custom.peek() {
+            custom.set(false);
+        }
+    }
+
+    /// Returns the signals holding the field, direction, sorted state and secondary sorts.
+    pub(crate) fn state_signals(&self) -> StateSignals<F> {
+        (self.field, self.direction, self.sorted, self.then_by)
+    }
+
+    /// Returns a sorter sharing everything but its state, which is held in the given signals. See [`Self::use_debounced`].
+    pub(crate) fn with_state_signals(&self, signals: StateSignals<F>) -> Self {
+        let (field, direction, sorted, then_by) = signals;
+        Self {
+            field,
+            direction,
+            sorted,
+            then_by,
+            ..*self
+        }
+    }
+
+    /// Sets the field, direction and sorted state directly, skipping middleware and callbacks. Used by [`crate::use_controlled_sorter`] to follow its parent.
+    pub(crate) fn sync(&self, state: SorterState<F>)
+    where
+        F: Copy + PartialEq,
+    {
+        let Self {
+            mut field,
+            mut direction,
+            mut sorted,
+            ..
+        } = *self;
+        if *field.peek() != state.field {
+            field.set(state.field);
+        }
+        if *direction.peek() != state.direction {
+            direction.set(state.direction);
+        }
+        if *sorted.peek() != state.sorted {
+            sorted.set(state.sorted);
+        }
+    }
+
+    /// Sets all sort state at once. Does nothing if unchanged, otherwise passes the change through any [`Self::with_middleware`], fires any [`Self::on_change`] callback and sends it to any [`Self::changes`] streams.
+    pub(crate) fn update(&self, field: F, direction: Direction, sorted: bool)
+    where
+        F: Copy + PartialEq,
+    {
+        let from = SorterState {
+            field: *self.field.peek(),
+            direction: *self.direction.peek(),
+            sorted: *self.sorted.peek(),
+        };
+        let mut to = SorterState {
+            field,
+            direction,
+            sorted,
+        };
+        if to == from {
+            return;
+        }
+        // Clone out so middleware may use the sorter
+        let middleware = self.middleware.peek().clone();
+        for (_, middleware) in &middleware {
+            match middleware.before_change(&from, to) {
+                Some(next) => to = next,
+                None => return,
+            }
+        }
+        if to == from {
+            return;
+        }
+        let SorterState {
+            field,
+            direction,
+            sorted,
+        } = to;
+        let Self {
+            field: mut field_state,
+            direction: mut direction_state,
+            sorted: mut sorted_state,
+            ..
+        } = *self;
+        field_state.set(field);
+        direction_state.set(direction);
+        sorted_state.set(sorted);
+        #[cfg(feature = "web")]
+        if let Some(storage) = self.storage {
+            storage.save(&field, direction);
+        }
+        // Clone out so the callback may use the sorter
+        let on_change = self.on_change.peek().clone();
+        if let Some(on_change) = on_change {
+            on_change(field, direction);
+        }
+        let mut changes = self.changes;
+        changes
+            .write()
+            .retain(|sender| sender.unbounded_send(to).is_ok());
+        #[cfg(feature = "debug")]
+        self.write_stats(|stats| stats.record_transition(from, to));
+        for (_, middleware) in &middleware {
+            middleware.after_change(&from, &to);
+        }
+    }
+
+    /// Sorts items according to the current field and direction.
+    ///
+    /// The sort is stable unless [`SortAlgorithm::Unstable`] was chosen: items that compare as equal keep their relative order.
+    ///
+    /// Does nothing if the sorter is unsorted (see [`Self::is_sorted`]), leaving items in their original order. Pass a fresh copy of your data each render so that the original order can be restored. Also does nothing in [`SortMode::Remote`].
+    ///
+    /// This is not a hook and may be called conditionally. For example:
+    /// - If data is coming from a `use_future` then you can call this fn once it has completed.
+    /// - If you need to apply a filter, do so before calling this fn.
+    pub fn sort<T: 'static>(&self, items: &mut [T])
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        if !self.sorts_locally() {
+            self.record_sort(items.len(), items.len(), 0);
+            return;
+        }
+        if self.sorts_by_keys::<T>() {
+            // Keys are cached by index so sort indices instead
+            let mut indices = (0..items.len()).collect::<Vec<_>>();
+            self.sort_indices_with(|index| &items[index], &mut indices, false);
+            apply_permutation(items, indices);
+            return;
+        }
+        let field = self.field.read();
+        let then_by = self.then_by.read();
+        let tie_breakers = field.tie_breakers();
+        let chain = self.compare_chain(sort_chain(
+            &*field,
+            *self.direction.read(),
+            &then_by,
+            &tie_breakers,
+        ));
+        self.check_chain(&chain, |index| &items[index], items.len());
+        let nulls = self.timed(items.len(), || sort_by(&chain, self.algorithm, items));
+        self.record_sort(items.len(), items.len(), nulls);
+    }
+
+    /// Sorts items like [`Self::sort`] for fields implementing [`OrdBy`]. As there are no `NULL` values, skips the pass grouping them and its allocations.
+    pub fn sort_ord<T>(&self, items: &mut [T])
+    where
+        F: OrdBy<T> + Sortable,
+    {
+        self.record_sort(items.len(), items.len(), 0);
+        if !self.sorts_locally() {
+            return;
+        }
+        let field = self.field.read();
+        let then_by = self.then_by.read();
+        let tie_breakers = field.tie_breakers();
+        let chain = sort_chain(&*field, *self.direction.read(), &then_by, &tie_breakers);
+        self.timed(items.len(), || sort_ord_by(&chain, self.algorithm, items));
+    }
+
+    /// Sorts only the first `n` items, e.g., for a leaderboard showing the top rows of a large dataset. Returns them: the same as the first `n` items after [`Self::sort`]. The rest are left in no particular order. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Player { name: String, score: u32 }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum PlayerField { #[default] Score }
+    /// # impl PartialOrdBy<Player> for PlayerField {
+    /// #     fn partial_cmp_by(&self, a: &Player, b: &Player) -> Option<std::cmp::Ordering> { a.score.partial_cmp(&b.score) }
+    /// # }
+    /// # impl Sortable for PlayerField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::decreasing_or_increasing() }
+    /// # }
+    /// #[component]
+    /// fn Leaderboard(players: Vec<Player>) -> Element {
+    ///     let sorter = use_sorter::<PlayerField>();
+    ///     let mut players = players;
+    ///     let top = sorter.sort_top_n(&mut players, 10);
+    ///     rsx! {
+    ///         for player in top.iter() {
+    ///             p { "{player.name}: {player.score}" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Selects the top items in linear time then sorts just those, so costs O(len + n log n) comparisons rather than O(len log len). Respects direction, secondary sorts and `NULL` handling: with [`NullHandling::Remove`] on the active field, `NULL` items are left out so fewer than `n` may be returned. Fields with a [`ComputedField`] or [`PartialOrdBy::projection`] fall back to a full sort.
+    ///
+    /// Returns the first `n` items unchanged if unsorted or in [`SortMode::Remote`].
+    pub fn sort_top_n<'a, T: 'static>(&self, items: &'a mut [T], n: usize) -> &'a mut [T]
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let n = n.min(items.len());
+        if !self.sorts_locally() {
+            self.record_sort(items.len(), n, 0);
+            return &mut items[..n];
+        }
+        let nulls = if self.sorts_by_keys::<T>() {
+            self.sort(items);
+            self.last_sort.peek().map_or(0, |(_, _, nulls)| nulls)
+        } else {
+            let field = self.field.read();
+            let then_by = self.then_by.read();
+            let tie_breakers = field.tie_breakers();
+            let chain = self.compare_chain(sort_chain(
+                &*field,
+                *self.direction.read(),
+                &then_by,
+                &tie_breakers,
+            ));
+            self.check_chain(&chain, |index| &items[index], items.len());
+            self.timed(items.len(), || {
+                sort_top_n_by(&chain, self.algorithm, items, n)
+            })
+        };
+        let field = self.field.read();
+        let visible = match self.null_handling(&field) {
+            // Sorted last so stop at the first
+            NullHandling::Remove => {
+                let field = self.field_cmp(&*field);
+                items[..n].partition_point(|item| !is_null(&field, item))
+            }
+            _ => n,
+        };
+        self.record_sort(items.len(), visible, nulls);
+        &mut items[..visible]
+    }
+
+    /// Returns the indices of items in sorted order without modifying them. Useful for large or shared data that would otherwise need cloning before calling [`Self::sort`]. Otherwise behaves like [`Self::sort`].
+    ///
+    /// Returns indices in their original order if unsorted or in [`SortMode::Remote`].
+    pub fn sort_indices<T: 'static>(&self, items: &[T]) -> Vec<usize>
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        self.sort_index_slice(items, &mut indices);
+        self.notify_sorted(&indices);
+        indices
+    }
+
+    /// Fires any [`Self::on_sorted`] callback if the order has changed since the last call.
+    pub(crate) fn notify_sorted(&self, indices: &[usize]) {
+        let on_sorted = self.on_sorted.peek().clone();
+        let Some(on_sorted) = on_sorted else {
+            return;
+        };
+        let mut last = self.last_indices;
+        if *last.peek() == indices {
+            return;
+        }
+        let previous = std::mem::replace(&mut *last.write(), indices.to_vec());
+        if !previous.is_empty() {
+            on_sorted(&previous, indices);
+        }
+    }
+
+    /// Sorts a subset of indices into items. Used to sort filtered views without cloning. Removes `NULL` items for [`NullHandling::Remove`].
+    pub(crate) fn sort_index_slice<T: 'static>(&self, items: &[T], indices: &mut Vec<usize>)
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        self.sort_indices_with(|index| &items[index], indices, true);
+    }
+
+    /// Sorts indices into items looked up by `item`. Optionally removes `NULL` items for [`NullHandling::Remove`].
+    pub(crate) fn sort_indices_with<'a, T: 'static>(
+        &self,
+        item: impl Fn(usize) -> &'a T + Copy,
+        indices: &mut Vec<usize>,
+        remove_nulls: bool,
+    ) where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let total = indices.len();
+        if !self.sorts_locally() {
+            self.record_sort(total, total, 0);
+            return;
+        }
+        let nulls = self.timed(total, || {
+            self.sort_indices_unrecorded(item, indices, remove_nulls)
+        });
+        self.record_sort(total, indices.len(), nulls);
+    }
+
+    /// Sorts indices like [`Self::sort_indices_with`] without recording timings or [`Self::last_sort_stats`], for callers sorting several groups as one. Returns the number of `NULL`s on the primary field, including any removed. Callers check [`Self::sorts_locally`] first.
+    pub(crate) fn sort_indices_unrecorded<'a, T: 'static>(
+        &self,
+        item: impl Fn(usize) -> &'a T + Copy,
+        indices: &mut Vec<usize>,
+        remove_nulls: bool,
+    ) -> usize
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let total = indices.len();
+        let field = self.field.read();
+        let then_by = self.then_by.read();
+        let tie_breakers = field.tie_breakers();
+        let chain = self.compare_chain(sort_chain(
+            &*field,
+            *self.direction.read(),
+            &then_by,
+            &tie_breakers,
+        ));
+        let remove_nulls = remove_nulls && self.null_handling(&field) == NullHandling::Remove;
+        self.check_chain(&chain, item, indices.len());
+        let computed = chain
+            .iter()
+            .map(|(cmp, _, _)| self.projected(cmp.field))
+            .collect::<Vec<_>>();
+        if computed.iter().any(Option::is_some) {
+            return sort_indices_cached(
+                chain,
+                &computed,
+                self.algorithm,
+                item,
+                indices,
+                remove_nulls,
+            );
+        }
+        if remove_nulls {
+            indices.retain(|&index| !is_null(&chain[0].0, item(index)));
+        }
+        let removed = total - indices.len();
+        removed + sort_indices_by(&chain, self.algorithm, item, indices)
+    }
+
+    /// Records row counts for [`Self::last_sort_stats`].
+    pub(crate) fn record_sort(&self, total: usize, visible: usize, nulls: usize) {
+        let mut last_sort = self.last_sort;
+        last_sort.set(Some((total, visible, nulls)));
+    }
+
+    /// Replaces the total of the last sort e.g., with the rows before filtering. See [`crate::FilteredSorted`].
+    pub(crate) fn record_total(&self, total: usize) {
+        let mut last_sort = self.last_sort;
+        let last = *last_sort.peek();
+        if let Some((_, visible, nulls)) = last {
+            last_sort.set(Some((total, visible, nulls)));
+        }
+    }
+
+    /// Returns row counts from the last call to [`Self::sort`], [`Self::sort_ord`], [`Self::sort_top_n`], [`Self::sort_indices`] (and the fns built on it) or [`crate::FilteredSorted::new`], with the current sort. `None` before the first sort. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Person { name: String, age: Option<u8> }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum PersonField { #[default] Age }
+    /// # impl PartialOrdBy<Person> for PersonField {
+    /// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { comparators::option(&a.age, &b.age) }
+    /// # }
+    /// # impl Sortable for PersonField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// #     fn label(&self) -> &str { "Age" }
+    /// # }
+    /// #[component]
+    /// fn People(people: Vec<Person>) -> Element {
+    ///     let sorter = use_sorter::<PersonField>();
+    ///     let mut people = people;
+    ///     sorter.sort(&mut people);
+    ///     let stats = sorter.last_sort_stats().unwrap();
+    ///     rsx! {
+    ///         // Table of people
+    ///         p { "{stats.visible} rows · sorted by {stats.description()} · {stats.nulls} unknown" }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Not reactive: read it after sorting in the same render. Components re-render when their data or sorter changes anyway. Updated by every sort including [`Self::insert_sorted`] and [`Self::sort_by_cached_key`]. Counts `NULL`s while sorting rather than in a separate pass, except for [`Self::insert_sorted`] which counts them after merging.
+    pub fn last_sort_stats(&self) -> Option<LastSortStats<F>>
+    where
+        F: Clone,
+    {
+        let (total, visible, nulls) = (*self.last_sort.peek())?;
+        Some(LastSortStats {
+            total,
+            visible,
+            nulls,
+            sort_keys: self.sort_keys(),
+        })
+    }
+
+    /// Checks each link of a sort chain with [`check_compare`] in debug builds if [`UseSorterBuilder::with_checked_comparisons`] was set. Panics on the first inconsistency.
+    pub(crate) fn check_chain<'a, T: 'static>(
+        &self,
+        chain: &[SortKey<FieldCmp<'_, T, F>>],
+        item: impl Fn(usize) -> &'a T + Copy,
+        len: usize,
+    ) where
+        F: PartialOrdBy<T>,
+    {
+        if !cfg!(debug_assertions) || !self.checked {
+            return;
+        }
+        for (priority, (cmp, _, _)) in chain.iter().enumerate() {
+            // Computed values are compared by their keys rather than the field
+            if self.computed::<T>(cmp.field).is_some() {
+                continue;
+            }
+            if let Err(error) = check_compare(cmp, item, len) {
+                panic!(
+                    "inconsistent comparisons sorting by {} at priority {priority} (0 is the active field, then secondary sorts and tie-breakers): {error}",
+                    std::any::type_name::<F>()
+                );
+            }
+        }
+    }
+
+    /// Runs a sort, recording how long it took with the `debug` feature. See [`Self::stats`].
+    pub(crate) fn timed<R>(&self, items: usize, sort: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "debug")]
+        {
+            let timer = SortTimer::start();
+            let sorted = sort();
+            let timing = timer.finish(items);
+            self.write_stats(|stats| stats.record_sort(timing));
+            sorted
+        }
+        #[cfg(not(feature = "debug"))]
+        {
+            let _ = items;
+            sort()
+        }
+    }
+
+    /// Returns sort timings and recent state transitions. Requires the `debug` feature. See [`crate::SorterDebugPanel`].
+    ///
+    /// Not reactive: sorts usually run while rendering and components don't re-render as they're recorded. Read them after sorting in the same render, or after rendering e.g., in an effect as [`crate::SorterDebugPanel`] does.
+    #[cfg(feature = "debug")]
+    pub fn stats(&self) -> SortStats<F>
+    where
+        F: Clone,
+    {
+        self.stats.peek().clone()
+    }
+
+    /// Clears recorded stats. Requires the `debug` feature.
+    #[cfg(feature = "debug")]
+    pub fn reset_stats(&self) {
+        self.write_stats(|stats| *stats = SortStats::default());
+    }
+
+    #[cfg(feature = "debug")]
+    fn write_stats(&self, write: impl FnOnce(&mut SortStats<F>)) {
+        let mut stats = self.stats;
+        write(&mut stats.write());
+    }
+
+    /// Sorts items (see [`Self::sort`]) then, if the active field uses [`NullHandling::Remove`] (see [`Self::null_handling`]), removes items where it is `NULL`. Returns the removed items in their original relative order.
+    pub fn sort_and_remove_nulls<T: 'static>(&self, items: &mut Vec<T>) -> Vec<T>
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        self.sort(items);
+        let field = self.field.read();
+        if !self.sorts_locally() || self.null_handling(&field) != NullHandling::Remove {
+            return Vec::new();
+        }
+        // Sorted last so split them off
+        let field = self.field_cmp(&*field);
+        let first_null = items.partition_point(|item| !is_null(&field, item));
+        let nulls = items.split_off(first_null);
+        self.record_sort(items.len() + nulls.len(), items.len(), nulls.len());
+        nulls
+    }
+
+    /// Iterates over references to items in sorted order without modifying them. See [`Self::sort_indices`].
+    pub fn sorted_iter<'b, T: 'static>(&self, items: &'b [T]) -> impl Iterator<Item = &'b T>
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        self.sort_indices(items)
+            .into_iter()
+            .map(move |index| &items[index])
+    }
+
+    /// Sorts pointers to items such as `Vec<Rc<T>>` or `Vec<&T>` in place. Otherwise behaves like [`Self::sort`].
+    pub fn sort_deref<P: Deref>(&self, items: &mut [P])
+    where
+        P::Target: Sized + 'static,
+        F: PartialOrdBy<P::Target> + Sortable,
+    {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        self.sort_indices_with(|index| &*items[index], &mut indices, false);
+        apply_permutation(items, indices);
+    }
+
+    /// Sorts rows within sections, keeping heading rows in place. Rows between two headings (or before the first or after the last) are sorted among themselves. Useful for data interleaved with section headings or separators e.g., people grouped under the name of their team. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # use std::cmp::Ordering;
+    /// #[derive(Clone, PartialEq)]
+    /// enum Row {
+    ///     Team(String),
+    ///     Person { name: String },
+    /// }
+    ///
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum RowField { #[default] Name }
+    /// # impl Sortable for RowField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// impl PartialOrdBy<Row> for RowField {
+    ///     fn partial_cmp_by(&self, a: &Row, b: &Row) -> Option<Ordering> {
+    ///         match (a, b) {
+    ///             (Row::Person { name: a }, Row::Person { name: b }) => a.partial_cmp(b),
+    ///             // Headings are never compared
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[component]
+    /// fn Teams(rows: Vec<Row>) -> Element {
+    ///     let sorter = use_sorter::<RowField>();
+    ///     let mut rows = rows;
+    ///     sorter.sort_sections(&mut rows, |row| matches!(row, Row::Team(_)));
+    ///     rsx! {
+    ///         for row in rows {
+    ///             match row {
+    ///                 Row::Team(team) => rsx! { tr { th { colspan: 2, "{team}" } } },
+    ///                 Row::Person { name } => rsx! { tr { td { "{name}" } } },
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Otherwise behaves like [`Self::sort`]. To sort the sections themselves, see [`crate::UseGrouper`].
+    pub fn sort_sections<T: 'static>(&self, items: &mut [T], is_heading: impl Fn(&T) -> bool)
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        for section in items.split_mut(is_heading) {
+            self.sort(section);
+        }
+    }
+
+    /// Returns the indices of items sorted within sections like [`Self::sort_sections`] without modifying them. Unlike [`Self::sort_indices`], `NULL` items are kept for [`NullHandling::Remove`] so sections keep their rows.
+    pub fn sort_section_indices<T: 'static>(
+        &self,
+        items: &[T],
+        is_heading: impl Fn(&T) -> bool,
+    ) -> Vec<usize>
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        for section in indices.split_mut(|&index| is_heading(&items[index])) {
+            let mut sorted = section.to_vec();
+            self.sort_indices_with(|index| &items[index], &mut sorted, false);
+            section.copy_from_slice(&sorted);
+        }
+        self.notify_sorted(&indices);
+        indices
+    }
+
+    /// Adds items to an already sorted `Vec` so it stays sorted. Useful when pages of rows stream in e.g., from [`crate::use_infinite_scroll()`]: sort what's loaded once when the sort changes, then insert each new page rather than sorting everything again. For example:
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Person { name: String }
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum PersonField { #[default] Name }
+    /// # impl PartialOrdBy<Person> for PersonField {
+    /// #     fn partial_cmp_by(&self, a: &Person, b: &Person) -> Option<std::cmp::Ordering> { a.name.partial_cmp(&b.name) }
+    /// # }
+    /// # impl Sortable for PersonField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// # async fn fetch_page(page: usize) -> Vec<Person> { Vec::new() }
+    /// #[component]
+    /// fn People() -> Element {
+    ///     let sorter = use_sorter::<PersonField>();
+    ///     let mut people = use_signal(Vec::<Person>::new);
+    ///     // Sort what's loaded whenever the sort changes
+    ///     use_effect(move || sorter.sort(&mut people.write()));
+    ///     let load_more = move |page| async move {
+    ///         let page = fetch_page(page).await;
+    ///         sorter.insert_sorted(&mut people.write(), page);
+    ///     };
+    ///     rsx! {
+    ///         for person in people.iter() {
+    ///             p { "{person.name}" }
+    ///         }
+    ///         button { onclick: move |_| load_more(people.len() / 50), "Load more" }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// New items are sorted among themselves and then merged in, taking O(n + k log k) for k new items. They're placed after any equal items already present, so items keep their arrival order as with a stable sort. `NULL` items are placed by [`NullHandling`] and kept for [`NullHandling::Remove`] as with [`Self::sort`]. If unsorted or in [`SortMode::Remote`] then new items are appended. `items` must already be sorted by the current state, otherwise their order is unspecified.
+    pub fn insert_sorted<T: 'static>(
+        &self,
+        items: &mut Vec<T>,
+        new_items: impl IntoIterator<Item = T>,
+    ) where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        if !self.sorts_locally() {
+            items.extend(new_items);
+            self.record_sort(items.len(), items.len(), 0);
+            return;
+        }
+        let mut new_items = new_items.into_iter().collect::<Vec<_>>();
+        let field = self.field.read();
+        let then_by = self.then_by.read();
+        let tie_breakers = field.tie_breakers();
+        let chain = self.compare_chain(sort_chain(
+            &*field,
+            *self.direction.read(),
+            &then_by,
+            &tie_breakers,
+        ));
+        self.check_chain(&chain, |index| &new_items[index], new_items.len());
+        self.timed(new_items.len(), || {
+            sort_by(&chain, self.algorithm, &mut new_items);
+            let mut existing = std::mem::take(items).into_iter().peekable();
+            items.reserve(existing.len() + new_items.len());
+            for item in new_items {
+                // Existing items go first on ties
+                while let Some(before) =
+                    existing.next_if(|other| cmp_chain(&chain, other, &item).is_le())
+                {
+                    items.push(before);
+                }
+                items.push(item);
+            }
+            items.extend(existing);
+        });
+        let nulls = items
+            .iter()
+            .filter(|item| is_null(&chain[0].0, item))
+            .count();
+        self.record_sort(items.len(), items.len(), nulls);
+    }
+
+    /// Collects references to items from any collection (e.g., a `HashSet` or a map's values) and sorts them. Useful for data that isn't held in a slice.
+    ///
+    /// Like [`Self::sort_indices`], `NULL` items are left out for [`NullHandling::Remove`]. If unsorted or in [`SortMode::Remote`] then items are returned in iteration order which may be arbitrary e.g., for a `HashMap`.
+    pub fn sort_collection<'a, T: 'static>(
+        &self,
+        items: impl IntoIterator<Item = &'a T>,
+    ) -> Vec<&'a T>
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let items = items.into_iter().collect::<Vec<_>>();
+        let mut indices = (0..items.len()).collect::<Vec<_>>();
+        self.sort_indices_with(|index| items[index], &mut indices, true);
+        indices.into_iter().map(|index| items[index]).collect()
+    }
+
+    /// Returns the keys of a map ordered by sorting their values. Accepts anything iterating over key-value references such as `&HashMap<K, T>` or `&BTreeMap<K, T>`. Otherwise behaves like [`Self::sort_collection`].
+    ///
+    /// ```rust
+    /// # use dioxus::prelude::*;
+    /// # use dioxus_sortable::*;
+    /// # use std::collections::HashMap;
+    /// # #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    /// # enum AgeField { #[default] Age }
+    /// # impl PartialOrdBy<u32> for AgeField {
+    /// #     fn partial_cmp_by(&self, a: &u32, b: &u32) -> Option<std::cmp::Ordering> { a.partial_cmp(b) }
+    /// # }
+    /// # impl Sortable for AgeField {
+    /// #     fn sort_by(&self) -> Option<SortBy> { SortBy::increasing_or_decreasing() }
+    /// # }
+    /// #[component]
+    /// fn Ages(ages: HashMap<String, u32>) -> Element {
+    ///     let sorter = use_sorter::<AgeField>();
+    ///     // Youngest first
+    ///     let names = sorter.sorted_keys(&ages);
+    ///     rsx! {
+    ///         for name in names {
+    ///             p { "{name}" }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn sorted_keys<'a, K: 'a, T: 'static>(
+        &self,
+        entries: impl IntoIterator<Item = (&'a K, &'a T)>,
+    ) -> Vec<&'a K>
+    where
+        F: PartialOrdBy<T> + Sortable,
+    {
+        let (keys, values): (Vec<_>, Vec<_>) = entries.into_iter().unzip();
+        let mut indices = (0..values.len()).collect::<Vec<_>>();
+        self.sort_indices_with(|index| values[index], &mut indices, true);
+        indices.into_iter().map(|index| keys[index]).collect()
+    }
+
+    /// Sorts items according to the current field and direction using [`SortKeyBy`]. Keys are computed once per item rather than on every comparison. Otherwise behaves like [`Self::sort`] except that secondary sorts from [`Self::add_sort`] and [`Sortable::tie_breakers`] are not used.
+    pub fn sort_by_cached_key<T>(&self, items: &mut [T])
+    where
+        F: SortKeyBy<T> + Sortable,
+    {
+        if !self.sorts_locally() {
+            self.record_sort(items.len(), items.len(), 0);
+            return;
+        }
+        let field = self.field.read();
+        let dir = *self.direction.read();
+        let nulls = self.null_handling(&field);
+        let nulls = self.timed(items.len(), || {
+            sort_by_cached_key(&*field, dir, nulls, self.algorithm, items)
+        });
+        self.record_sort(items.len(), items.len(), nulls);
+    }
+}
+
+/// A field compared by any comparator registered with [`UseSorter::with_comparator`], otherwise by its [`PartialOrdBy`]. Created by [`UseSorter::field_cmp`].
+pub(crate) struct FieldCmp<'f, T, F> {
+    field: &'f F,
+    custom: Option<Comparator<T>>,
+}
+
+impl<T, F: PartialEq> PartialEq for FieldCmp<'_, T, F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field == other.field
+    }
+}
+
+impl<T, F: PartialOrdBy<T>> Compare<T> for FieldCmp<'_, T, F> {
+    fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        match &self.custom {
+            Some(custom) => custom(a, b),
+            None => self.field.partial_cmp_by(a, b),
+        }
+    }
+}
+
+/// A link in a sort chain comparing items by index: by values cached from a [`ComputedField`] or by looking items up.
+enum IndexCmp<'l, 'a, T, C> {
+    Cached(KeyCmp),
+    Item(C, &'l dyn Fn(usize) -> &'a T),
+}
+
+impl<T, C: Compare<T>> Compare<usize> for IndexCmp<'_, '_, T, C> {
+    fn compare(&self, a: &usize, b: &usize) -> Option<Ordering> {
+        match self {
+            Self::Cached(cmp) => cmp(*a, *b),
+            Self::Item(cmp, item) => cmp.compare(item(*a), item(*b)),
+        }
+    }
+}
+
+/// Sorts indices into items like [`sort_indices_by`], computing values for links with a [`ComputeKeys`] once per item. Optionally removes `NULL` items first. Returns the number of `NULL`s on the primary field, including any removed.
+pub(crate) fn sort_indices_cached<'a, T: 'a, C: Compare<T>>(
+    chain: Vec<SortKey<C>>,
+    computed: &[Option<Computed<T>>],
+    algorithm: SortAlgorithm,
+    item: impl Fn(usize) -> &'a T + Copy,
+    indices: &mut Vec<usize>,
+    remove_nulls: bool,
+) -> usize {
+    let lookup: &dyn Fn(usize) -> &'a T = &item;
+    let chain = chain
+        .into_iter()
+        .zip(computed)
+        .map(|((cmp, dir, nulls), computed)| {
+            let cmp = match computed {
+                Some(computed) => {
+                    IndexCmp::Cached(computed.keys(&mut indices.iter().map(|&i| (i, item(i)))))
+                }
+                None => IndexCmp::Item(cmp, lookup),
+            };
+            (cmp, dir, nulls)
+        })
+        .collect::<Vec<_>>();
+    let total = indices.len();
+    if remove_nulls {
+        indices.retain(|index| !is_null(&chain[0].0, index));
+    }
+    let removed = total - indices.len();
+    removed + sort_indices_by_index(&chain, algorithm, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sort_slice_by, test_dom::mount, Projection};
+    use proptest::prelude::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Row(f64);
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum RowField {
+        #[default]
+        Value,
+    }
+
+    impl PartialOrdBy<Row> for RowField {
+        fn partial_cmp_by(&self, a: &Row, b: &Row) -> Option<Ordering> {
+            match self {
+                Self::Value => a.0.partial_cmp(&b.0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by() {
+        use Direction::*;
+        use NullHandling::*;
         use RowField::*;
+        use SortAlgorithm::*;
 
         // Ascending
         let mut rows = vec![Row(2.0), Row(1.0), Row(3.0)];
-        sort_by(&Value, Ascending, First, rows.as_mut_slice());
+        sort_by(&[(&Value, Ascending, First)], Stable, rows.as_mut_slice());
         assert_eq!(rows, vec![Row(1.0), Row(2.0), Row(3.0)]);
         // Descending
-        sort_by(&Value, Descending, First, rows.as_mut_slice());
+        sort_by(&[(&Value, Descending, First)], Stable, rows.as_mut_slice());
         assert_eq!(rows, vec![Row(3.0), Row(2.0), Row(1.0)]);
 
         // Nulls last, ascending
         let mut rows = vec![Row(f64::NAN), Row(f64::NAN), Row(2.0), Row(1.0), Row(3.0)];
-        sort_by(&Value, Ascending, Last, rows.as_mut_slice());
+        sort_by(&[(&Value, Ascending, Last)], Stable, rows.as_mut_slice());
         assert_eq!(rows[0], Row(1.0));
         assert_eq!(rows[1], Row(2.0));
         assert_eq!(rows[2], Row(3.0));
         assert!(rows[3].0.is_nan());
         assert!(rows[4].0.is_nan());
         // Nulls first, ascending
-        sort_by(&Value, Ascending, First, rows.as_mut_slice());
+        sort_by(&[(&Value, Ascending, First)], Stable, rows.as_mut_slice());
         assert!(rows[0].0.is_nan());
         assert!(rows[1].0.is_nan());
         assert_eq!(rows[2], Row(1.0));
@@ -367,18 +1983,878 @@ mod tests {
         assert_eq!(rows[4], Row(3.0));
 
         // Nulls last, descending
-        sort_by(&Value, Descending, Last, rows.as_mut_slice());
+        sort_by(&[(&Value, Descending, Last)], Stable, rows.as_mut_slice());
         assert_eq!(rows[0], Row(3.0));
         assert_eq!(rows[1], Row(2.0));
         assert_eq!(rows[2], Row(1.0));
         assert!(rows[3].0.is_nan());
         assert!(rows[4].0.is_nan());
+        // Removed nulls can't be removed in place so are placed last
+        sort_by(&[(&Value, Descending, Remove)], Stable, rows.as_mut_slice());
+        assert_eq!(rows[0], Row(3.0));
+        assert!(rows[3].0.is_nan());
+        assert!(!is_null(&&Value, &rows[2]));
+        assert!(is_null(&&Value, &rows[4]));
         // Nulls first, descending
-        sort_by(&Value, Descending, First, rows.as_mut_slice());
+        sort_by(&[(&Value, Descending, First)], Stable, rows.as_mut_slice());
         assert!(rows[0].0.is_nan());
         assert!(rows[1].0.is_nan());
         assert_eq!(rows[2], Row(3.0));
         assert_eq!(rows[3], Row(2.0));
         assert_eq!(rows[4], Row(1.0));
+
+        // SQL default: nulls last ascending, first descending
+        sort_by(
+            &[(&Value, Ascending, SqlDefault)],
+            Stable,
+            rows.as_mut_slice(),
+        );
+        assert_eq!(rows[0], Row(1.0));
+        assert!(rows[4].0.is_nan());
+        sort_by(
+            &[(&Value, Descending, SqlDefault)],
+            Stable,
+            rows.as_mut_slice(),
+        );
+        assert!(rows[0].0.is_nan());
+        assert_eq!(rows[4], Row(1.0));
+
+        // Unstable still sorts
+        let mut rows = vec![Row(2.0), Row(f64::NAN), Row(1.0), Row(3.0)];
+        sort_by(&[(&Value, Descending, Last)], Unstable, rows.as_mut_slice());
+        assert_eq!(rows[0..3], [Row(3.0), Row(2.0), Row(1.0)]);
+        assert!(rows[3].0.is_nan());
+    }
+
+    /// Row with an identifying tag that is ignored when sorting.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Tagged(f64, usize);
+
+    impl PartialOrdBy<Tagged> for RowField {
+        fn partial_cmp_by(&self, a: &Tagged, b: &Tagged) -> Option<Ordering> {
+            match self {
+                Self::Value => a.0.partial_cmp(&b.0),
+            }
+        }
+    }
+
+    impl SortKeyBy<Tagged> for RowField {
+        type Key = i64;
+
+        fn sort_key_by(&self, item: &Tagged) -> Option<i64> {
+            match self {
+                Self::Value => (!item.0.is_nan()).then_some(item.0 as i64),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_cached_key() {
+        use Direction::*;
+        use NullHandling::*;
+        use RowField::*;
+
+        let rows = vec![
+            Tagged(1.0, 0),
+            Tagged(f64::NAN, 1),
+            Tagged(0.0, 2),
+            Tagged(2.0, 3),
+            Tagged(f64::NAN, 4),
+            Tagged(1.0, 5),
+        ];
+        // Must match the comparison based sort, including stability
+        for (dir, nulls) in [
+            (Ascending, First),
+            (Ascending, Last),
+            (Descending, First),
+            (Descending, Last),
+            (Ascending, SqlDefault),
+            (Descending, SqlDefault),
+        ] {
+            let mut expected = rows.clone();
+            sort_by(
+                &[(&Value, dir, nulls)],
+                SortAlgorithm::Stable,
+                &mut expected,
+            );
+            let mut keyed = rows.clone();
+            sort_by_cached_key(&Value, dir, nulls, SortAlgorithm::Stable, &mut keyed);
+            let tags = |rows: &[Tagged]| rows.iter().map(|r| r.1).collect::<Vec<_>>();
+            assert_eq!(tags(&keyed), tags(&expected));
+        }
+    }
+
+    #[test]
+    fn test_sort_by_checks_nulls_once() {
+        use std::cell::Cell;
+
+        // Counts NULL checks i.e., comparing an item against itself
+        struct Counted<'a>(&'a Cell<usize>);
+        impl PartialEq for Counted<'_> {
+            fn eq(&self, _: &Self) -> bool {
+                true
+            }
+        }
+        impl PartialOrdBy<Row> for Counted<'_> {
+            fn partial_cmp_by(&self, a: &Row, b: &Row) -> Option<Ordering> {
+                if std::ptr::eq(a, b) {
+                    self.0.set(self.0.get() + 1);
+                }
+                a.0.partial_cmp(&b.0)
+            }
+        }
+
+        let checks = Cell::new(0);
+        let field = Counted(&checks);
+        let mut rows = (0..100)
+            .map(|i| Row(if i % 2 == 0 { f64::NAN } else { i as f64 }))
+            .collect::<Vec<_>>();
+        let chain = [(&field, Direction::Descending, NullHandling::First)];
+        sort_by(&chain, SortAlgorithm::Stable, &mut rows);
+        assert_eq!(checks.get(), rows.len());
+        assert!(rows[..50].iter().all(|row| row.0.is_nan()));
+        assert_eq!(rows[50], Row(99.0));
+        assert_eq!(rows[99], Row(1.0));
+    }
+
+    #[test]
+    fn test_sort_indices_by_deref() {
+        let mut rows = [2.0, f64::NAN, 1.0].map(|v| Rc::new(Row(v))).to_vec();
+        let mut indices = vec![0, 1, 2];
+        let chain = [(&RowField::Value, Direction::Ascending, NullHandling::First)];
+        sort_indices_by(&chain, SortAlgorithm::Stable, |i| &*rows[i], &mut indices);
+        assert_eq!(indices, vec![1, 2, 0]);
+        apply_permutation(&mut rows, indices);
+        assert!(rows[0].0.is_nan());
+        assert_eq!(rows[1..], [Rc::new(Row(1.0)), Rc::new(Row(2.0))]);
+    }
+
+    #[test]
+    fn test_sort_ord_by() {
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        enum PairField {
+            First,
+            Second,
+        }
+        impl OrdBy<(u32, u32)> for PairField {
+            fn cmp_by(&self, a: &(u32, u32), b: &(u32, u32)) -> Ordering {
+                match self {
+                    Self::First => a.0.cmp(&b.0),
+                    Self::Second => a.1.cmp(&b.1),
+                }
+            }
+        }
+
+        let pairs = vec![(2, 1), (1, 2), (2, 3), (1, 1)];
+        let chain = [
+            (&PairField::First, Direction::Descending, NullHandling::Last),
+            (&PairField::Second, Direction::Ascending, NullHandling::Last),
+        ];
+        let mut ord = pairs.clone();
+        sort_ord_by(&chain, SortAlgorithm::Stable, &mut ord);
+        assert_eq!(ord, vec![(2, 1), (2, 3), (1, 1), (1, 2)]);
+        // Blanket PartialOrdBy agrees
+        let mut partial = pairs;
+        sort_by(&chain, SortAlgorithm::Stable, &mut partial);
+        assert_eq!(ord, partial);
+    }
+
+    #[test]
+    fn test_sort_indices_by() {
+        use Direction::*;
+        use NullHandling::*;
+        use RowField::*;
+
+        let rows = vec![
+            Tagged(1.0, 0),
+            Tagged(f64::NAN, 1),
+            Tagged(0.0, 2),
+            Tagged(1.0, 3),
+        ];
+        let mut indices = vec![0, 1, 2, 3];
+        sort_indices_by(
+            &[(&Value, Descending, Last)],
+            SortAlgorithm::Stable,
+            |i| &rows[i],
+            &mut indices,
+        );
+        assert_eq!(indices, vec![0, 3, 2, 1]);
+        // Original data is untouched and matches an in-place sort
+        let mut sorted = rows.clone();
+        sort_by(
+            &[(&Value, Descending, Last)],
+            SortAlgorithm::Stable,
+            &mut sorted,
+        );
+        let by_index = indices.iter().map(|&i| rows[i].1).collect::<Vec<_>>();
+        assert_eq!(by_index, sorted.iter().map(|r| r.1).collect::<Vec<_>>());
+
+        // Subsets are sorted without touching other items
+        let mut subset = vec![3, 2];
+        sort_indices_by(
+            &[(&Value, Ascending, Last)],
+            SortAlgorithm::Stable,
+            |i| &rows[i],
+            &mut subset,
+        );
+        assert_eq!(subset, vec![2, 3]);
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    enum TaggedField {
+        #[default]
+        Value,
+        Tag,
+    }
+
+    impl PartialOrdBy<Tagged> for TaggedField {
+        fn partial_cmp_by(&self, a: &Tagged, b: &Tagged) -> Option<Ordering> {
+            match self {
+                Self::Value => a.0.partial_cmp(&b.0),
+                Self::Tag => a.1.partial_cmp(&b.1),
+            }
+        }
+    }
+
+    impl Sortable for TaggedField {
+        fn sort_by(&self) -> Option<SortBy> {
+            match self {
+                Self::Value => SortBy::increasing_or_decreasing(),
+                Self::Tag => SortBy::decreasing(),
+            }
+        }
+
+        fn tie_breakers(&self) -> Vec<Self> {
+            match self {
+                Self::Value => vec![Self::Tag],
+                Self::Tag => vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn test_sort_by_tie_breakers() {
+        use Direction::*;
+        use TaggedField::*;
+
+        let rows = vec![
+            Tagged(1.0, 0),
+            Tagged(f64::NAN, 1),
+            Tagged(0.0, 2),
+            Tagged(1.0, 3),
+            Tagged(f64::NAN, 4),
+        ];
+        let tie_breakers = Value.tie_breakers();
+        let chain = sort_chain(&Value, Ascending, &[], &tie_breakers);
+        assert_eq!(
+            chain,
+            vec![
+                (&Value, Ascending, NullHandling::Last),
+                (&Tag, Descending, NullHandling::Last)
+            ]
+        );
+
+        // Ties (including NULLs) are broken by the tag in its own direction
+        let mut sorted = rows.clone();
+        sort_by(&chain, SortAlgorithm::Stable, &mut sorted);
+        let tags = sorted.iter().map(|r| r.1).collect::<Vec<_>>();
+        assert_eq!(tags, vec![2, 3, 0, 4, 1]);
+    }
+
+    #[test]
+    fn test_sort_by_then_by() {
+        use Direction::*;
+        use TaggedField::*;
+
+        let rows = vec![Tagged(1.0, 0), Tagged(0.0, 1), Tagged(1.0, 2)];
+        // Secondary sorts come before tie-breakers and use their own direction
+        let then_by = [(Tag, Ascending)];
+        let tie_breakers = Value.tie_breakers();
+        let chain = sort_chain(&Value, Descending, &then_by, &tie_breakers);
+        assert_eq!(
+            chain,
+            vec![
+                (&Value, Descending, NullHandling::Last),
+                (&Tag, Ascending, NullHandling::Last),
+                (&Tag, Descending, NullHandling::Last)
+            ]
+        );
+        let mut sorted = rows.clone();
+        sort_by(&chain, SortAlgorithm::Stable, &mut sorted);
+        let tags = sorted.iter().map(|r| r.1).collect::<Vec<_>>();
+        assert_eq!(tags, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_by_custom_comparator() {
+        use Direction::*;
+        use TaggedField::*;
+
+        let rows = vec![Tagged(1.0, 0), Tagged(-2.0, 1), Tagged(f64::NAN, 2)];
+        // Compare by magnitude instead of value. Tags aren't overridden
+        let magnitude: Comparator<Tagged> = Rc::new(|a, b| a.0.abs().partial_cmp(&b.0.abs()));
+        let chain = vec![
+            (
+                FieldCmp {
+                    field: &Value,
+                    custom: Some(magnitude),
+                },
+                Ascending,
+                NullHandling::First,
+            ),
+            (
+                FieldCmp {
+                    field: &Tag,
+                    custom: None,
+                },
+                Ascending,
+                NullHandling::Last,
+            ),
+        ];
+        let mut sorted = rows.clone();
+        sort_by(&chain, SortAlgorithm::Stable, &mut sorted);
+        let tags = sorted.iter().map(|r| r.1).collect::<Vec<_>>();
+        assert_eq!(tags, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_sort_indices_cached() {
+        use std::cell::Cell;
+        use Direction::*;
+        use TaggedField::*;
+
+        // Derived from the value: its magnitude, NULL if NaN. Counts computations
+        let computes = Rc::new(Cell::new(0));
+        let counter = computes.clone();
+        let magnitude = ComputedField::new(move |row: &Tagged| {
+            counter.set(counter.get() + 1);
+            Some(row.0.abs()).filter(|v| !v.is_nan())
+        });
+        let computed: Computed<Tagged> = Rc::new(magnitude);
+        let rows = (0..50)
+            .map(|i| {
+                Tagged(
+                    if i % 5 == 0 {
+                        f64::NAN
+                    } else {
+                        25.0 - i as f64
+                    },
+                    i,
+                )
+            })
+            .collect::<Vec<_>>();
+        let chain = vec![
+            (&Value, Descending, NullHandling::Remove),
+            (&Tag, Ascending, NullHandling::Last),
+        ];
+        let mut indices = (0..rows.len()).collect::<Vec<_>>();
+        sort_indices_cached(
+            chain,
+            &[Some(computed), None],
+            SortAlgorithm::Stable,
+            |i| &rows[i],
+            &mut indices,
+            true,
+        );
+        // Once per row, not per comparison
+        assert_eq!(computes.get(), rows.len());
+        assert_eq!(indices.len(), 40);
+        let magnitudes = indices.iter().map(|&i| rows[i].0.abs()).collect::<Vec<_>>();
+        assert!(magnitudes.windows(2).all(|w| w[0] >= w[1]));
+        // Ties broken by tag: 1.0 from 24 and 26
+        let tied = indices.iter().filter(|&&i| rows[i].0.abs() == 1.0);
+        assert_eq!(tied.map(|&i| rows[i].1).collect::<Vec<_>>(), vec![24, 26]);
+    }
+
+    #[test]
+    fn test_sort_by_is_stable() {
+        use Direction::*;
+        use NullHandling::*;
+        use RowField::*;
+
+        let tags = |rows: &[Tagged]| rows.iter().map(|r| r.1).collect::<Vec<_>>();
+        let rows = vec![
+            Tagged(1.0, 0),
+            Tagged(f64::NAN, 1),
+            Tagged(0.0, 2),
+            Tagged(1.0, 3),
+            Tagged(f64::NAN, 4),
+            Tagged(0.0, 5),
+        ];
+
+        // Equal values and NULLs keep their original relative order in both directions
+        let mut sorted = rows.clone();
+        sort_by(
+            &[(&Value, Ascending, Last)],
+            SortAlgorithm::Stable,
+            &mut sorted,
+        );
+        assert_eq!(tags(&sorted), vec![2, 5, 0, 3, 1, 4]);
+        let mut sorted = rows.clone();
+        sort_by(
+            &[(&Value, Descending, First)],
+            SortAlgorithm::Stable,
+            &mut sorted,
+        );
+        assert_eq!(tags(&sorted), vec![1, 4, 0, 3, 2, 5]);
+    }
+
+    fn tagged_rows() -> impl Strategy<Value = Vec<Tagged>> {
+        // Few distinct values so ties and NULLs are common
+        let value = prop_oneof![1 => Just(f64::NAN), 4 => (0..5).prop_map(f64::from)];
+        prop::collection::vec(value, 0..64).prop_map(|values| {
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(tag, value)| Tagged(value, tag))
+                .collect()
+        })
+    }
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop_oneof![Just(Direction::Ascending), Just(Direction::Descending)]
+    }
+
+    fn null_handling() -> impl Strategy<Value = NullHandling> {
+        prop_oneof![
+            Just(NullHandling::First),
+            Just(NullHandling::Last),
+            Just(NullHandling::Remove),
+            Just(NullHandling::SqlDefault),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn prop_sort_slice_by(rows in tagged_rows(), dir in direction(), nulls in null_handling()) {
+            let mut sorted = rows.clone();
+            sort_slice_by(&RowField::Value, dir, nulls, &mut sorted);
+
+            // A permutation of the input
+            let mut tags = sorted.iter().map(|r| r.1).collect::<Vec<_>>();
+            tags.sort_unstable();
+            prop_assert_eq!(tags, (0..rows.len()).collect::<Vec<_>>());
+
+            // NULLs are grouped at one end
+            let is_null = |row: &Tagged| row.0.is_nan();
+            let null_count = rows.iter().filter(|row| is_null(row)).count();
+            let (front, back) = sorted.split_at(if nulls.nulls_first(dir) {
+                null_count
+            } else {
+                rows.len() - null_count
+            });
+            let (null_group, values) = if nulls.nulls_first(dir) {
+                (front, back)
+            } else {
+                (back, front)
+            };
+            prop_assert!(null_group.iter().all(is_null));
+            prop_assert!(!values.iter().any(is_null));
+
+            // Values are ordered by direction and ties (including NULLs) keep their original order
+            for group in [null_group, values] {
+                for pair in group.windows(2) {
+                    let ord = pair[0].0.partial_cmp(&pair[1].0).unwrap_or(Ordering::Equal);
+                    let ord = match dir {
+                        Direction::Ascending => ord,
+                        Direction::Descending => ord.reverse(),
+                    };
+                    prop_assert_ne!(ord, Ordering::Greater);
+                    if ord == Ordering::Equal {
+                        prop_assert!(pair[0].1 < pair[1].1);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn prop_sort_indices_by_matches_sort_by(rows in tagged_rows(), dir in direction(), nulls in null_handling()) {
+            let chain = [(&RowField::Value, dir, nulls)];
+            let mut sorted = rows.clone();
+            sort_by(&chain, SortAlgorithm::Stable, &mut sorted);
+            let mut indices = (0..rows.len()).collect::<Vec<_>>();
+            sort_indices_by(&chain, SortAlgorithm::Stable, |i| &rows[i], &mut indices);
+            prop_assert_eq!(indices, sorted.iter().map(|r| r.1).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn prop_sort_top_n_by_matches_sort_by(rows in tagged_rows(), dir in direction(), nulls in null_handling(), n in 0..70usize) {
+            let chain = [(&RowField::Value, dir, nulls)];
+            let mut sorted = rows.clone();
+            sort_by(&chain, SortAlgorithm::Stable, &mut sorted);
+            let mut top = rows.clone();
+            sort_top_n_by(&chain, SortAlgorithm::Stable, &mut top, n);
+            let tags = |rows: &[Tagged]| rows.iter().map(|r| r.1).collect::<Vec<_>>();
+            let n = n.min(rows.len());
+            prop_assert_eq!(tags(&top[..n]), tags(&sorted[..n]));
+        }
+    }
+
+    #[test]
+    fn test_next_state() {
+        use Direction::*;
+        use SortState::*;
+
+        // Existing variants
+        let rev = SortBy::Reversible(Descending);
+        assert_eq!(rev.next_state(None), Some(Sorted(Descending)));
+        assert_eq!(
+            rev.next_state(Some(Sorted(Descending))),
+            Some(Sorted(Ascending))
+        );
+        assert_eq!(rev.next_state(Some(Unsorted)), Some(Sorted(Descending)));
+        let reset = SortBy::ReversibleWithReset(Ascending);
+        assert_eq!(
+            reset.next_state(Some(Sorted(Ascending))),
+            Some(Sorted(Descending))
+        );
+        assert_eq!(reset.next_state(Some(Sorted(Descending))), Some(Unsorted));
+        assert_eq!(reset.next_state(Some(Unsorted)), Some(Sorted(Ascending)));
+        let score = SortBy::FixedWithReset(Descending);
+        assert_eq!(score.next_state(None), Some(Sorted(Descending)));
+        assert_eq!(score.next_state(Some(Sorted(Descending))), Some(Unsorted));
+        assert_eq!(score.next_state(Some(Unsorted)), Some(Sorted(Descending)));
+        assert!(!score.is_reversible() && score.can_reset());
+        assert_eq!(score.ensure_direction(Ascending), Descending);
+
+        // Custom cycle wraps around
+        let cycle = SortBy::Cycle(&[Sorted(Descending), Sorted(Ascending), Unsorted]);
+        assert_eq!(cycle.direction(), Descending);
+        assert!(cycle.is_reversible());
+        assert_eq!(cycle.next_state(None), Some(Sorted(Descending)));
+        assert_eq!(
+            cycle.next_state(Some(Sorted(Descending))),
+            Some(Sorted(Ascending))
+        );
+        assert_eq!(cycle.next_state(Some(Sorted(Ascending))), Some(Unsorted));
+        assert_eq!(cycle.next_state(Some(Unsorted)), Some(Sorted(Descending)));
+
+        // One direction only
+        let cycle = SortBy::Cycle(&[Sorted(Descending), Unsorted]);
+        assert!(!cycle.is_reversible());
+        assert_eq!(cycle.ensure_direction(Ascending), Descending);
+        assert_eq!(SortBy::Cycle(&[]).next_state(None), None);
+
+        // Never sorted by
+        let group = SortBy::GroupOnly;
+        assert_eq!(group.next_state(None), None);
+        assert_eq!(group.next_state(Some(Unsorted)), None);
+        assert!(!group.is_reversible() && !group.can_reset());
+        assert_eq!(group.ensure_direction(Descending), Descending);
+
+        // Overridden initial direction. Fixed and cycles can't change
+        let rev = SortBy::Reversible(Ascending).with_initial_direction(Descending);
+        assert_eq!(rev.next_state(None), Some(Sorted(Descending)));
+        let reset = SortBy::ReversibleWithReset(Ascending).with_initial_direction(Descending);
+        assert_eq!(
+            reset.next_state(Some(Sorted(Descending))),
+            Some(Sorted(Ascending))
+        );
+        assert_eq!(reset.next_state(Some(Sorted(Ascending))), Some(Unsorted));
+        let fixed = SortBy::Fixed(Ascending).with_initial_direction(Descending);
+        assert_eq!(fixed.next_state(None), Some(Sorted(Ascending)));
+        assert_eq!(cycle.with_initial_direction(Ascending), cycle);
+    }
+
+    /// Runs the test with a sorter created in a virtual DOM.
+    fn with_sorter(test: impl FnOnce(UseSorter<TaggedField>)) {
+        let (dom, sorter) = mount(use_sorter::<TaggedField>);
+        dom.in_runtime(|| test(sorter));
+    }
+
+    #[test]
+    fn test_restore_baseline() {
+        with_sorter(|sorter| {
+            let mut rows = vec![Tagged(2.0, 0), Tagged(0.0, 1), Tagged(1.0, 2)];
+            assert!(!sorter.restore_baseline(&mut rows));
+            sorter.capture_baseline(&rows, |row: &Tagged| row.1);
+            assert!(sorter.has_baseline::<Tagged>());
+
+            sorter.add_sort(TaggedField::Tag);
+            sorter.sort(&mut rows);
+            rows.insert(0, Tagged(3.0, 3));
+            assert!(sorter.restore_baseline(&mut rows));
+            // Added rows go last
+            assert_eq!(
+                rows.iter().map(|row| row.1).collect::<Vec<_>>(),
+                [0, 1, 2, 3]
+            );
+            assert!(!sorter.is_sorted() && sorter.then_by().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_set_direction() {
+        with_sorter(|sorter| {
+            sorter.add_sort(TaggedField::Tag);
+            // Flips the active field as SortControl's direction button does
+            let (field, dir) = sorter.get_state();
+            sorter.set_direction(dir.invert());
+            assert_eq!(
+                sorter.sort_keys(),
+                [
+                    (field, dir.invert()),
+                    (TaggedField::Tag, Direction::Descending)
+                ]
+            );
+            // Unlike set_field which replaces the chain
+            sorter.set_field(field, dir);
+            assert!(sorter.then_by().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_move_row() {
+        with_sorter(|sorter| {
+            let mut rows = vec![Tagged(2.0, 0), Tagged(0.0, 1), Tagged(1.0, 2)];
+            let mut shown = rows.clone();
+            sorter.sort(&mut shown);
+            let tags = |rows: &[Tagged]| rows.iter().map(|row| row.1).collect::<Vec<_>>();
+            assert_eq!(tags(&shown), [1, 2, 0]);
+            // Moves the last row shown to the top, keeping the sorted order otherwise
+            sorter.move_row(&mut rows, 2, 0);
+            assert_eq!(tags(&rows), [0, 1, 2]);
+            assert!(sorter.is_custom_order() && !sorter.is_sorted());
+
+            sorter.toggle_field(TaggedField::Value);
+            assert!(!sorter.is_custom_order() && sorter.has_custom_order());
+            sorter.restore_custom_order();
+            assert!(sorter.is_custom_order());
+            sorter.move_row(&mut rows, 0, 5);
+            assert_eq!(tags(&rows), [1, 2, 0]);
+
+            sorter.clear();
+            assert!(!sorter.has_custom_order());
+        });
+    }
+
+    #[test]
+    fn test_sort_top_n() {
+        with_sorter(|sorter| {
+            let mut rows = vec![Tagged(2.0, 0), Tagged(f64::NAN, 1), Tagged(1.0, 2)];
+            let top = sorter.sort_top_n(&mut rows, 2);
+            assert_eq!(top, [Tagged(1.0, 2), Tagged(2.0, 0)]);
+
+            sorter.set_null_handling(TaggedField::Value, NullHandling::Remove);
+            let top = sorter.sort_top_n(&mut rows, 5);
+            assert_eq!(top.len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_check_partial_ord_by() {
+        let rows = (0..100)
+            .map(|tag| {
+                Tagged(
+                    if tag % 7 == 0 {
+                        f64::NAN
+                    } else {
+                        (tag % 5) as f64
+                    },
+                    tag,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(crate::check_partial_ord_by(&RowField::Value, &rows), Ok(()));
+
+        with_sorter(|sorter| {
+            // NaN as a value rather than NULL: `None` against other values only
+            let sorter = sorter.with_comparator(TaggedField::Value, |a: &Tagged, b: &Tagged| {
+                (a.1 != 1 && b.1 != 1 || a.1 == b.1).then_some(a.0.total_cmp(&b.0))
+            });
+            let chain = sorter.compare_chain(vec![(
+                &TaggedField::Value,
+                Direction::Ascending,
+                NullHandling::Last,
+            )]);
+            let rows = [Tagged(0.0, 0), Tagged(1.0, 1)];
+            let checked = check_compare(&chain[0].0, |i| &rows[i], rows.len());
+            assert_eq!(checked, Err(crate::InconsistentCmp::Null(0, 1)));
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "inconsistent comparisons")]
+    fn test_checked_comparisons() {
+        with_sorter(|sorter| {
+            let sorter = UseSorter {
+                checked: true,
+                ..sorter
+            };
+            // Rock, paper, scissors
+            let sorter = sorter.with_comparator(TaggedField::Value, |a: &Tagged, b: &Tagged| {
+                let beats = |a: usize, b: usize| (a + 1) % 3 == b;
+                Some(match (a.1, b.1) {
+                    (a, b) if a == b => Ordering::Equal,
+                    (a, b) if beats(a, b) => Ordering::Less,
+                    _ => Ordering::Greater,
+                })
+            });
+            let mut rows = vec![Tagged(0.0, 0), Tagged(0.0, 1), Tagged(0.0, 2)];
+            sorter.sort(&mut rows);
+        });
+    }
+
+    impl SortKeyBy<Option<u32>> for TaggedField {
+        type Key = u32;
+
+        fn sort_key_by(&self, item: &Option<u32>) -> Option<u32> {
+            *item
+        }
+    }
+
+    impl OrdBy<u32> for TaggedField {
+        fn cmp_by(&self, a: &u32, b: &u32) -> Ordering {
+            a.cmp(b)
+        }
+    }
+
+    #[test]
+    fn test_last_sort_stats() {
+        with_sorter(|sorter| {
+            assert_eq!(sorter.last_sort_stats(), None);
+            let mut rows = vec![Tagged(2.0, 0), Tagged(f64::NAN, 1), Tagged(1.0, 2)];
+            sorter.sort(&mut rows);
+            let stats = sorter.last_sort_stats().unwrap();
+            assert_eq!((stats.total, stats.visible, stats.nulls), (3, 3, 1));
+            assert_eq!(
+                stats.sort_keys,
+                [(TaggedField::Value, Direction::Ascending)]
+            );
+            let counts = |sorter: UseSorter<TaggedField>| {
+                let stats = sorter.last_sort_stats().unwrap();
+                (stats.total, stats.visible, stats.nulls)
+            };
+
+            // Every entry point replaces the stats rather than leaving them stale
+            let mut ord = vec![3, 1, 2, 0];
+            sorter.sort_ord(&mut ord);
+            assert_eq!(counts(sorter), (4, 4, 0));
+
+            let mut keys = vec![Some(3), None, Some(1), None, Some(2)];
+            sorter.sort_by_cached_key(&mut keys);
+            assert_eq!(counts(sorter), (5, 5, 2));
+
+            sorter.insert_sorted(&mut rows, [Tagged(0.0, 3)]);
+            assert_eq!(counts(sorter), (4, 4, 1));
+
+            sorter.sort_top_n(&mut rows, 2);
+            assert_eq!(counts(sorter), (4, 2, 1));
+
+            sorter.set_null_handling(TaggedField::Value, NullHandling::Remove);
+            sorter.sort_indices(&rows);
+            assert_eq!(counts(sorter), (4, 3, 1));
+        });
+    }
+
+    thread_local! {
+        static PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Parses text as a number, counting calls.
+    fn parse(text: &str) -> Option<f64> {
+        PARSES.with(|parses| parses.set(parses.get() + 1));
+        text.parse().ok()
+    }
+
+    impl PartialOrdBy<String> for TaggedField {
+        fn partial_cmp_by(&self, a: &String, b: &String) -> Option<Ordering> {
+            match self {
+                Self::Value => parse(a)?.partial_cmp(&parse(b)?),
+                Self::Tag => a.len().partial_cmp(&b.len()),
+            }
+        }
+
+        fn projection(&self) -> Option<Projection<String>> {
+            let value = Projection::new(|text: &String| parse(text));
+            (*self == Self::Value).then_some(value)
+        }
+    }
+
+    #[test]
+    fn test_projection() {
+        with_sorter(|sorter| {
+            let mut rows = ["10", "x", "9", "1.5"].map(String::from).to_vec();
+            PARSES.with(|parses| parses.set(0));
+            sorter.sort(&mut rows);
+            assert_eq!(rows, ["1.5", "9", "10", "x"]);
+            // Parsed once per row rather than per comparison
+            assert_eq!(PARSES.with(|parses| parses.get()), rows.len());
+            assert_eq!(sorter.sort_indices(&rows), [0, 1, 2, 3]);
+
+            // Replaced by comparators
+            let sorter = sorter.with_comparator(TaggedField::Value, |a: &String, b: &String| {
+                b.partial_cmp(a)
+            });
+            sorter.sort(&mut rows);
+            assert_eq!(rows, ["x", "9", "10", "1.5"]);
+        });
+    }
+
+    #[test]
+    fn test_is_null() {
+        with_sorter(|sorter| {
+            assert!(sorter.is_null(&TaggedField::Value, &Tagged(f64::NAN, 0)));
+            assert!(!sorter.is_null(&TaggedField::Value, &Tagged(1.0, 0)));
+            // Follows custom comparators
+            let sorter = sorter.with_comparator(TaggedField::Value, |a: &Tagged, b: &Tagged| {
+                (a.0 >= 0.0 && b.0 >= 0.0).then(|| a.0.total_cmp(&b.0))
+            });
+            assert!(sorter.is_null(&TaggedField::Value, &Tagged(-1.0, 0)));
+        });
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        with_sorter(|sorter| {
+            let mut rows = vec![Tagged(2.0, 0), Tagged(f64::NAN, 1), Tagged(1.0, 2)];
+            sorter.sort(&mut rows);
+            let page = vec![Tagged(1.0, 3), Tagged(f64::NAN, 4), Tagged(0.0, 5)];
+            let mut all = rows.iter().chain(&page).cloned().collect::<Vec<_>>();
+            sorter.sort(&mut all);
+            sorter.insert_sorted(&mut rows, page);
+            // Same as sorting everything
+            let tags = |rows: &[Tagged]| rows.iter().map(|row| row.1).collect::<Vec<_>>();
+            assert_eq!(tags(&rows), tags(&all));
+            assert_eq!(tags(&rows), [5, 3, 2, 0, 4, 1]);
+        });
+    }
+
+    #[test]
+    fn test_cell_sort() {
+        use TaggedField::*;
+        with_sorter(|sorter| {
+            assert!(sorter.is_active(&Value) && !sorter.is_active(&Tag));
+            assert_eq!(
+                sorter.cell_sort(&Tag),
+                CellSort {
+                    active: Some((Value, Direction::Ascending)),
+                    direction: None,
+                    priority: None,
+                }
+            );
+            sorter.add_sort(Tag);
+            let sort = sorter.cell_sort(&Tag);
+            assert!(sort.is_sorted() && !sort.is_active());
+            assert_eq!(sort.direction, Some(Direction::Descending));
+        });
+    }
+
+    #[test]
+    fn test_disable_field() {
+        use TaggedField::*;
+        with_sorter(|sorter| {
+            sorter.disable_field(Tag);
+            assert!(!sorter.is_enabled(&Tag));
+            sorter.toggle_field(Tag);
+            sorter.add_sort(Tag);
+            sorter.set_field(Tag, Direction::Ascending);
+            assert_eq!(sorter.sort_keys(), [(Value, Direction::Ascending)]);
+
+            sorter.enable_field(&Tag);
+            sorter.toggle_field(Tag);
+            assert_eq!(sorter.get_state(), (Tag, Direction::Descending));
+        });
     }
 }
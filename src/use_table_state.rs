@@ -0,0 +1,342 @@
+use crate::aggregates::Aggregate;
+use crate::{
+    use_filter, use_paginator, use_selection, FilterBy, FilteredSorted, KeyedRows, PartialOrdBy,
+    SelectionMode, Sortable, UseFilter, UsePaginator, UseSelection, UseSorter, UseSorterBuilder,
+};
+use dioxus::prelude::*;
+use std::{fmt, ops::Range, rc::Rc};
+
+type OnChange = Option<Rc<dyn Fn(TableChange)>>;
+
+/// Page size used by [`UseTableStateBuilder`] unless set.
+pub const DEFAULT_PAGE_SIZE: usize = 25;
+
+/// What changed in a [`UseTableState`]. Passed to [`UseTableState::on_change`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TableChange {
+    /// Sort field, direction or sorted state changed. Returns to the first page.
+    Sort,
+    /// Search text, a field filter or a predicate changed. Returns to the first page.
+    Filter,
+    /// Page or page size changed.
+    Page,
+    /// Rows were selected or deselected.
+    Selection,
+}
+
+/// Stores Dioxus hooks and state of a whole table: sorting, filtering, pagination and selection of rows of `T`. Type `F` is the field enum and `K` the key identifying rows for selection. See [`use_table_state()`].
+///
+/// Change state through these methods rather than the individual hooks so that the hooks are kept in step e.g., filtering returns to the first page, and [`Self::on_change`] is notified. The exception is sorting: clicking a [`crate::Th`] given [`Self::sorter`] is handled too.
+///
+/// Render rows from [`Self::view`].
+pub struct UseTableState<T: 'static, F: 'static, K: 'static> {
+    sorter: UseSorter<F>,
+    filter: UseFilter<T, F>,
+    paginator: UsePaginator,
+    selection: UseSelection<T, K>,
+    on_change: CopyValue<OnChange>,
+}
+
+impl<T, F, K> Clone for UseTableState<T, F, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F, K> Copy for UseTableState<T, F, K> {}
+
+impl<T, F: fmt::Debug, K: fmt::Debug> fmt::Debug for UseTableState<T, F, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UseTableState")
+            .field("sorter", &self.sorter)
+            .field("filter", &self.filter)
+            .field("paginator", &self.paginator)
+            .field("selection", &self.selection)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F, K> PartialEq for UseTableState<T, F, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.sorter == other.sorter
+            && self.filter == other.filter
+            && self.paginator == other.paginator
+            && self.selection == other.selection
+            && self.on_change == other.on_change
+    }
+}
+
+/// Builder for [`UseTableState`]. Use this to configure each part of the table before creating the hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseTableStateBuilder<F> {
+    sorter: UseSorterBuilder<F>,
+    search_fields: Vec<F>,
+    page_size: usize,
+    selection: SelectionMode,
+}
+
+impl<F: Default + Sortable> Default for UseTableStateBuilder<F> {
+    fn default() -> Self {
+        Self {
+            sorter: UseSorterBuilder::default(),
+            search_fields: Vec::new(),
+            page_size: DEFAULT_PAGE_SIZE,
+            selection: SelectionMode::default(),
+        }
+    }
+}
+
+impl<F: Copy + Default + Sortable + 'static> UseTableStateBuilder<F> {
+    /// Optionally configures the sorter e.g., its initial field.
+    pub fn with_sorter(self, sorter: UseSorterBuilder<F>) -> Self {
+        Self { sorter, ..self }
+    }
+
+    /// Optionally sets the fields checked by [`UseTableState::set_search`]. Without these, search text matches nothing.
+    pub fn with_search_fields(self, search_fields: &[F]) -> Self {
+        Self {
+            search_fields: search_fields.to_vec(),
+            ..self
+        }
+    }
+
+    /// Optionally sets the number of rows per page. Defaults to [`DEFAULT_PAGE_SIZE`].
+    pub fn with_page_size(self, page_size: usize) -> Self {
+        Self { page_size, ..self }
+    }
+
+    /// Optionally sets how many rows may be selected. Defaults to [`SelectionMode::Multiple`].
+    pub fn with_selection_mode(self, selection: SelectionMode) -> Self {
+        Self { selection, ..self }
+    }
+
+    /// Creates Dioxus hooks to manage table state. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+    ///
+    /// The `key` fn identifies rows for selection. See [`use_selection()`].
+    pub fn use_table_state<T: 'static, K: 'static>(
+        self,
+        key: fn(&T) -> K,
+    ) -> UseTableState<T, F, K> {
+        let state = UseTableState {
+            sorter: self.sorter.use_sorter(),
+            filter: use_filter(&self.search_fields),
+            paginator: use_paginator(self.page_size),
+            selection: use_selection(self.selection, key),
+            on_change: use_hook(|| CopyValue::new(None)),
+        };
+        // Catches sorting from headers as well as our own methods
+        state
+            .sorter
+            .on_change(move |_, _| state.changed(TableChange::Sort));
+        state
+    }
+}
+
+/// Creates Dioxus hooks to manage sorting, filtering, pagination and selection of a table together. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Uses the defaults of [`UseTableStateBuilder`]. The `key` fn identifies rows for selection.
+pub fn use_table_state<T: 'static, F: Copy + Default + Sortable + 'static, K: 'static>(
+    key: fn(&T) -> K,
+) -> UseTableState<T, F, K> {
+    UseTableStateBuilder::default().use_table_state(key)
+}
+
+impl<T, F, K> UseTableState<T, F, K> {
+    /// Returns the sorter. Pass to [`crate::Th`] and other components. Don't replace its [`UseSorter::on_change`] callback: use [`Self::on_change`] instead.
+    pub fn sorter(&self) -> UseSorter<F> {
+        self.sorter
+    }
+
+    /// Returns the filter. Prefer [`Self::set_search`] and friends to change it.
+    pub fn filter(&self) -> UseFilter<T, F> {
+        self.filter
+    }
+
+    /// Returns the paginator. Prefer [`Self::set_page`] and friends to change it.
+    pub fn paginator(&self) -> UsePaginator {
+        self.paginator
+    }
+
+    /// Returns the selection. Prefer [`Self::toggle_selected`] and friends to change it.
+    pub fn selection(&self) -> UseSelection<T, K> {
+        self.selection
+    }
+
+    /// Registers a callback fired after any change made through this state. Receives what changed.
+    ///
+    /// Call this every render, straight after creating the state. Replaces any earlier callback.
+    pub fn on_change(self, on_change: impl Fn(TableChange) + 'static) -> Self {
+        let mut slot = self.on_change;
+        slot.set(Some(Rc::new(on_change)));
+        self
+    }
+
+    /// Keeps the hooks in step after a change and notifies any callback.
+    fn changed(&self, change: TableChange) {
+        if matches!(change, TableChange::Sort | TableChange::Filter) {
+            self.paginator.first();
+        }
+        // Clone so the callback may change state
+        let on_change = self.on_change.peek().clone();
+        if let Some(on_change) = on_change {
+            on_change(change);
+        }
+    }
+
+    /// Jumps to a (zero-based) page of `total` rows. See [`TableView::total`].
+    pub fn set_page(&self, page: usize, total: usize) {
+        self.paginator.set_page(page, total);
+        self.changed(TableChange::Page);
+    }
+
+    /// Moves to the next page if there is one.
+    pub fn next_page(&self, total: usize) {
+        self.paginator.next(total);
+        self.changed(TableChange::Page);
+    }
+
+    /// Moves to the previous page if there is one.
+    pub fn previous_page(&self, total: usize) {
+        self.paginator.previous(total);
+        self.changed(TableChange::Page);
+    }
+
+    /// Sets the number of rows per page. Returns to the first page.
+    pub fn set_page_size(&self, page_size: usize) {
+        self.paginator.set_page_size(page_size);
+        self.changed(TableChange::Page);
+    }
+}
+
+impl<T, F: Copy + Sortable, K> UseTableState<T, F, K> {
+    /// Toggles sorting by a field. See [`UseSorter::toggle_field`].
+    pub fn toggle_field(&self, field: F) {
+        self.sorter.toggle_field(field);
+    }
+}
+
+impl<T, F: Copy + FilterBy<T>, K> UseTableState<T, F, K> {
+    /// Sets the search text. See [`UseFilter::set_search`].
+    pub fn set_search(&self, text: impl Into<String>) {
+        self.filter.set_search(text);
+        self.changed(TableChange::Filter);
+    }
+
+    /// Sets the filter text for a field. See [`UseFilter::set_field_filter`].
+    pub fn set_field_filter(&self, field: F, value: impl Into<String>) {
+        self.filter.set_field_filter(field, value);
+        self.changed(TableChange::Filter);
+    }
+
+    /// Registers a custom predicate under a name. See [`UseFilter::set_predicate`].
+    pub fn set_predicate(&self, name: &'static str, predicate: impl Fn(&T) -> bool + 'static) {
+        self.filter.set_predicate(name, predicate);
+        self.changed(TableChange::Filter);
+    }
+
+    /// Removes a named predicate.
+    pub fn remove_predicate(&self, name: &'static str) {
+        self.filter.remove_predicate(name);
+        self.changed(TableChange::Filter);
+    }
+
+    /// Removes the search text, all field filters and all predicates.
+    pub fn clear_filters(&self) {
+        self.filter.clear();
+        self.changed(TableChange::Filter);
+    }
+}
+
+impl<T, F, K: Clone + PartialEq> UseTableState<T, F, K> {
+    /// Selects or deselects a row.
+    pub fn set_selected(&self, row: &T, selected: bool) {
+        self.selection.set_selected(row, selected);
+        self.changed(TableChange::Selection);
+    }
+
+    /// Toggles a row between selected and deselected.
+    pub fn toggle_selected(&self, row: &T) {
+        self.selection.toggle(row);
+        self.changed(TableChange::Selection);
+    }
+
+    /// Selects every row in the view across all pages i.e., all rows passing the filter.
+    pub fn select_all(&self, view: &TableView<'_, T>) {
+        self.selection.select_all(view.filtered.iter());
+        self.changed(TableChange::Selection);
+    }
+
+    /// Deselects all rows.
+    pub fn clear_selection(&self) {
+        self.selection.clear();
+        self.changed(TableChange::Selection);
+    }
+}
+
+impl<T: 'static, F, K> UseTableState<T, F, K>
+where
+    F: Copy + FilterBy<T> + PartialOrdBy<T> + Sortable,
+{
+    /// Filters, sorts then pages items. Returns the rows to render. Holds indices rather than cloning the items.
+    pub fn view<'b>(&self, items: &'b [T]) -> TableView<'b, T> {
+        let filtered = FilteredSorted::new(items, &self.filter, &self.sorter);
+        let range = self.paginator.range(filtered.len());
+        TableView { filtered, range }
+    }
+}
+
+/// The current page of filtered and sorted rows. Created by [`UseTableState::view`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableView<'b, T> {
+    filtered: FilteredSorted<'b, T>,
+    range: Range<usize>,
+}
+
+impl<'b, T> TableView<'b, T> {
+    /// Returns the number of rows passing the filter across all pages. Pass to [`crate::Paginator`].
+    pub fn total(&self) -> usize {
+        self.filtered.len()
+    }
+
+    /// Returns the number of rows on the current page.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns true if the current page has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns the number of rows on pages before the current one. Add to a row's position on the page for its position across all pages e.g., for [`crate::RowIndexCell`].
+    pub fn offset(&self) -> usize {
+        self.range.start
+    }
+
+    /// Returns the indices into the original items of rows on the current page, in view order.
+    pub fn indices(&self) -> &[usize] {
+        &self.filtered.indices()[self.range.clone()]
+    }
+
+    /// Iterates over rows on the current page in view order.
+    pub fn iter(&self) -> impl Iterator<Item = &'b T> + '_ {
+        self.range
+            .clone()
+            .filter_map(|position| self.filtered.get(position))
+    }
+
+    /// Iterates over `(key, &T)` pairs on the current page in view order. See [`KeyedRows`].
+    pub fn keyed<J, G: Fn(&T) -> J>(&self, key: G) -> KeyedRows<'b, T, G> {
+        self.filtered.keyed_range(self.range.clone(), key)
+    }
+
+    /// Computes an aggregate over all rows passing the filter, across all pages, e.g., a total for the footer. Use [`Self::iter`] to aggregate the current page only. See [`crate::aggregates`].
+    pub fn aggregate<A: Aggregate<T>>(&self, aggregate: &A) -> A::Output {
+        self.filtered.aggregate(aggregate)
+    }
+
+    /// Returns all rows passing the filter across all pages.
+    pub fn filtered(&self) -> &FilteredSorted<'b, T> {
+        &self.filtered
+    }
+}
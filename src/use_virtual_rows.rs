@@ -0,0 +1,90 @@
+use dioxus::prelude::*;
+use std::ops::Range;
+
+/// Extra rows rendered above and below the viewport so fast scrolling doesn't show gaps.
+const OVERSCAN: usize = 5;
+
+/// Stores Dioxus hooks and state for rendering only the rows visible in a scrolling viewport. See [`crate::VirtualTableBody`].
+///
+/// Rows must have a fixed height.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UseVirtualRows {
+    scroll_top: Signal<f64>,
+    row_height: f64,
+    viewport_height: f64,
+}
+
+/// Creates Dioxus hooks to manage virtual rows. Must follow Dioxus hook rules and be called unconditionally in the same order as other hooks.
+///
+/// Heights are in pixels. `row_height` must match the rendered height of each row.
+pub fn use_virtual_rows(row_height: f64, viewport_height: f64) -> UseVirtualRows {
+    UseVirtualRows {
+        scroll_top: use_signal(|| 0.0),
+        row_height,
+        viewport_height,
+    }
+}
+
+impl UseVirtualRows {
+    /// Returns the height of each row in pixels.
+    pub fn row_height(&self) -> f64 {
+        self.row_height
+    }
+
+    /// Returns the height of the scrolling viewport in pixels.
+    pub fn viewport_height(&self) -> f64 {
+        self.viewport_height
+    }
+
+    /// Records the viewport's scroll position in pixels. Called by [`crate::VirtualScroll`] when the `web` feature is enabled, otherwise call it from your own scroll handler.
+    pub fn set_scroll_top(&self, scroll_top: f64) {
+        let mut state = self.scroll_top;
+        if *state.peek() != scroll_top {
+            state.set(scroll_top.max(0.0));
+        }
+    }
+
+    /// Returns the range of row indices to render for `total` rows.
+    pub fn window(&self, total: usize) -> Range<usize> {
+        visible_range(
+            *self.scroll_top.read(),
+            self.row_height,
+            self.viewport_height,
+            total,
+        )
+    }
+}
+
+fn visible_range(
+    scroll_top: f64,
+    row_height: f64,
+    viewport_height: f64,
+    total: usize,
+) -> Range<usize> {
+    if row_height <= 0.0 {
+        return 0..total;
+    }
+    let first = (scroll_top / row_height).floor() as usize;
+    let count = (viewport_height / row_height).ceil() as usize + 1;
+    let start = first.saturating_sub(OVERSCAN).min(total);
+    let end = (first + count + OVERSCAN).min(total);
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_visible_range() {
+        // Top of the list: viewport of 10 rows plus a partial and overscan
+        assert_eq!(visible_range(0.0, 20.0, 200.0, 1000), 0..16);
+        // Scrolled: overscan both sides
+        assert_eq!(visible_range(2000.0, 20.0, 200.0, 1000), 95..116);
+        // Clamped to the end
+        assert_eq!(visible_range(19900.0, 20.0, 200.0, 1000), 990..1000);
+        // Scrolled past the data e.g., after filtering
+        assert_eq!(visible_range(2000.0, 20.0, 200.0, 10), 10..10);
+        assert_eq!(visible_range(0.0, 0.0, 200.0, 3), 0..3);
+    }
+}